@@ -0,0 +1,14 @@
+#![no_main]
+
+use {libfuzzer_sys::fuzz_target, ord::fuzzing::brc20::deserialize_brc20};
+
+fuzz_target!(|input: &str| {
+  let Ok(operation) = deserialize_brc20(input) else {
+    return;
+  };
+
+  let mut value = serde_json::to_value(&operation).unwrap();
+  value["p"] = "brc-20".into();
+
+  assert_eq!(deserialize_brc20(&value.to_string()).unwrap(), operation);
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use {libfuzzer_sys::fuzz_target, ord::fuzzing::brc20s::deserialize_brc20s};
+
+fuzz_target!(|input: &str| {
+  let Ok(operation) = deserialize_brc20s(input) else {
+    return;
+  };
+
+  let mut value = serde_json::to_value(&operation).unwrap();
+  value["p"] = "brc20-s".into();
+
+  assert_eq!(deserialize_brc20s(&value.to_string()).unwrap(), operation);
+});
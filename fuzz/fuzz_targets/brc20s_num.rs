@@ -0,0 +1,46 @@
+#![no_main]
+
+use {
+  arbitrary::Arbitrary, bigdecimal::num_bigint::Sign, libfuzzer_sys::fuzz_target,
+  ord::fuzzing::brc20s::Num, std::str::FromStr,
+};
+
+#[derive(Clone, Debug, Arbitrary)]
+struct Input<'a> {
+  a: &'a str,
+  b: &'a str,
+}
+
+fuzz_target!(|input: Input| {
+  let (Ok(a), Ok(b)) = (Num::from_str(input.a), Num::from_str(input.b)) else {
+    return;
+  };
+
+  for n in [&a, &b] {
+    assert_ne!(n.sign(), Sign::Minus);
+    assert!(n.scale() <= 18);
+    assert_eq!(&Num::from_str(&n.to_string()).unwrap(), n);
+
+    if let Ok(value) = n.checked_to_u128() {
+      assert_eq!(&Num::from(value), n);
+      assert_eq!(n.truncate_to_u128().unwrap(), value);
+    }
+
+    if let Ok(value) = n.truncate_to_u128() {
+      assert!(&Num::from(value) <= n);
+    }
+
+    assert!(&Num::from_str(&n.truncate_to_str().unwrap()).unwrap() <= n);
+  }
+
+  let sum = a.checked_add(&b).unwrap();
+  assert!(sum >= a && sum >= b);
+  assert_eq!(sum.checked_sub(&b).unwrap(), a);
+
+  assert_eq!(a.checked_sub(&b).is_ok(), a >= b);
+
+  let product = a.checked_mul(&b).unwrap();
+  assert_eq!(product, b.checked_mul(&a).unwrap());
+
+  assert_eq!(a.checked_div(&b).is_ok(), b > Num::zero());
+});
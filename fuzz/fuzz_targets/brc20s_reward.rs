@@ -0,0 +1,82 @@
+#![no_main]
+
+use {
+  arbitrary::Arbitrary,
+  libfuzzer_sys::fuzz_target,
+  ord::fuzzing::brc20s::{
+    update_pool, withdraw_user_reward, Pid, PledgedTick, PoolInfo, PoolType, UserInfo,
+  },
+  std::str::FromStr,
+};
+
+#[derive(Clone, Debug, Arbitrary)]
+struct Input {
+  fixed: bool,
+  erate: u128,
+  minted: u128,
+  staked: u128,
+  dmax: u128,
+  acc_reward_per_share: u128,
+  last_update_block: u32,
+  staked_decimal: u8,
+  user_staked: u128,
+  reward_debt: u128,
+  pending_reward: u128,
+  blocks: Vec<u16>,
+}
+
+fuzz_target!(|input: Input| {
+  let pid = Pid::from_str("0123456789#01").unwrap();
+
+  let mut pool = PoolInfo::new(
+    &pid,
+    &if input.fixed {
+      PoolType::Fixed
+    } else {
+      PoolType::Pool
+    },
+    &"1111111111111111111111111111111111111111111111111111111111111111i1"
+      .parse()
+      .unwrap(),
+    &PledgedTick::Native,
+    input.erate,
+    input.minted.min(input.dmax),
+    input.staked,
+    input.dmax,
+    input.acc_reward_per_share.to_string(),
+    input.last_update_block.into(),
+    false,
+    0,
+    0,
+  );
+
+  let mut user = UserInfo {
+    pid: pid.clone(),
+    staked: input.user_staked.min(input.staked),
+    minted: 0,
+    pending_reward: input.pending_reward,
+    reward_debt: input.reward_debt,
+    latest_updated_block: input.last_update_block.into(),
+  };
+
+  let staked_decimal = input.staked_decimal % 19;
+
+  for delta in input.blocks {
+    let before = pool.clone();
+    let block = pool.last_update_block + u64::from(delta);
+
+    if update_pool(&mut pool, block, staked_decimal).is_err() {
+      pool = before;
+      continue;
+    }
+
+    assert!(pool.minted <= pool.dmax);
+    assert!(pool.minted >= before.minted);
+    assert_eq!(pool.last_update_block, block.max(before.last_update_block));
+
+    let pending_reward = user.pending_reward;
+    if let Ok(reward) = withdraw_user_reward(&mut user, &pool, staked_decimal) {
+      assert_eq!(user.pending_reward, pending_reward + reward);
+    }
+  }
+});
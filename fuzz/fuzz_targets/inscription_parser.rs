@@ -0,0 +1,67 @@
+#![no_main]
+
+use {
+  bitcoin::{
+    blockdata::{
+      opcodes,
+      script::{self, PushBytesBuf},
+    },
+    Witness,
+  },
+  libfuzzer_sys::fuzz_target,
+  ord::{fuzzing::InscriptionParser, Inscription},
+};
+
+fn push(builder: script::Builder, bytes: &[u8]) -> script::Builder {
+  builder.push_slice(PushBytesBuf::try_from(bytes.to_vec()).unwrap())
+}
+
+/// Reveals the inscription the way `ord wallet inscribe` does.
+fn witness(inscription: &Inscription) -> Witness {
+  let mut builder = push(
+    script::Builder::new()
+      .push_opcode(opcodes::OP_FALSE)
+      .push_opcode(opcodes::all::OP_IF),
+    b"ord",
+  );
+
+  for (tag, value) in [
+    (1, &inscription.content_type),
+    (3, &inscription.parent),
+    (5, &inscription.metadata),
+  ] {
+    if let Some(value) = value {
+      builder = push(push(builder, &[tag]), value);
+    }
+  }
+
+  if let Some(body) = &inscription.body {
+    builder = push(builder, &[]);
+    for chunk in body.chunks(520) {
+      builder = push(builder, chunk);
+    }
+  }
+
+  let mut witness = Witness::new();
+  witness.push(builder.push_opcode(opcodes::all::OP_ENDIF).into_script());
+  witness.push([]);
+  witness
+}
+
+fuzz_target!(|elements: Vec<Vec<u8>>| {
+  let Ok(inscriptions) = InscriptionParser::parse(&Witness::from_slice(&elements)) else {
+    return;
+  };
+
+  for inscription in inscriptions {
+    // unrecognized fields are dropped when revealing the inscription again.
+    if inscription.unrecognized_even_field {
+      continue;
+    }
+
+    assert_eq!(
+      InscriptionParser::parse(&witness(&inscription)).unwrap(),
+      [inscription]
+    );
+  }
+});
@@ -56,6 +56,25 @@ impl Chain {
     }
   }
 
+  pub(crate) fn first_brc20_self_mint_height(self) -> u64 {
+    match self {
+      Self::Mainnet => 837090,
+      Self::Regtest => 0,
+      Self::Signet => 0,
+      Self::Testnet => 0,
+    }
+  }
+
+  pub(crate) fn first_brc20_burn_height(self) -> u64 {
+    match self {
+      // not activated on mainnet yet.
+      Self::Mainnet => u64::MAX,
+      Self::Regtest => 0,
+      Self::Signet => 0,
+      Self::Testnet => 0,
+    }
+  }
+
   pub(crate) fn first_brc20s_height(self) -> u64 {
     match self {
       Self::Mainnet => 798108,
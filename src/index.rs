@@ -37,20 +37,31 @@ use {
   std::io::{BufWriter, Read, Write},
 };
 
-use crate::okx::datastore::ord::{bitmap::District, collections::CollectionKind};
+use crate::okx::datastore::ord::{
+  bitmap::{District, Parcel},
+  collections::CollectionKind,
+  names::{Name, Namespace},
+};
 
 pub(super) use self::{
   entry::{InscriptionEntry, InscriptionEntryValue},
+  export::{BRC20ExportKind, BRC20SExportKind, ExportFormat},
+  inscription_stats::{ContentTypeStats, InscriptionStats},
   updater::BlockData,
 };
 
+mod block_files;
+mod check;
+mod diff;
 mod entry;
+mod export;
 mod fetcher;
+mod inscription_stats;
 mod reorg;
 mod rtx;
 mod updater;
 
-const SCHEMA_VERSION: u64 = 6;
+const SCHEMA_VERSION: u64 = 20;
 
 macro_rules! define_table {
   ($name:ident, $key:ty, $value:ty) => {
@@ -68,13 +79,21 @@ macro_rules! define_multimap_table {
 define_multimap_table! { INSCRIPTION_ID_TO_CHILDREN, &InscriptionIdValue, &InscriptionIdValue }
 define_multimap_table! { SATPOINT_TO_INSCRIPTION_ID, &SatPointValue, &InscriptionIdValue }
 define_multimap_table! { SAT_TO_INSCRIPTION_ID, u64, &InscriptionIdValue }
+define_multimap_table! { SCRIPT_KEY_TO_RARE_SATS, &str, u64 }
+define_table! { CONTENT_TYPE_TO_INSCRIPTION_STATS, &str, (u64, u64) }
 define_table! { HEIGHT_TO_BLOCK_HASH, u64, &BlockHashValue }
+define_table! { HEIGHT_TO_INSCRIPTION_STATS, u64, &[u8] }
 define_table! { HEIGHT_TO_LAST_INSCRIPTION_NUMBER, u64, (i64, i64) }
 define_table! { INSCRIPTION_ID_TO_INSCRIPTION_ENTRY, &InscriptionIdValue, InscriptionEntryValue }
 define_table! { INSCRIPTION_ID_TO_SATPOINT, &InscriptionIdValue, &SatPointValue }
+define_table! { INSCRIPTION_NUMBER_TO_CONTENT_TYPE, i64, &str }
 define_table! { INSCRIPTION_NUMBER_TO_INSCRIPTION_ID, i64, &InscriptionIdValue }
+define_table! { NAKAMOTO_HEIGHTS, u64, () }
+define_table! { NAME_NAMESPACE_TO_RULES, &str, &str }
 define_table! { OUTPOINT_TO_SAT_RANGES, &OutPointValue, &[u8] }
 define_table! { OUTPOINT_TO_ENTRY, &OutPointValue, &[u8] }
+define_table! { PIZZA_SAT_RANGES, u64, u64 }
+define_table! { RARE_SAT_TO_SCRIPT_KEY, u64, &str }
 define_table! { REINSCRIPTION_ID_TO_SEQUENCE_NUMBER, &InscriptionIdValue, u64 }
 define_table! { SAT_TO_SATPOINT, u64, &SatPointValue }
 define_table! { STATISTIC_TO_COUNT, u64, u64 }
@@ -95,6 +114,14 @@ pub(crate) enum Statistic {
   OutputsTraversed = 3,
   SatRanges = 4,
   UnboundInscriptions = 5,
+  CursedInscriptions = 6,
+  CommitsByBlocks = 7,
+  CommitsByElapsed = 8,
+  CommitsByDirtyBytes = 9,
+  CommitsAtTip = 10,
+  CommitsFinished = 11,
+  NoTxindex = 12,
+  IndexBitmap = 13,
 }
 
 impl Statistic {
@@ -113,6 +140,8 @@ impl From<Statistic> for u64 {
 pub(crate) struct Info {
   pub(crate) blocks_indexed: u64,
   pub(crate) branch_pages: u64,
+  pub(crate) commits: u64,
+  pub(crate) commit_triggers: BTreeMap<String, u64>,
   pub(crate) fragmented_bytes: u64,
   pub(crate) index_file_size: u64,
   pub(crate) index_path: PathBuf,
@@ -171,6 +200,24 @@ pub(crate) struct Index {
 
 impl Index {
   pub(crate) fn open(options: &Options) -> Result<Self> {
+    // the BRC20 burn rules include the self mint ones.
+    if options.first_brc20_burn_height() < options.first_brc20_self_mint_height() {
+      bail!(
+        "first BRC20 burn height {} is below first BRC20 self mint height {}",
+        options.first_brc20_burn_height(),
+        options.first_brc20_self_mint_height(),
+      );
+    }
+
+    let name_namespaces = options
+      .index_names
+      .iter()
+      .map(|namespace| (namespace.name.clone(), namespace.to_string()))
+      .collect::<BTreeMap<String, String>>();
+    if name_namespaces.len() != options.index_names.len() {
+      bail!("duplicate namespace in --index-names");
+    }
+
     let client = options.bitcoin_rpc_client()?;
 
     let path = if let Some(path) = &options.index {
@@ -244,6 +291,51 @@ impl Index {
           }
         }
 
+        // names are only indexed from the blocks indexed after their namespace is configured.
+        let indexed_namespaces = database
+          .begin_read()?
+          .open_table(NAME_NAMESPACE_TO_RULES)?
+          .iter()?
+          .map(|result| {
+            result.map(|(name, rules)| (name.value().to_string(), rules.value().to_string()))
+          })
+          .collect::<Result<BTreeMap<String, String>, _>>()?;
+
+        if indexed_namespaces != name_namespaces {
+          bail!(
+            "index at `{}` was built with --index-names `{}`, rebuild the index to index names in `{}`",
+            path.display(),
+            indexed_namespaces.into_values().collect::<Vec<String>>().join(","),
+            name_namespaces.into_values().collect::<Vec<String>>().join(","),
+          );
+        }
+
+        // these options change what is indexed from genesis, so they can't change later.
+        for (statistic, enabled, flag) in [
+          (Statistic::NoTxindex, options.no_txindex, "--no-txindex"),
+          (
+            Statistic::IndexBitmap,
+            options.enable_index_bitmap,
+            "--enable-index-bitmap",
+          ),
+        ] {
+          let indexed = database
+            .begin_read()?
+            .open_table(STATISTIC_TO_COUNT)?
+            .get(&statistic.key())?
+            .map(|x| x.value())
+            .unwrap_or(0)
+            != 0;
+
+          if indexed != enabled {
+            bail!(
+              "index at `{}` was built {} {flag}, rebuild the index to change it",
+              path.display(),
+              if indexed { "with" } else { "without" },
+            );
+          }
+        }
+
         database
       }
       Err(_) => {
@@ -258,18 +350,40 @@ impl Index {
         tx.open_multimap_table(INSCRIPTION_ID_TO_CHILDREN)?;
         tx.open_multimap_table(SATPOINT_TO_INSCRIPTION_ID)?;
         tx.open_multimap_table(SAT_TO_INSCRIPTION_ID)?;
+        tx.open_multimap_table(SCRIPT_KEY_TO_RARE_SATS)?;
+        tx.open_table(CONTENT_TYPE_TO_INSCRIPTION_STATS)?;
         tx.open_table(HEIGHT_TO_BLOCK_HASH)?;
+        tx.open_table(HEIGHT_TO_INSCRIPTION_STATS)?;
         tx.open_table(HEIGHT_TO_LAST_INSCRIPTION_NUMBER)?;
         tx.open_table(INSCRIPTION_ID_TO_INSCRIPTION_ENTRY)?;
         tx.open_table(INSCRIPTION_ID_TO_SATPOINT)?;
+        tx.open_table(INSCRIPTION_NUMBER_TO_CONTENT_TYPE)?;
         tx.open_table(INSCRIPTION_NUMBER_TO_INSCRIPTION_ID)?;
+        tx.open_table(NAKAMOTO_HEIGHTS)?;
+        tx.open_table(NAME_NAMESPACE_TO_RULES)?;
         tx.open_table(OUTPOINT_TO_ENTRY)?;
+        tx.open_table(PIZZA_SAT_RANGES)?;
+        tx.open_table(RARE_SAT_TO_SCRIPT_KEY)?;
         tx.open_table(REINSCRIPTION_ID_TO_SEQUENCE_NUMBER)?;
         tx.open_table(SAT_TO_SATPOINT)?;
         tx.open_table(WRITE_TRANSACTION_STARTING_BLOCK_COUNT_TO_TIMESTAMP)?;
 
-        tx.open_table(STATISTIC_TO_COUNT)?
-          .insert(&Statistic::Schema.key(), &SCHEMA_VERSION)?;
+        {
+          let mut statistic_to_count = tx.open_table(STATISTIC_TO_COUNT)?;
+          statistic_to_count.insert(&Statistic::Schema.key(), &SCHEMA_VERSION)?;
+          statistic_to_count.insert(&Statistic::NoTxindex.key(), &u64::from(options.no_txindex))?;
+          statistic_to_count.insert(
+            &Statistic::IndexBitmap.key(),
+            &u64::from(options.enable_index_bitmap),
+          )?;
+        }
+
+        {
+          let mut name_namespace_to_rules = tx.open_table(NAME_NAMESPACE_TO_RULES)?;
+          for (name, rules) in &name_namespaces {
+            name_namespace_to_rules.insert(name.as_str(), rules.as_str())?;
+          }
+        }
 
         if options.index_sats {
           tx.open_table(OUTPOINT_TO_SAT_RANGES)?
@@ -311,6 +425,10 @@ impl Index {
     self.options.chain().network()
   }
 
+  pub(crate) fn get_name_namespaces(&self) -> &[Namespace] {
+    &self.options.index_names
+  }
+
   #[cfg(test)]
   fn set_durability(&mut self, durability: redb::Durability) {
     self.durability = durability;
@@ -412,6 +530,20 @@ impl Index {
         .get(&Statistic::OutputsTraversed.key())?
         .map(|x| x.value())
         .unwrap_or(0);
+      let commits = statistic_to_count
+        .get(&Statistic::Commits.key())?
+        .map(|x| x.value())
+        .unwrap_or(0);
+      let mut commit_triggers = BTreeMap::new();
+      for trigger in updater::CommitTrigger::ALL {
+        commit_triggers.insert(
+          trigger.name().to_string(),
+          statistic_to_count
+            .get(&trigger.statistic().key())?
+            .map(|x| x.value())
+            .unwrap_or(0),
+        );
+      }
       Info {
         index_path: self.path.clone(),
         blocks_indexed: wtx
@@ -422,6 +554,8 @@ impl Index {
           .map(|(height, _hash)| height.value() + 1)
           .unwrap_or(0),
         branch_pages: stats.branch_pages(),
+        commits,
+        commit_triggers,
         fragmented_bytes: stats.fragmented_bytes(),
         index_file_size: fs::metadata(&self.path)?.len(),
         leaf_pages: stats.leaf_pages(),
@@ -478,17 +612,23 @@ impl Index {
     }
   }
 
+  fn blocks_indexed(rtx: &redb::ReadTransaction) -> Result<u64> {
+    Ok(
+      rtx
+        .open_table(HEIGHT_TO_BLOCK_HASH)?
+        .range(0..)?
+        .next_back()
+        .and_then(|result| result.ok())
+        .map(|(height, _hash)| height.value() + 1)
+        .unwrap_or(0),
+    )
+  }
+
   pub(crate) fn export(&self, filename: &String, include_addresses: bool) -> Result {
     let mut writer = BufWriter::new(File::create(filename)?);
     let rtx = self.database.begin_read()?;
 
-    let blocks_indexed = rtx
-      .open_table(HEIGHT_TO_BLOCK_HASH)?
-      .range(0..)?
-      .next_back()
-      .and_then(|result| result.ok())
-      .map(|(height, _hash)| height.value() + 1)
-      .unwrap_or(0);
+    let blocks_indexed = Self::blocks_indexed(&rtx)?;
 
     writeln!(writer, "# export at block height {}", blocks_indexed)?;
 
@@ -663,6 +803,37 @@ impl Index {
     }
   }
 
+  /// The satributes of the sat, including the ones only known from the sat index.
+  pub(crate) fn satributes(&self, sat: Sat) -> Result<Vec<Satribute>> {
+    let mut satributes = sat.satributes();
+
+    if !self.has_sat_index()? {
+      return Ok(satributes);
+    }
+
+    let rtx = self.database.begin_read()?;
+
+    if rtx
+      .open_table(NAKAMOTO_HEIGHTS)?
+      .get(&sat.height().n())?
+      .is_some()
+    {
+      satributes.push(Satribute::Nakamoto);
+    }
+
+    if rtx
+      .open_table(PIZZA_SAT_RANGES)?
+      .range(..=sat.n())?
+      .next_back()
+      .transpose()?
+      .is_some_and(|(_, end)| sat.n() < end.value())
+    {
+      satributes.push(Satribute::Pizza);
+    }
+
+    Ok(satributes)
+  }
+
   pub(crate) fn rare_sat_satpoint(&self, sat: Sat) -> Result<Option<SatPoint>> {
     if self.has_sat_index()? {
       Ok(
@@ -678,6 +849,35 @@ impl Index {
     }
   }
 
+  /// Returns the sats that are uncommon or rarer held by the address, with their satpoints.
+  pub(crate) fn get_rare_sats_by_address(
+    &self,
+    address: &bitcoin::Address,
+  ) -> Result<Option<Vec<(Sat, SatPoint)>>> {
+    if !self.has_sat_index()? {
+      return Ok(None);
+    }
+
+    let rtx = self.database.begin_read()?;
+    let sat_to_satpoint = rtx.open_table(SAT_TO_SATPOINT)?;
+
+    let mut result = Vec::new();
+    for sat in rtx.open_multimap_table(SCRIPT_KEY_TO_RARE_SATS)?.get(
+      ScriptKey::from_address(address.clone())
+        .to_string()
+        .as_str(),
+    )? {
+      let sat = sat?.value();
+      let satpoint = sat_to_satpoint
+        .get(&sat)?
+        .map(|satpoint| Entry::load(*satpoint.value()))
+        .ok_or_else(|| anyhow!("failed to get satpoint of rare sat {sat}"))?;
+      result.push((Sat(sat), satpoint));
+    }
+
+    Ok(Some(result))
+  }
+
   pub(crate) fn block_header(&self, hash: BlockHash) -> Result<Option<Header>> {
     self.client.get_block_header(&hash).into_option()
   }
@@ -705,6 +905,55 @@ impl Index {
     self.client.get_block_info(&hash).into_option()
   }
 
+  pub(crate) fn get_inscription_stats(&self) -> Result<InscriptionStats> {
+    let rtx = self.database.begin_read()?;
+
+    let mut content_types = BTreeMap::new();
+    for result in rtx.open_table(CONTENT_TYPE_TO_INSCRIPTION_STATS)?.iter()? {
+      let (content_type, value) = result?;
+      let (count, bytes) = value.value();
+      content_types.insert(
+        content_type.value().to_string(),
+        ContentTypeStats { count, bytes },
+      );
+    }
+
+    let statistic_to_count = rtx.open_table(STATISTIC_TO_COUNT)?;
+    let statistic = |statistic: Statistic| -> Result<u64> {
+      Ok(
+        statistic_to_count
+          .get(&statistic.key())?
+          .map(|x| x.value())
+          .unwrap_or(0),
+      )
+    };
+
+    Ok(InscriptionStats {
+      content_types,
+      cursed: statistic(Statistic::CursedInscriptions)?,
+      unbound: statistic(Statistic::UnboundInscriptions)?,
+    })
+  }
+
+  pub(crate) fn get_block_inscription_stats(
+    &self,
+    height: u64,
+  ) -> Result<Option<InscriptionStats>> {
+    let rtx = self.database.begin_read()?;
+
+    if rtx.open_table(HEIGHT_TO_BLOCK_HASH)?.get(height)?.is_none() {
+      return Ok(None);
+    }
+
+    let stats = rtx
+      .open_table(HEIGHT_TO_INSCRIPTION_STATS)?
+      .get(height)?
+      .map(|stats| bincode::deserialize(stats.value()).unwrap())
+      .unwrap_or_default();
+
+    Ok(Some(stats))
+  }
+
   pub(crate) fn get_children_by_inscription_id(
     &self,
     inscription_id: InscriptionId,
@@ -797,6 +1046,159 @@ impl Index {
     )
   }
 
+  pub(crate) fn ord_get_inscriptions_by_address(
+    &self,
+    address: &bitcoin::Address,
+  ) -> Result<Vec<(i64, InscriptionId)>> {
+    let rtx = self.database.begin_read()?;
+    let number_to_id = rtx.open_table(INSCRIPTION_NUMBER_TO_INSCRIPTION_ID)?;
+
+    let mut inscriptions = Vec::new();
+    for number in ord::OrdDbReader::new(&rtx)
+      .get_inscription_numbers_by_script_key(&ScriptKey::from_address(address.clone()))?
+    {
+      let id = number_to_id
+        .get(&number)?
+        .map(|id| Entry::load(*id.value()))
+        .ok_or_else(|| anyhow!("failed to get inscription id of number {number}"))?;
+      inscriptions.push((number, id));
+    }
+    Ok(inscriptions)
+  }
+
+  /// Returns the content types of the inscriptions, in the order of their numbers.
+  pub(crate) fn ord_get_inscription_content_types(
+    &self,
+    numbers: impl IntoIterator<Item = i64>,
+  ) -> Result<Vec<Option<String>>> {
+    let rtx = self.database.begin_read()?;
+    let number_to_content_type = rtx.open_table(INSCRIPTION_NUMBER_TO_CONTENT_TYPE)?;
+
+    let mut content_types = Vec::new();
+    for number in numbers {
+      content_types.push(
+        number_to_content_type
+          .get(number)?
+          .map(|content_type| content_type.value().to_string()),
+      );
+    }
+    Ok(content_types)
+  }
+
+  pub(crate) fn ord_get_name_inscription_id(&self, name: &Name) -> Result<Option<InscriptionId>> {
+    Ok(
+      ord::OrdDbReader::new(&self.database.begin_read()?)
+        .get_collection_inscription_id(&name.to_collection_key())?,
+    )
+  }
+
+  /// Returns the names registered by the inscriptions owned by the address.
+  pub(crate) fn ord_get_names_by_address(
+    &self,
+    address: &bitcoin::Address,
+  ) -> Result<Vec<(Name, i64, InscriptionId)>> {
+    let mut names = Vec::new();
+    for (number, id) in self.ord_get_inscriptions_by_address(address)? {
+      if !self
+        .ord_get_collections_by_inscription_id(id)?
+        .unwrap_or_default()
+        .contains(&CollectionKind::Name)
+      {
+        continue;
+      }
+
+      let Some(name) = self
+        .get_inscription_by_id(id)?
+        .and_then(|inscription| {
+          inscription
+            .body()
+            .map(|content| Name::parse(content, self.get_name_namespaces()))
+        })
+        .and_then(|name| name.ok())
+      else {
+        continue;
+      };
+
+      // the namespaces may have changed since the name was registered.
+      if self.ord_get_name_inscription_id(&name)? == Some(id) {
+        names.push((name, number, id));
+      }
+    }
+    Ok(names)
+  }
+
+  /// Returns the bitmap districts and parcels registered by the inscriptions owned by the address.
+  pub(crate) fn ord_get_bitmap_by_address(
+    &self,
+    address: &bitcoin::Address,
+  ) -> Result<(
+    Vec<(District, i64, InscriptionId)>,
+    Vec<(Parcel, i64, InscriptionId)>,
+  )> {
+    let mut districts = Vec::new();
+    let mut parcels = Vec::new();
+    for (number, id) in self.ord_get_inscriptions_by_address(address)? {
+      let collections = self
+        .ord_get_collections_by_inscription_id(id)?
+        .unwrap_or_default();
+      if !collections.contains(&CollectionKind::BitMap)
+        && !collections.contains(&CollectionKind::BitMapParcel)
+      {
+        continue;
+      }
+
+      let Some(inscription) = self.get_inscription_by_id(id)? else {
+        continue;
+      };
+      let content = inscription.body().unwrap_or_default();
+
+      if collections.contains(&CollectionKind::BitMap) {
+        if let Ok(district) = District::parse(content) {
+          districts.push((district, number, id));
+        }
+      } else if let Ok(parcel) = Parcel::parse(content) {
+        parcels.push((parcel, number, id));
+      }
+    }
+    Ok((districts, parcels))
+  }
+
+  /// Returns the number of verified children of the inscription.
+  pub(crate) fn ord_get_children_count(&self, parent: InscriptionId) -> Result<u64> {
+    Ok(ord::OrdDbReader::new(&self.database.begin_read()?).get_children_count(parent)?)
+  }
+
+  /// Returns up to `limit` verified children of the inscription in inscription order, skipping
+  /// the first `start`, along with the number of children.
+  pub(crate) fn ord_get_children(
+    &self,
+    parent: InscriptionId,
+    start: u64,
+    limit: u64,
+  ) -> Result<(Vec<InscriptionId>, u64)> {
+    let rtx = self.database.begin_read()?;
+    let reader = ord::OrdDbReader::new(&rtx);
+    Ok((
+      reader.get_children(parent, start, limit)?,
+      reader.get_children_count(parent)?,
+    ))
+  }
+
+  pub(crate) fn ord_get_inscription_history(
+    &self,
+    inscription_id: InscriptionId,
+    start: usize,
+    limit: usize,
+  ) -> Result<Vec<ord::InscriptionTransfer>> {
+    Ok(
+      ord::OrdDbReader::new(&self.database.begin_read()?).get_inscription_history(
+        inscription_id,
+        start,
+        limit,
+      )?,
+    )
+  }
+
   pub(crate) fn get_inscription_by_id(
     &self,
     inscription_id: InscriptionId,
@@ -1400,6 +1802,12 @@ impl Index {
     Ok(info)
   }
 
+  pub(crate) fn brc20_search_ticks(&self, keyword: &str, limit: usize) -> Result<Vec<brc20::Tick>> {
+    let rtx = self.database.begin_read()?;
+    let brc20_db = brc20_db::DataStoreReader::new(&rtx);
+    Ok(brc20_db.search_ticks(keyword, limit)?)
+  }
+
   pub(crate) fn brc20_get_balance_by_address(
     &self,
     tick: &brc20::Tick,
@@ -1519,12 +1927,45 @@ impl Index {
     Ok(info)
   }
 
+  pub(crate) fn brc20s_search_ticks(
+    &self,
+    keyword: &str,
+    limit: usize,
+  ) -> Result<Vec<(brc20s::Tick, brc20s::TickId)>> {
+    let rtx = self.database.begin_read()?;
+    let brc20s_db = brc20s_db::DataStoreReader::new(&rtx);
+    Ok(brc20s_db.search_ticks(keyword, limit)?)
+  }
+
+  pub(crate) fn brc20s_tick_ids_by_name(&self, name: &str) -> Result<Vec<brc20s::TickId>> {
+    let rtx = self.database.begin_read()?;
+    let brc20s_db = brc20s_db::DataStoreReader::new(&rtx);
+    Ok(brc20s_db.get_tick_ids_by_name(name)?)
+  }
+
   pub(crate) fn brc20s_pool_info(&self, pid: &brc20s::Pid) -> Result<Option<brc20s::PoolInfo>> {
     let rtx = self.database.begin_read().unwrap();
     let brc20s_db = brc20s_db::DataStoreReader::new(&rtx);
     let info = brc20s_db.get_pid_to_poolinfo(pid)?;
     Ok(info)
   }
+
+  /// Returns the snapshots of the pool between the blocks `from` and `to`, both inclusive.
+  pub(crate) fn brc20s_pool_history(
+    &self,
+    pid: &brc20s::Pid,
+    from: u64,
+    to: u64,
+    limit: usize,
+  ) -> Result<Option<Vec<brc20s::PoolSnapshot>>> {
+    let rtx = self.database.begin_read().unwrap();
+    let brc20s_db = brc20s_db::DataStoreReader::new(&rtx);
+    if brc20s_db.get_pid_to_poolinfo(pid)?.is_none() {
+      return Ok(None);
+    }
+    Ok(Some(brc20s_db.get_pool_snapshots(pid, from, to, limit)?))
+  }
+
   pub(crate) fn brc20s_stake_info(
     &self,
     address: &bitcoin::Address,
@@ -1586,12 +2027,7 @@ impl Index {
       .get_pid_to_poolinfo(pid)?
       .ok_or(anyhow!("pool info not found from state!"))?;
 
-    let dec = match pool_info.clone().stake {
-      PledgedTick::Native => NATIVE_TOKEN_DECIMAL,
-      PledgedTick::BRC20STick(tickid) => brc20s_db.get_tick_info(&tickid).unwrap().unwrap().decimal,
-      PledgedTick::BRC20Tick(tick) => brc20_db.get_token_info(&tick).unwrap().unwrap().decimal,
-      PledgedTick::Unknown => 0_u8,
-    };
+    let dec = Self::brc20s_stake_decimal(&brc20s_db, &brc20_db, &pool_info.stake);
 
     let block = self.height().unwrap().unwrap_or(Height(0)).n();
 
@@ -1605,6 +2041,108 @@ impl Index {
     Ok((Some(result.to_string()), Some(block.to_string())))
   }
 
+  /// Returns the users of the pool with their pending reward at the latest indexed block, and
+  /// the height of that block.
+  pub(crate) fn brc20s_pool_users(
+    &self,
+    pid: &brc20s::Pid,
+  ) -> Result<Option<(u64, Vec<(ScriptKey, brc20s::UserInfo, u128)>)>> {
+    let rtx = self.database.begin_read()?;
+    let brc20s_db = brc20s_db::DataStoreReader::new(&rtx);
+    let brc20_db = brc20_db::DataStoreReader::new(&rtx);
+    let Some(pool_info) = brc20s_db.get_pid_to_poolinfo(pid)? else {
+      return Ok(None);
+    };
+
+    let dec = Self::brc20s_stake_decimal(&brc20s_db, &brc20_db, &pool_info.stake);
+
+    let block = self.height()?.unwrap_or(Height(0)).n();
+
+    let mut users = Vec::new();
+    for (script_key, user_info) in brc20s_db.get_pid_to_users(pid)? {
+      // users who withdrew everything keep the reward settled at their last withdrawal.
+      let pending_reward = if user_info.staked == 0 {
+        user_info.pending_reward
+      } else {
+        user_info
+          .pending_reward
+          .checked_add(reward::query_reward(
+            user_info.clone(),
+            pool_info.clone(),
+            block,
+            dec,
+          )?)
+          .ok_or_else(|| {
+            anyhow!(
+              "pending reward of {script_key} overflows in pool {}",
+              pid.as_str()
+            )
+          })?
+      };
+      users.push((script_key, user_info, pending_reward));
+    }
+
+    Ok(Some((block, users)))
+  }
+
+  /// Projects the rewards of a deposit in the next block, returning its height with the pool
+  /// and user info before the deposit. The projection fails if the deposit or the number of
+  /// blocks overflow the reward computation.
+  pub(crate) fn brc20s_reward_projection(
+    &self,
+    pid: &brc20s::Pid,
+    address: &bitcoin::Address,
+    deposit: u128,
+    blocks: u64,
+  ) -> Result<
+    Option<(
+      u64,
+      brc20s::PoolInfo,
+      brc20s::UserInfo,
+      Result<reward::RewardProjection, crate::okx::protocol::brc20s::BRC20SError>,
+    )>,
+  > {
+    let rtx = self.database.begin_read()?;
+    let brc20s_db = brc20s_db::DataStoreReader::new(&rtx);
+    let brc20_db = brc20_db::DataStoreReader::new(&rtx);
+
+    let Some(pool_info) = brc20s_db.get_pid_to_poolinfo(pid)? else {
+      return Ok(None);
+    };
+
+    let user_info = brc20s_db
+      .get_pid_to_use_info(&ScriptKey::from_address(address.clone()), pid)?
+      .unwrap_or_else(|| brc20s::UserInfo::default(pid));
+
+    let dec = Self::brc20s_stake_decimal(&brc20s_db, &brc20_db, &pool_info.stake);
+
+    let block = Self::blocks_indexed(&rtx)?;
+
+    let projection = reward::project_reward(
+      user_info.clone(),
+      pool_info.clone(),
+      block,
+      deposit,
+      blocks,
+      dec,
+    );
+
+    Ok(Some((block, pool_info, user_info, projection)))
+  }
+
+  fn brc20s_stake_decimal(
+    brc20s_db: &brc20s_db::DataStoreReader,
+    brc20_db: &brc20_db::DataStoreReader,
+    stake: &PledgedTick,
+  ) -> u8 {
+    match stake {
+      PledgedTick::Native => NATIVE_TOKEN_DECIMAL,
+      PledgedTick::BRC20STick(tickid) => brc20s_db.get_tick_info(tickid).unwrap().unwrap().decimal,
+      PledgedTick::BRC20Tick(tick) => brc20_db.get_token_info(tick).unwrap().unwrap().decimal,
+      PledgedTick::Unknown => 0_u8,
+    }
+  }
+
   pub(crate) fn brc20s_balance(
     &self,
     tick_id: &brc20s::TickId,
@@ -1856,6 +2394,51 @@ mod tests {
     }
   }
 
+  #[test]
+  fn blocks_fetched_by_multiple_workers_are_indexed_in_order() {
+    for workers in ["1", "8"] {
+      let context = Context::builder()
+        .args(["--index-sats", "--block-fetch-workers", workers])
+        .build();
+      let blocks = context.mine_blocks(50);
+      assert_eq!(context.index.block_count().unwrap(), 51);
+      assert_eq!(
+        context.index.block_hash(Some(50)).unwrap(),
+        Some(blocks[49].block_hash())
+      );
+
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+        ..Default::default()
+      });
+      context.mine_blocks(1);
+      assert_eq!(
+        context
+          .index
+          .get_inscription_entry(InscriptionId { txid, index: 0 })
+          .unwrap()
+          .unwrap()
+          .height,
+        51
+      );
+    }
+  }
+
+  #[test]
+  fn commit_triggers_are_tracked() {
+    let context = Context::builder()
+      .args(["--commit-interval", "2", "--commit-tip-distance", "0"])
+      .build();
+    context.mine_blocks(5);
+
+    let info = context.index.info().unwrap();
+    assert_eq!(info.commit_triggers["blocks"], 2);
+    assert_eq!(info.commit_triggers["tip"], 2);
+    assert_eq!(info.commit_triggers["elapsed"], 0);
+    assert_eq!(info.commit_triggers["dirty_bytes"], 0);
+    assert_eq!(info.commit_triggers["finished"], 0);
+  }
+
   #[test]
   fn inscriptions_below_first_inscription_height_are_skipped() {
     let inscription = inscription("text/plain;charset=utf-8", "hello");
@@ -2840,6 +3423,209 @@ mod tests {
     );
   }
 
+  #[test]
+  fn pizza_sats_are_looked_up_in_the_sat_index() {
+    let context = Context::builder().arg("--index-sats").build();
+
+    let wtx = context.index.database.begin_write().unwrap();
+    wtx
+      .open_table(PIZZA_SAT_RANGES)
+      .unwrap()
+      .insert(&100, &200)
+      .unwrap();
+    wtx.commit().unwrap();
+
+    assert!(!context
+      .index
+      .satributes(Sat(99))
+      .unwrap()
+      .contains(&Satribute::Pizza));
+    assert_eq!(
+      context.index.satributes(Sat(100)).unwrap(),
+      [Satribute::Vintage, Satribute::Pizza]
+    );
+    assert!(context
+      .index
+      .satributes(Sat(199))
+      .unwrap()
+      .contains(&Satribute::Pizza));
+    assert!(!context
+      .index
+      .satributes(Sat(200))
+      .unwrap()
+      .contains(&Satribute::Pizza));
+  }
+
+  #[test]
+  fn nakamoto_sats_are_looked_up_in_the_sat_index() {
+    let context = Context::builder().arg("--index-sats").build();
+
+    let wtx = context.index.database.begin_write().unwrap();
+    wtx
+      .open_table(NAKAMOTO_HEIGHTS)
+      .unwrap()
+      .insert(&1, &())
+      .unwrap();
+    wtx.commit().unwrap();
+
+    assert!(!context
+      .index
+      .satributes(Sat(50 * COIN_VALUE - 1))
+      .unwrap()
+      .contains(&Satribute::Nakamoto));
+    assert_eq!(
+      context.index.satributes(Sat(50 * COIN_VALUE + 1)).unwrap(),
+      [Satribute::Vintage, Satribute::Nakamoto]
+    );
+    assert!(context
+      .index
+      .satributes(Sat(100 * COIN_VALUE - 1))
+      .unwrap()
+      .contains(&Satribute::Nakamoto));
+  }
+
+  #[test]
+  fn brc20_burn_height_below_self_mint_height_is_rejected() {
+    assert_eq!(
+      Context::builder()
+        .args([
+          "--first-brc20-self-mint-height",
+          "10",
+          "--first-brc20-burn-height",
+          "9"
+        ])
+        .try_build()
+        .err()
+        .unwrap()
+        .to_string(),
+      "first BRC20 burn height 9 is below first BRC20 self mint height 10"
+    );
+
+    Context::builder()
+      .args([
+        "--first-brc20-self-mint-height",
+        "10",
+        "--first-brc20-burn-height",
+        "10",
+      ])
+      .build();
+  }
+
+  #[test]
+  fn name_namespaces_are_fixed_when_the_index_is_created() {
+    let tempdir = Context::builder()
+      .args(["--index-names", "sats:any:64,btc:hostname:63"])
+      .build()
+      .tempdir;
+
+    let path = tempdir.path().to_owned();
+    let delimiter = if cfg!(windows) { '\\' } else { '/' };
+
+    // the order of the namespaces doesn't matter.
+    let tempdir = Context::builder()
+      .tempdir(tempdir)
+      .args(["--index-names", "btc:hostname:63,sats:any:64"])
+      .build()
+      .tempdir;
+
+    assert_eq!(
+      Context::builder()
+        .tempdir(tempdir)
+        .args(["--index-names", "sats:any:32,btc:hostname:63"])
+        .try_build()
+        .err()
+        .unwrap()
+        .to_string(),
+      format!(
+        "index at `{}{delimiter}regtest{delimiter}index.redb` was built with --index-names `btc:hostname:63,sats:any:64`, rebuild the index to index names in `btc:hostname:63,sats:any:32`",
+        path.display()
+      )
+    );
+
+    assert_eq!(
+      Context::builder()
+        .args(["--index-names", "sats:any:64,sats:any:32"])
+        .try_build()
+        .err()
+        .unwrap()
+        .to_string(),
+      "duplicate namespace in --index-names"
+    );
+  }
+
+  #[test]
+  fn no_txindex_is_fixed_when_the_index_is_created() {
+    let tempdir = Context::builder().args(["--no-txindex"]).build().tempdir;
+
+    let path = tempdir.path().to_owned();
+    let delimiter = if cfg!(windows) { '\\' } else { '/' };
+
+    let tempdir = Context::builder()
+      .tempdir(tempdir)
+      .args(["--no-txindex"])
+      .build()
+      .tempdir;
+
+    assert_eq!(
+      Context::builder()
+        .tempdir(tempdir)
+        .try_build()
+        .err()
+        .unwrap()
+        .to_string(),
+      format!(
+        "index at `{}{delimiter}regtest{delimiter}index.redb` was built with --no-txindex, rebuild the index to change it",
+        path.display()
+      )
+    );
+  }
+
+  #[test]
+  fn index_bitmap_is_fixed_when_the_index_is_created() {
+    let tempdir = Context::builder().build().tempdir;
+
+    let path = tempdir.path().to_owned();
+    let delimiter = if cfg!(windows) { '\\' } else { '/' };
+
+    assert_eq!(
+      Context::builder()
+        .tempdir(tempdir)
+        .args(["--enable-index-bitmap"])
+        .try_build()
+        .err()
+        .unwrap()
+        .to_string(),
+      format!(
+        "index at `{}{delimiter}regtest{delimiter}index.redb` was built without --enable-index-bitmap, rebuild the index to change it",
+        path.display()
+      )
+    );
+  }
+
+  #[test]
+  fn no_txindex_indexes_inscriptions_from_indexed_outputs() {
+    let context = Context::builder().args(["--no-txindex"]).build();
+
+    context.mine_blocks(1);
+
+    let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..Default::default()
+    });
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    context.mine_blocks(1);
+
+    context.index.assert_inscription_location(
+      inscription_id,
+      SatPoint {
+        outpoint: OutPoint { txid, vout: 0 },
+        offset: 0,
+      },
+      None,
+    );
+  }
+
   #[test]
   fn old_schema_gives_correct_error() {
     let tempdir = {
@@ -4222,6 +5008,7 @@ mod tests {
             body: Some("hello".into()),
             parent: Some(parent_inscription_id.parent_value()),
             unrecognized_even_field: false,
+            metadata: None,
           }
           .to_witness(),
         )],
@@ -4269,6 +5056,7 @@ mod tests {
             body: Some("hello".into()),
             parent: Some(parent_inscription_id.parent_value()),
             unrecognized_even_field: false,
+            metadata: None,
           }
           .to_witness(),
         )],
@@ -4329,6 +5117,7 @@ mod tests {
               body: Some("hello".into()),
               parent: Some(parent_inscription_id.parent_value()),
               unrecognized_even_field: false,
+              metadata: None,
             }
             .to_witness(),
           ),
@@ -4389,6 +5178,7 @@ mod tests {
               body: Some("hello".into()),
               parent: Some(parent_inscription_id.parent_value()),
               unrecognized_even_field: false,
+              metadata: None,
             }
             .to_witness(),
           ),
@@ -4455,6 +5245,7 @@ mod tests {
                 .collect(),
             ),
             unrecognized_even_field: false,
+            metadata: None,
           }
           .to_witness(),
         )],
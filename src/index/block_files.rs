@@ -0,0 +1,271 @@
+use {
+  super::*,
+  bitcoin::{block::Header, consensus::Decodable},
+  std::{
+    fs,
+    io::{Seek, SeekFrom},
+  },
+};
+
+const HEADER_SIZE: usize = 80;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct BlockLocation {
+  file: u32,
+  offset: u64,
+  size: u32,
+}
+
+/// Reads blocks directly from Bitcoin Core's `blocks/blk*.dat` files.
+///
+/// The height of each block is recovered without Bitcoin Core's block index, by following the
+/// previous block hashes of the headers stored in the files back from a known block hash.
+pub(crate) struct BlockFiles {
+  dir: PathBuf,
+  xor_key: Option<[u8; 8]>,
+  locations: Vec<(BlockHash, BlockLocation)>,
+}
+
+impl BlockFiles {
+  /// Scans the block files in `dir` and maps the heights from genesis up to the block `tip`.
+  pub(crate) fn open(dir: &Path, network: Network, tip: BlockHash) -> Result<Self> {
+    let xor_key = Self::read_xor_key(dir)?;
+    let magic = network.magic().to_bytes();
+
+    let mut files = fs::read_dir(dir)?
+      .filter_map(|entry| {
+        let name = entry.ok()?.file_name().into_string().ok()?;
+        name
+          .strip_prefix("blk")?
+          .strip_suffix(".dat")?
+          .parse::<u32>()
+          .ok()
+      })
+      .collect::<Vec<u32>>();
+    files.sort();
+
+    let mut blocks = HashMap::new();
+    for file in files {
+      let mut reader = File::open(Self::file_path(dir, file))?;
+      let len = reader.metadata()?.len();
+      let mut offset = 0;
+      // records are a 4 byte network magic, a 4 byte little endian size and the serialized block.
+      while offset + 8 + HEADER_SIZE as u64 <= len {
+        let mut prefix = [0; 8 + HEADER_SIZE];
+        Self::read_at(&mut reader, xor_key, offset, &mut prefix)?;
+
+        // block files are preallocated, the remaining bytes are zeroes.
+        if prefix[..4] != magic {
+          break;
+        }
+
+        let size = u32::from_le_bytes(prefix[4..8].try_into().unwrap());
+        let header = Header::consensus_decode(&mut &prefix[8..])?;
+        blocks.insert(
+          header.block_hash(),
+          (
+            header.prev_blockhash,
+            BlockLocation {
+              file,
+              offset: offset + 8,
+              size,
+            },
+          ),
+        );
+
+        offset += 8 + u64::from(size);
+      }
+    }
+
+    let mut locations = Vec::new();
+    let mut hash = tip;
+    loop {
+      let (prev_blockhash, location) = blocks
+        .get(&hash)
+        .ok_or_else(|| anyhow!("block {hash} not found in block files"))?;
+      locations.push((hash, *location));
+      if *prev_blockhash == BlockHash::all_zeros() {
+        break;
+      }
+      hash = *prev_blockhash;
+    }
+    locations.reverse();
+
+    log::info!(
+      "Found {} blocks in {} block files, {} blocks on the best chain",
+      blocks.len(),
+      dir.display(),
+      locations.len(),
+    );
+
+    Ok(Self {
+      dir: dir.to_owned(),
+      xor_key,
+      locations,
+    })
+  }
+
+  /// The number of blocks that can be read, starting from genesis.
+  pub(crate) fn len(&self) -> u64 {
+    self.locations.len().try_into().unwrap()
+  }
+
+  /// Reads the block at `height`. Only the header is read unless `txdata` is set.
+  pub(crate) fn read_block(&self, height: u64, txdata: bool) -> Result<Block> {
+    let (hash, location) = self
+      .locations
+      .get(usize::try_from(height).unwrap())
+      .ok_or_else(|| anyhow!("block {height} not found in block files"))?;
+
+    let mut reader = File::open(Self::file_path(&self.dir, location.file))?;
+
+    let block = if txdata {
+      let mut buffer = vec![0; usize::try_from(location.size).unwrap()];
+      Self::read_at(&mut reader, self.xor_key, location.offset, &mut buffer)?;
+      Block::consensus_decode(&mut buffer.as_slice())?
+    } else {
+      let mut buffer = [0; HEADER_SIZE];
+      Self::read_at(&mut reader, self.xor_key, location.offset, &mut buffer)?;
+      Block {
+        header: Header::consensus_decode(&mut buffer.as_slice())?,
+        txdata: Vec::new(),
+      }
+    };
+
+    if block.block_hash() != *hash {
+      bail!(
+        "block {height} in block files has hash {}, expected {hash}",
+        block.block_hash()
+      );
+    }
+
+    Ok(block)
+  }
+
+  fn file_path(dir: &Path, file: u32) -> PathBuf {
+    dir.join(format!("blk{file:05}.dat"))
+  }
+
+  /// Bitcoin Core 28.0 and later obfuscate block files with the key in `xor.dat`.
+  fn read_xor_key(dir: &Path) -> Result<Option<[u8; 8]>> {
+    let path = dir.join("xor.dat");
+    if !path.exists() {
+      return Ok(None);
+    }
+
+    let key: [u8; 8] = fs::read(&path)?
+      .try_into()
+      .map_err(|_| anyhow!("invalid xor key in {}", path.display()))?;
+
+    Ok(if key == [0; 8] { None } else { Some(key) })
+  }
+
+  fn read_at(
+    reader: &mut File,
+    xor_key: Option<[u8; 8]>,
+    offset: u64,
+    buffer: &mut [u8],
+  ) -> Result {
+    reader.seek(SeekFrom::Start(offset))?;
+    reader.read_exact(buffer)?;
+
+    if let Some(key) = xor_key {
+      for (i, byte) in buffer.iter_mut().enumerate() {
+        *byte ^= key[usize::try_from((offset + i as u64) % 8).unwrap()];
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, bitcoin::consensus::Encodable, tempfile::TempDir};
+
+  fn child(parent: &Block, nonce: u32) -> Block {
+    let mut block = parent.clone();
+    block.header.prev_blockhash = parent.block_hash();
+    block.header.nonce = nonce;
+    block
+  }
+
+  fn write_block_file(dir: &Path, file: u32, blocks: &[&Block], xor_key: Option<[u8; 8]>) {
+    let mut data = Vec::new();
+    for block in blocks {
+      let mut serialized = Vec::new();
+      block.consensus_encode(&mut serialized).unwrap();
+      data.extend_from_slice(&Network::Regtest.magic().to_bytes());
+      data.extend_from_slice(&u32::try_from(serialized.len()).unwrap().to_le_bytes());
+      data.extend_from_slice(&serialized);
+    }
+    // preallocated space at the end of the file.
+    data.extend_from_slice(&[0; 100]);
+
+    if let Some(key) = xor_key {
+      for (i, byte) in data.iter_mut().enumerate() {
+        *byte ^= key[i % 8];
+      }
+    }
+
+    fs::write(BlockFiles::file_path(dir, file), data).unwrap();
+  }
+
+  fn test_read_blocks(xor_key: Option<[u8; 8]>) {
+    let dir = TempDir::new().unwrap();
+    if let Some(key) = xor_key {
+      fs::write(dir.path().join("xor.dat"), key).unwrap();
+    }
+
+    let genesis = Chain::Regtest.genesis_block();
+    let first = child(&genesis, 1);
+    let stale = child(&genesis, 2);
+    let second = child(&first, 3);
+
+    // blocks are stored out of order and alongside stale blocks.
+    write_block_file(dir.path(), 0, &[&genesis, &second], xor_key);
+    write_block_file(dir.path(), 1, &[&stale, &first], xor_key);
+
+    let block_files = BlockFiles::open(dir.path(), Network::Regtest, second.block_hash()).unwrap();
+
+    assert_eq!(block_files.len(), 3);
+    assert_eq!(block_files.read_block(0, true).unwrap(), genesis);
+    assert_eq!(block_files.read_block(1, true).unwrap(), first);
+    assert_eq!(block_files.read_block(2, true).unwrap(), second);
+    assert_eq!(
+      block_files.read_block(2, false).unwrap(),
+      Block {
+        header: second.header,
+        txdata: Vec::new(),
+      }
+    );
+    assert!(block_files.read_block(3, true).is_err());
+
+    let block_files = BlockFiles::open(dir.path(), Network::Regtest, first.block_hash()).unwrap();
+    assert_eq!(block_files.len(), 2);
+  }
+
+  #[test]
+  fn read_blocks() {
+    test_read_blocks(None);
+  }
+
+  #[test]
+  fn read_xored_blocks() {
+    test_read_blocks(Some([1, 2, 3, 4, 5, 6, 7, 8]));
+  }
+
+  #[test]
+  fn missing_block() {
+    let dir = TempDir::new().unwrap();
+    let genesis = Chain::Regtest.genesis_block();
+    write_block_file(dir.path(), 0, &[&genesis], None);
+
+    assert!(BlockFiles::open(
+      dir.path(),
+      Network::Regtest,
+      child(&genesis, 1).block_hash()
+    )
+    .is_err());
+  }
+}
@@ -0,0 +1,552 @@
+use {
+  super::*,
+  okx::datastore::{
+    brc20::{Balance, TokenInfo, TransferInfo, TransferableLog},
+    brc20s::{PoolInfo, StakeInfo, UserInfo},
+  },
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Violation {
+  pub(crate) invariant: String,
+  pub(crate) key: String,
+  pub(crate) message: String,
+}
+
+impl Violation {
+  fn new(invariant: &str, key: impl Display, message: String) -> Self {
+    Self {
+      invariant: invariant.into(),
+      key: key.to_string(),
+      message,
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct IndexCheck {
+  pub(crate) height: u64,
+  pub(crate) violations: Vec<Violation>,
+}
+
+fn check_brc20(
+  tokens: &[TokenInfo],
+  balances: &[(ScriptKey, Balance)],
+  transferable_logs: &[TransferableLog],
+  inscribe_transfers: &[(InscriptionId, TransferInfo)],
+) -> Vec<Violation> {
+  let mut violations = Vec::new();
+
+  let mut supplies = BTreeMap::<String, u128>::new();
+  let mut transferable_balances = BTreeMap::<(String, String), (u128, u128)>::new();
+
+  for (script_key, balance) in balances {
+    let tick = balance.tick.to_lowercase().as_str().to_string();
+    let supply = supplies.entry(tick.clone()).or_default();
+    *supply = supply.saturating_add(balance.overall_balance);
+    transferable_balances
+      .entry((script_key.to_string(), tick))
+      .or_default()
+      .0 = balance.transferable_balance;
+  }
+
+  for log in transferable_logs {
+    let (_, logged) = transferable_balances
+      .entry((
+        log.owner.to_string(),
+        log.tick.to_lowercase().as_str().to_string(),
+      ))
+      .or_default();
+    *logged = logged.saturating_add(log.amount);
+  }
+
+  // burned amounts are debited from the balances and not from `minted`.
+  for token in tokens {
+    let tick = token.tick.to_lowercase().as_str().to_string();
+    let supply = supplies.remove(&tick).unwrap_or_default();
+    if Some(supply) != token.minted.checked_sub(token.burned) {
+      violations.push(Violation::new(
+        "brc20_balances_sum_to_minted",
+        &tick,
+        format!(
+          "balances sum to {supply}, minted {} and burned {}",
+          token.minted, token.burned
+        ),
+      ));
+    }
+  }
+
+  for (tick, supply) in supplies {
+    violations.push(Violation::new(
+      "brc20_balances_sum_to_minted",
+      &tick,
+      format!("balances sum to {supply} for a tick that was not deployed"),
+    ));
+  }
+
+  for ((address, tick), (transferable_balance, logged)) in transferable_balances {
+    if transferable_balance != logged {
+      violations.push(Violation::new(
+        "brc20_transferable_balance_matches_logs",
+        format!("{address}_{tick}"),
+        format!(
+          "transferable balance is {transferable_balance}, transferable logs sum to {logged}"
+        ),
+      ));
+    }
+  }
+
+  let logs = transferable_logs
+    .iter()
+    .map(|log| (log.inscription_id, log))
+    .collect::<HashMap<InscriptionId, &TransferableLog>>();
+
+  for (inscription_id, transfer_info) in inscribe_transfers {
+    match logs.get(inscription_id) {
+      Some(log)
+        if log.tick.to_lowercase() == transfer_info.tick.to_lowercase()
+          && log.amount == transfer_info.amt => {}
+      Some(log) => violations.push(Violation::new(
+        "brc20_inscribe_transfer_has_transferable_log",
+        inscription_id,
+        format!(
+          "inscribed transfer of {} {} is logged as {} {}",
+          transfer_info.amt, transfer_info.tick, log.amount, log.tick
+        ),
+      )),
+      None => violations.push(Violation::new(
+        "brc20_inscribe_transfer_has_transferable_log",
+        inscription_id,
+        format!(
+          "inscribed transfer of {} {} has no transferable log",
+          transfer_info.amt, transfer_info.tick
+        ),
+      )),
+    }
+  }
+
+  violations
+}
+
+fn check_brc20s(
+  pools: &[PoolInfo],
+  user_infos: &[(ScriptKey, UserInfo)],
+  stake_infos: &[(ScriptKey, StakeInfo)],
+) -> Vec<Violation> {
+  let mut violations = Vec::new();
+
+  let pools = pools
+    .iter()
+    .map(|pool| (pool.pid.as_str().to_string(), pool))
+    .collect::<BTreeMap<String, &PoolInfo>>();
+
+  for (pid, pool) in &pools {
+    if pool.minted > pool.dmax {
+      violations.push(Violation::new(
+        "brc20s_pool_minted_within_dmax",
+        pid,
+        format!("minted {} exceeds dmax {}", pool.minted, pool.dmax),
+      ));
+    }
+
+    let staked = user_infos
+      .iter()
+      .filter(|(_, user_info)| user_info.pid.as_str() == pid)
+      .fold(0, |staked: u128, (_, user_info)| {
+        staked.saturating_add(user_info.staked)
+      });
+
+    if staked != pool.staked {
+      violations.push(Violation::new(
+        "brc20s_pool_staked_matches_users",
+        pid,
+        format!("pool staked is {}, user infos sum to {staked}", pool.staked),
+      ));
+    }
+  }
+
+  // keyed by address, pledged tick and pid.
+  let mut user_stakes = BTreeMap::<(String, String, String), u128>::new();
+
+  for (script_key, user_info) in user_infos {
+    let pid = user_info.pid.as_str().to_string();
+    let Some(pool) = pools.get(&pid) else {
+      violations.push(Violation::new(
+        "brc20s_user_info_has_pool",
+        format!("{script_key}_{pid}"),
+        "user info refers to a pool that was not deployed".into(),
+      ));
+      continue;
+    };
+
+    if user_info.staked != 0 {
+      user_stakes.insert(
+        (script_key.to_string(), pool.stake.to_string(), pid),
+        user_info.staked,
+      );
+    }
+  }
+
+  for (script_key, stake_info) in stake_infos {
+    let mut total_only = 0;
+    let mut max_share = 0;
+
+    for (pid, only, staked) in &stake_info.pool_stakes {
+      let (address, stake, pid) = (
+        script_key.to_string(),
+        stake_info.stake.to_string(),
+        pid.as_str().to_string(),
+      );
+
+      match user_stakes.remove(&(address.clone(), stake.clone(), pid.clone())) {
+        Some(user_staked) if user_staked == *staked => {}
+        user_staked => violations.push(Violation::new(
+          "brc20s_stake_info_matches_user_infos",
+          format!("{address}_{stake}_{pid}"),
+          format!(
+            "stake info records {staked}, user info staked is {}",
+            user_staked.unwrap_or_default()
+          ),
+        )),
+      }
+
+      if *only {
+        total_only = staked.saturating_add(total_only);
+      } else {
+        max_share = max_share.max(*staked);
+      }
+    }
+
+    if (total_only, max_share) != (stake_info.total_only, stake_info.max_share) {
+      violations.push(Violation::new(
+        "brc20s_stake_info_matches_user_infos",
+        format!("{script_key}_{}", stake_info.stake.to_string()),
+        format!(
+          "total only is {} and max share is {}, pool stakes give {total_only} and {max_share}",
+          stake_info.total_only, stake_info.max_share
+        ),
+      ));
+    }
+  }
+
+  for ((address, stake, pid), staked) in user_stakes {
+    violations.push(Violation::new(
+      "brc20s_stake_info_matches_user_infos",
+      format!("{address}_{stake}_{pid}"),
+      format!("user info staked is {staked}, stake info has no record"),
+    ));
+  }
+
+  violations
+}
+
+impl Index {
+  pub(crate) fn check(&self) -> Result<IndexCheck> {
+    let rtx = self.database.begin_read()?;
+    let brc20_db = brc20_db::DataStoreReader::new(&rtx);
+    let brc20s_db = brc20s_db::DataStoreReader::new(&rtx);
+
+    log::info!("checking brc20 and brc20s tables");
+
+    let mut violations = check_brc20(
+      &brc20_db.get_tokens_info()?,
+      &brc20_db.get_all_balances()?,
+      &brc20_db.get_all_transferable()?,
+      &brc20_db.get_all_inscribe_transfer_inscriptions()?,
+    );
+
+    violations.extend(check_brc20s(
+      &brc20s_db.get_all_poolinfo(0, None)?.0,
+      &brc20s_db.get_all_use_info()?,
+      &brc20s_db.get_all_user_stakeinfo()?,
+    ));
+
+    Ok(IndexCheck {
+      height: Self::blocks_indexed(&rtx)?,
+      violations,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {
+    super::*,
+    okx::datastore::brc20s::{Pid, PledgedTick, PoolType},
+  };
+
+  fn script_key(n: u8) -> ScriptKey {
+    ScriptKey::ScriptHash(bitcoin::ScriptHash::from_raw_hash(
+      Hash::from_slice(&[n; 20]).unwrap(),
+    ))
+  }
+
+  fn inscription_id(n: u32) -> InscriptionId {
+    format!("{}i{n}", "1".repeat(64)).parse().unwrap()
+  }
+
+  fn token(tick: &str, minted: u128, burned: u128) -> TokenInfo {
+    TokenInfo {
+      tick: tick.parse().unwrap(),
+      inscription_id: inscription_id(0),
+      inscription_number: 0,
+      supply: 1000,
+      minted,
+      limit_per_mint: 1000,
+      decimal: 18,
+      deploy_by: script_key(0),
+      deployed_number: 0,
+      deployed_timestamp: 0,
+      latest_mint_number: 0,
+      self_mint: false,
+      burned,
+    }
+  }
+
+  fn balance(
+    n: u8,
+    tick: &str,
+    overall_balance: u128,
+    transferable_balance: u128,
+  ) -> (ScriptKey, Balance) {
+    (
+      script_key(n),
+      Balance {
+        tick: tick.parse().unwrap(),
+        overall_balance,
+        transferable_balance,
+      },
+    )
+  }
+
+  fn transferable_log(n: u8, tick: &str, inscription: u32, amount: u128) -> TransferableLog {
+    TransferableLog {
+      inscription_id: inscription_id(inscription),
+      inscription_number: 0,
+      amount,
+      tick: tick.parse().unwrap(),
+      owner: script_key(n),
+    }
+  }
+
+  fn pool(pid: &str, only: bool, minted: u128, staked: u128, dmax: u128) -> PoolInfo {
+    PoolInfo::new(
+      &Pid::from_str(pid).unwrap(),
+      &PoolType::Pool,
+      &inscription_id(0),
+      &PledgedTick::Native,
+      10,
+      minted,
+      staked,
+      dmax,
+      "0".into(),
+      0,
+      only,
+      0,
+      0,
+    )
+  }
+
+  fn user_info(n: u8, pid: &str, staked: u128) -> (ScriptKey, UserInfo) {
+    (
+      script_key(n),
+      UserInfo {
+        pid: Pid::from_str(pid).unwrap(),
+        staked,
+        minted: 0,
+        pending_reward: 0,
+        reward_debt: 0,
+        latest_updated_block: 0,
+      },
+    )
+  }
+
+  fn stake_info(
+    n: u8,
+    pool_stakes: &[(&str, bool, u128)],
+    max_share: u128,
+    total_only: u128,
+  ) -> (ScriptKey, StakeInfo) {
+    (
+      script_key(n),
+      StakeInfo::new(
+        pool_stakes
+          .iter()
+          .map(|(pid, only, staked)| (Pid::from_str(pid).unwrap(), *only, *staked))
+          .collect(),
+        &PledgedTick::Native,
+        max_share,
+        total_only,
+      ),
+    )
+  }
+
+  fn invariants(violations: Vec<Violation>) -> Vec<(String, String)> {
+    violations
+      .into_iter()
+      .map(|violation| (violation.invariant, violation.key))
+      .collect()
+  }
+
+  #[test]
+  fn consistent_brc20_tables() {
+    assert_eq!(
+      check_brc20(
+        &[token("ordi", 100, 10), token("sats", 0, 0)],
+        &[balance(1, "ordi", 60, 20), balance(2, "ORDI", 30, 0)],
+        &[
+          transferable_log(1, "ordi", 1, 5),
+          transferable_log(1, "ordi", 2, 15)
+        ],
+        &[
+          (
+            inscription_id(1),
+            TransferInfo {
+              tick: "ordi".parse().unwrap(),
+              amt: 5
+            }
+          ),
+          (
+            inscription_id(2),
+            TransferInfo {
+              tick: "ORDI".parse().unwrap(),
+              amt: 15
+            }
+          ),
+        ],
+      ),
+      []
+    );
+  }
+
+  #[test]
+  fn brc20_violations() {
+    assert_eq!(
+      invariants(check_brc20(
+        &[token("ordi", 100, 0)],
+        &[balance(1, "ordi", 60, 20), balance(2, "sats", 1, 0)],
+        &[
+          transferable_log(1, "ordi", 1, 5),
+          transferable_log(3, "ordi", 2, 15)
+        ],
+        &[
+          (
+            inscription_id(1),
+            TransferInfo {
+              tick: "ordi".parse().unwrap(),
+              amt: 6
+            }
+          ),
+          (
+            inscription_id(3),
+            TransferInfo {
+              tick: "ordi".parse().unwrap(),
+              amt: 1
+            }
+          ),
+        ],
+      )),
+      [
+        ("brc20_balances_sum_to_minted".into(), "ordi".into()),
+        ("brc20_balances_sum_to_minted".into(), "sats".into()),
+        (
+          "brc20_transferable_balance_matches_logs".into(),
+          format!("{}_ordi", script_key(1))
+        ),
+        (
+          "brc20_transferable_balance_matches_logs".into(),
+          format!("{}_ordi", script_key(3))
+        ),
+        (
+          "brc20_inscribe_transfer_has_transferable_log".into(),
+          inscription_id(1).to_string()
+        ),
+        (
+          "brc20_inscribe_transfer_has_transferable_log".into(),
+          inscription_id(3).to_string()
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn consistent_brc20s_tables() {
+    assert_eq!(
+      check_brc20s(
+        &[
+          pool("0123456789#01", true, 50, 30, 50),
+          pool("0123456789#02", false, 0, 20, 50),
+        ],
+        &[
+          user_info(1, "0123456789#01", 10),
+          user_info(2, "0123456789#01", 20),
+          user_info(1, "0123456789#02", 20),
+          user_info(2, "0123456789#02", 0),
+        ],
+        &[
+          stake_info(
+            1,
+            &[("0123456789#02", false, 20), ("0123456789#01", true, 10)],
+            20,
+            10
+          ),
+          stake_info(2, &[("0123456789#01", true, 20)], 0, 20),
+        ],
+      ),
+      []
+    );
+  }
+
+  #[test]
+  fn brc20s_violations() {
+    assert_eq!(
+      invariants(check_brc20s(
+        &[
+          pool("0123456789#01", true, 51, 30, 50),
+          pool("0123456789#02", false, 0, 20, 50),
+        ],
+        &[
+          user_info(1, "0123456789#01", 10),
+          user_info(1, "0123456789#02", 20),
+          user_info(1, "0123456789#03", 20),
+          user_info(2, "0123456789#02", 5),
+        ],
+        &[stake_info(1, &[("0123456789#01", true, 15)], 20, 15)],
+      )),
+      [
+        (
+          "brc20s_pool_minted_within_dmax".into(),
+          "0123456789#01".into()
+        ),
+        (
+          "brc20s_pool_staked_matches_users".into(),
+          "0123456789#01".into()
+        ),
+        (
+          "brc20s_pool_staked_matches_users".into(),
+          "0123456789#02".into()
+        ),
+        (
+          "brc20s_user_info_has_pool".into(),
+          format!("{}_0123456789#03", script_key(1))
+        ),
+        (
+          "brc20s_stake_info_matches_user_infos".into(),
+          format!("{}_btc_0123456789#01", script_key(1))
+        ),
+        (
+          "brc20s_stake_info_matches_user_infos".into(),
+          format!("{}_btc", script_key(1))
+        ),
+        (
+          "brc20s_stake_info_matches_user_infos".into(),
+          format!("{}_btc_0123456789#02", script_key(1))
+        ),
+        (
+          "brc20s_stake_info_matches_user_infos".into(),
+          format!("{}_btc_0123456789#02", script_key(2))
+        ),
+      ]
+    );
+  }
+}
@@ -0,0 +1,599 @@
+use {
+  super::{
+    export::{receipt_row, RECEIPT_COLUMNS},
+    *,
+  },
+  std::{
+    collections::BTreeSet,
+    io::{BufRead, BufReader},
+    ops::Range,
+  },
+};
+
+/// Receipt columns compared against the reference, the remaining ones differ between indexers.
+const COMPARED_RECEIPT_COLUMNS: &[&str] = &[
+  "inscription_id",
+  "op",
+  "from",
+  "to",
+  "tick",
+  "amount",
+  "valid",
+];
+
+type Row = BTreeMap<String, String>;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct BalanceMismatch {
+  pub(crate) address: String,
+  pub(crate) tick: String,
+  pub(crate) reference_overall_balance: Option<String>,
+  pub(crate) overall_balance: Option<String>,
+  pub(crate) reference_transferable_balance: Option<String>,
+  pub(crate) transferable_balance: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct EventDivergence {
+  pub(crate) txid: Txid,
+  /// The height of the transaction, from the index or else from the reference.
+  pub(crate) height: Option<u64>,
+  pub(crate) reference_events: Vec<Row>,
+  pub(crate) events: Vec<Row>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct BRC20Diff {
+  pub(crate) height: u64,
+  pub(crate) reference_height: Option<u64>,
+  pub(crate) balances_compared: usize,
+  pub(crate) transactions_compared: usize,
+  /// Addresses and ticks of the balance mismatches.
+  pub(crate) mismatched_addresses: BTreeSet<String>,
+  pub(crate) mismatched_ticks: BTreeSet<String>,
+  pub(crate) balance_mismatches: Vec<BalanceMismatch>,
+  pub(crate) divergent_transactions: usize,
+  /// The first transaction, in chain order, whose events differ.
+  pub(crate) first_divergence: Option<EventDivergence>,
+}
+
+/// A reference dump in the JSON lines layout of `ord index export-brc20`, mixing balance and receipt rows.
+#[derive(Debug, Default, PartialEq)]
+struct Reference {
+  height: Option<u64>,
+  balances: BTreeMap<(String, String), Row>,
+  events: Vec<(Txid, Vec<Row>)>,
+}
+
+impl Reference {
+  fn load(reader: impl BufRead) -> Result<Self> {
+    let mut reference = Self::default();
+    let mut transactions = HashMap::new();
+
+    for (i, line) in reader.lines().enumerate() {
+      let line = line?;
+      if line.trim().is_empty() {
+        continue;
+      }
+
+      let object = serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&line)
+        .with_context(|| format!("invalid reference row on line {}", i + 1))?;
+
+      // amounts may be written as numbers by other indexers.
+      let row = object
+        .into_iter()
+        .map(|(column, value)| match value {
+          serde_json::Value::String(value) => (column, value),
+          value => (column, value.to_string()),
+        })
+        .collect::<Row>();
+
+      if let Some(height) = row.get("export_height") {
+        reference.height = Some(height.parse()?);
+      } else if let Some(txid) = row.get("txid") {
+        let txid = txid.parse::<Txid>()?;
+        let index = *transactions.entry(txid).or_insert_with(|| {
+          reference.events.push((txid, Vec::new()));
+          reference.events.len() - 1
+        });
+        reference.events[index].1.push(row);
+      } else if let (Some(address), Some(tick), true) = (
+        row.get("address"),
+        row.get("tick"),
+        row.contains_key("overall_balance"),
+      ) {
+        let key = (address.clone(), tick.to_lowercase());
+        if reference.balances.insert(key, row).is_some() {
+          bail!("duplicate reference balance on line {}", i + 1);
+        }
+      } else {
+        bail!("unrecognized reference row on line {}", i + 1);
+      }
+    }
+
+    Ok(reference)
+  }
+}
+
+fn same_amount(a: &str, b: &str) -> bool {
+  match (a.parse::<u128>(), b.parse::<u128>()) {
+    (Ok(a), Ok(b)) => a == b,
+    _ => a == b,
+  }
+}
+
+/// Compares the reference balances with `balances`, keyed by address and lowercase tick. Balances
+/// of ticks the reference does not mention are not compared, and missing balances count as zero.
+fn diff_balances(
+  reference: &BTreeMap<(String, String), Row>,
+  balances: &BTreeMap<(String, String), (u128, u128)>,
+) -> Vec<BalanceMismatch> {
+  let mut mismatches = Vec::new();
+
+  for ((address, tick), row) in reference {
+    let balance = balances.get(&(address.clone(), tick.clone()));
+    let overall_balance = balance.map(|(overall, _)| overall.to_string());
+    let transferable_balance = balance.map(|(_, transferable)| transferable.to_string());
+
+    let reference_overall_balance = row.get("overall_balance").cloned();
+    let reference_transferable_balance = row.get("transferable_balance").cloned();
+
+    let differs = |reference: &Option<String>, actual: &Option<String>| {
+      reference
+        .as_ref()
+        .is_some_and(|reference| !same_amount(reference, actual.as_deref().unwrap_or("0")))
+    };
+
+    if differs(&reference_overall_balance, &overall_balance)
+      || differs(&reference_transferable_balance, &transferable_balance)
+    {
+      mismatches.push(BalanceMismatch {
+        address: address.clone(),
+        tick: tick.clone(),
+        reference_overall_balance,
+        overall_balance,
+        reference_transferable_balance,
+        transferable_balance,
+      });
+    }
+  }
+
+  let ticks = reference
+    .keys()
+    .map(|(_, tick)| tick)
+    .collect::<HashSet<_>>();
+
+  for ((address, tick), (overall, transferable)) in balances {
+    if ticks.contains(tick)
+      && !reference.contains_key(&(address.clone(), tick.clone()))
+      && (*overall != 0 || *transferable != 0)
+    {
+      mismatches.push(BalanceMismatch {
+        address: address.clone(),
+        tick: tick.clone(),
+        reference_overall_balance: None,
+        overall_balance: Some(overall.to_string()),
+        reference_transferable_balance: None,
+        transferable_balance: Some(transferable.to_string()),
+      });
+    }
+  }
+
+  mismatches
+}
+
+/// Whether the events of a transaction match the reference, comparing only the columns the reference has.
+fn same_events(reference: &[Row], events: &[Row]) -> bool {
+  reference.len() == events.len()
+    && reference.iter().zip(events).all(|(reference, event)| {
+      COMPARED_RECEIPT_COLUMNS.iter().all(|column| {
+        let (Some(expected), Some(actual)) = (reference.get(*column), event.get(*column)) else {
+          return true;
+        };
+        match *column {
+          "tick" => expected.to_lowercase() == actual.to_lowercase(),
+          "amount" => same_amount(expected, actual),
+          _ => expected == actual,
+        }
+      })
+    })
+}
+
+/// Compares the events of the reference transactions and of the local transactions of `blocks`,
+/// which lists the txids with receipts of each block in block order, returning the number of
+/// transactions compared and the divergences in chain order. Transactions without local receipts
+/// sort after the local ones of their height.
+/// The local blocks compared with the reference events, the heights covered by its event rows.
+/// Rows without a height are still compared by txid, and a reference with only balances compares
+/// no blocks, since every local transaction would otherwise be reported as divergent.
+fn compared_heights(reference: &[(Txid, Vec<Row>)], indexed: u64) -> Range<u64> {
+  let heights = reference
+    .iter()
+    .filter_map(|(_, rows)| rows.first()?.get("height")?.parse::<u64>().ok())
+    .collect::<Vec<u64>>();
+
+  match (heights.iter().min(), heights.iter().max()) {
+    (Some(start), Some(end)) => *start..(end + 1).min(indexed),
+    _ => 0..0,
+  }
+}
+
+fn diff_events(
+  reference: &[(Txid, Vec<Row>)],
+  blocks: &[(u64, Vec<Txid>)],
+  events: impl Fn(&Txid) -> Result<Vec<Row>>,
+) -> Result<(usize, Vec<EventDivergence>)> {
+  let positions = blocks
+    .iter()
+    .flat_map(|(height, txids)| {
+      txids
+        .iter()
+        .enumerate()
+        .map(|(i, txid)| (*txid, (*height, i)))
+    })
+    .collect::<HashMap<Txid, (u64, usize)>>();
+
+  let reference_events = reference
+    .iter()
+    .map(|(txid, rows)| (*txid, rows.as_slice()))
+    .collect::<HashMap<Txid, &[Row]>>();
+
+  let txids = reference.iter().map(|(txid, _)| *txid).chain(
+    blocks
+      .iter()
+      .flat_map(|(_, txids)| txids.iter().copied())
+      .filter(|txid| !reference_events.contains_key(txid)),
+  );
+
+  let mut compared = 0;
+  let mut divergences = Vec::new();
+  for (order, txid) in txids.enumerate() {
+    compared += 1;
+
+    let reference_events = reference_events
+      .get(&txid)
+      .map(|rows| rows.to_vec())
+      .unwrap_or_default();
+    let events = events(&txid)?;
+    if same_events(&reference_events, &events) {
+      continue;
+    }
+
+    let position = positions.get(&txid).copied();
+    let height = position.map(|(height, _)| height).or_else(|| {
+      reference_events
+        .first()
+        .and_then(|row| row.get("height"))
+        .and_then(|height| height.parse().ok())
+    });
+
+    divergences.push((
+      (
+        height.unwrap_or(u64::MAX),
+        position.map_or(usize::MAX, |(_, i)| i),
+        order,
+      ),
+      EventDivergence {
+        txid,
+        height,
+        reference_events,
+        events,
+      },
+    ));
+  }
+
+  divergences.sort_by_key(|(key, _)| *key);
+
+  Ok((
+    compared,
+    divergences
+      .into_iter()
+      .map(|(_, divergence)| divergence)
+      .collect(),
+  ))
+}
+
+impl Index {
+  pub(crate) fn diff_brc20(&self, reference: &Path) -> Result<BRC20Diff> {
+    let reference = Reference::load(BufReader::new(File::open(reference)?))?;
+
+    let rtx = self.database.begin_read()?;
+    let brc20_db = brc20_db::DataStoreReader::new(&rtx);
+
+    log::info!(
+      "comparing {} balances and {} transactions with the reference",
+      reference.balances.len(),
+      reference.events.len()
+    );
+
+    let balances = brc20_db
+      .get_all_balances()?
+      .into_iter()
+      .map(|(script_key, balance)| {
+        (
+          (
+            script_key.to_string(),
+            balance.tick.to_string().to_lowercase(),
+          ),
+          (balance.overall_balance, balance.transferable_balance),
+        )
+      })
+      .collect();
+
+    let balance_mismatches = diff_balances(&reference.balances, &balances);
+
+    let heights = compared_heights(&reference.events, Self::blocks_indexed(&rtx)?);
+
+    let blocks = heights
+      .map(|height| Ok((height, brc20_db.get_block_txids(height)?)))
+      .collect::<Result<Vec<(u64, Vec<Txid>)>>>()?;
+
+    let (transactions_compared, divergences) = diff_events(&reference.events, &blocks, |txid| {
+      Ok(
+        brc20_db
+          .get_transaction_receipts(txid)?
+          .iter()
+          .map(|receipt| {
+            RECEIPT_COLUMNS
+              .iter()
+              .map(|column| column.to_string())
+              .zip(receipt_row(0, txid, receipt))
+              .filter(|(column, _)| column != "height")
+              .collect::<Row>()
+          })
+          .collect(),
+      )
+    })?;
+
+    Ok(BRC20Diff {
+      height: Self::blocks_indexed(&rtx)?,
+      reference_height: reference.height,
+      balances_compared: reference.balances.len(),
+      transactions_compared,
+      mismatched_addresses: balance_mismatches
+        .iter()
+        .map(|mismatch| mismatch.address.clone())
+        .collect(),
+      mismatched_ticks: balance_mismatches
+        .iter()
+        .map(|mismatch| mismatch.tick.clone())
+        .collect(),
+      balance_mismatches,
+      divergent_transactions: divergences.len(),
+      first_divergence: divergences.into_iter().next(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn txid(n: u64) -> Txid {
+    format!("{n:064x}").parse().unwrap()
+  }
+
+  fn row(columns: &[(&str, &str)]) -> Row {
+    columns
+      .iter()
+      .map(|(column, value)| (column.to_string(), value.to_string()))
+      .collect()
+  }
+
+  #[test]
+  fn reference_rows_are_classified() {
+    let reference = Reference::load(
+      format!(
+        "{{\"export_height\":100}}\n\
+         {{\"address\":\"a\",\"tick\":\"ORDI\",\"overall_balance\":10}}\n\
+         \n\
+         {{\"txid\":\"{}\",\"op\":\"mint\"}}\n\
+         {{\"txid\":\"{}\",\"op\":\"deploy\"}}\n\
+         {{\"txid\":\"{}\",\"op\":\"transfer\"}}\n",
+        txid(2),
+        txid(1),
+        txid(2)
+      )
+      .as_bytes(),
+    )
+    .unwrap();
+
+    assert_eq!(reference.height, Some(100));
+    assert_eq!(
+      reference.balances,
+      [(
+        ("a".into(), "ordi".into()),
+        row(&[
+          ("address", "a"),
+          ("tick", "ORDI"),
+          ("overall_balance", "10")
+        ])
+      )]
+      .into()
+    );
+    assert_eq!(
+      reference.events,
+      [
+        (
+          txid(2),
+          vec![
+            row(&[("txid", &txid(2).to_string()), ("op", "mint")]),
+            row(&[("txid", &txid(2).to_string()), ("op", "transfer")]),
+          ]
+        ),
+        (
+          txid(1),
+          vec![row(&[("txid", &txid(1).to_string()), ("op", "deploy")])]
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn unrecognized_reference_rows_are_rejected() {
+    assert_eq!(
+      Reference::load("{\"address\":\"a\"}\n".as_bytes())
+        .unwrap_err()
+        .to_string(),
+      "unrecognized reference row on line 1"
+    );
+    assert_eq!(
+      Reference::load(
+        "{\"address\":\"a\",\"tick\":\"ordi\",\"overall_balance\":\"1\"}\n\
+         {\"address\":\"a\",\"tick\":\"ORDI\",\"overall_balance\":\"1\"}\n"
+          .as_bytes()
+      )
+      .unwrap_err()
+      .to_string(),
+      "duplicate reference balance on line 2"
+    );
+  }
+
+  #[test]
+  fn balance_mismatches() {
+    let reference = [
+      ("a", "ordi", "10", Some("0")),
+      ("b", "ordi", "5", None),
+      ("c", "ordi", "0", None),
+      ("d", "ordi", "7", Some("1")),
+    ]
+    .into_iter()
+    .map(|(address, tick, overall, transferable)| {
+      let mut row = row(&[
+        ("address", address),
+        ("tick", tick),
+        ("overall_balance", overall),
+      ]);
+      if let Some(transferable) = transferable {
+        row.insert("transferable_balance".into(), transferable.into());
+      }
+      ((address.into(), tick.into()), row)
+    })
+    .collect();
+
+    let balances = [
+      (("a", "ordi"), (10, 0)),
+      (("b", "ordi"), (5, 5)),
+      (("d", "ordi"), (7, 2)),
+      (("e", "ordi"), (3, 0)),
+      (("e", "sats"), (3, 0)),
+    ]
+    .into_iter()
+    .map(|((address, tick), balance)| ((address.into(), tick.into()), balance))
+    .collect();
+
+    assert_eq!(
+      diff_balances(&reference, &balances),
+      [
+        BalanceMismatch {
+          address: "d".into(),
+          tick: "ordi".into(),
+          reference_overall_balance: Some("7".into()),
+          overall_balance: Some("7".into()),
+          reference_transferable_balance: Some("1".into()),
+          transferable_balance: Some("2".into()),
+        },
+        BalanceMismatch {
+          address: "e".into(),
+          tick: "ordi".into(),
+          reference_overall_balance: None,
+          overall_balance: Some("3".into()),
+          reference_transferable_balance: None,
+          transferable_balance: Some("0".into()),
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn compared_heights_are_covered_by_reference_events() {
+    assert_eq!(compared_heights(&[], 100), 0..0);
+
+    assert_eq!(
+      compared_heights(&[(txid(1), vec![row(&[("op", "mint")])])], 100),
+      0..0
+    );
+
+    let reference = [
+      (txid(1), vec![row(&[("height", "12")])]),
+      (txid(2), vec![row(&[("height", "10")])]),
+      (txid(3), vec![row(&[("op", "mint")])]),
+    ];
+    assert_eq!(compared_heights(&reference, 100), 10..13);
+    assert_eq!(compared_heights(&reference, 11), 10..11);
+  }
+
+  #[test]
+  fn divergences_are_in_chain_order() {
+    let event = |txid: Txid, op: &str| row(&[("txid", &txid.to_string()), ("op", op)]);
+    let reference_event = |txid: Txid, height: &str, op: &str| {
+      row(&[("txid", &txid.to_string()), ("height", height), ("op", op)])
+    };
+
+    // the reference lists the transactions out of chain order.
+    let reference = vec![
+      (txid(3), vec![reference_event(txid(3), "11", "mint")]),
+      (txid(2), vec![reference_event(txid(2), "10", "mint")]),
+      (txid(1), vec![reference_event(txid(1), "10", "deploy")]),
+      (txid(5), vec![reference_event(txid(5), "10", "mint")]),
+    ];
+    let blocks = vec![(10, vec![txid(1), txid(2), txid(4)]), (11, vec![txid(3)])];
+    let local = [
+      (txid(1), event(txid(1), "deploy")),
+      (txid(2), event(txid(2), "transfer")),
+      (txid(3), event(txid(3), "transfer")),
+      (txid(4), event(txid(4), "mint")),
+    ]
+    .into_iter()
+    .collect::<HashMap<Txid, Row>>();
+
+    let (compared, divergences) = diff_events(&reference, &blocks, |txid| {
+      Ok(local.get(txid).cloned().into_iter().collect())
+    })
+    .unwrap();
+
+    assert_eq!(compared, 5);
+    assert_eq!(
+      divergences
+        .iter()
+        .map(|divergence| (divergence.txid, divergence.height))
+        .collect::<Vec<_>>(),
+      [
+        (txid(2), Some(10)),
+        (txid(4), Some(10)),
+        (txid(5), Some(10)),
+        (txid(3), Some(11)),
+      ]
+    );
+    assert_eq!(divergences[1].reference_events, Vec::<Row>::new());
+    assert_eq!(divergences[1].events, [event(txid(4), "mint")]);
+    assert_eq!(divergences[2].events, Vec::<Row>::new());
+  }
+
+  #[test]
+  fn events_compare_reference_columns() {
+    let events = [row(&[
+      ("op", "mint"),
+      ("tick", "ordi"),
+      ("amount", "1000"),
+      ("valid", "true"),
+      ("error", ""),
+    ])];
+
+    assert!(same_events(
+      &[row(&[
+        ("op", "mint"),
+        ("tick", "ORDI"),
+        ("amount", "01000")
+      ])],
+      &events
+    ));
+    assert!(same_events(
+      &[row(&[("op", "mint"), ("error", "another message")])],
+      &events
+    ));
+    assert!(!same_events(&[row(&[("amount", "999")])], &events));
+    assert!(!same_events(&[], &events));
+    assert!(!same_events(&[row(&[]), row(&[])], &events));
+  }
+}
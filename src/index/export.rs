@@ -0,0 +1,418 @@
+use {
+  super::*,
+  crate::okx::datastore::brc20::{Event, OperationType, Receipt},
+  clap::ValueEnum,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, ValueEnum)]
+pub(crate) enum ExportFormat {
+  Csv,
+  Jsonl,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, ValueEnum)]
+pub(crate) enum BRC20ExportKind {
+  Tokens,
+  Balances,
+  Transferable,
+  Receipts,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, ValueEnum)]
+pub(crate) enum BRC20SExportKind {
+  Pools,
+  UserInfos,
+  StakeInfos,
+}
+
+/// Writes rows of string fields as CSV or JSON lines, preceded by a line carrying the export height.
+struct RowWriter<W: Write> {
+  writer: W,
+  format: ExportFormat,
+  columns: &'static [&'static str],
+}
+
+impl<W: Write> RowWriter<W> {
+  fn new(
+    mut writer: W,
+    format: ExportFormat,
+    height: u64,
+    columns: &'static [&'static str],
+  ) -> Result<Self> {
+    match format {
+      ExportFormat::Csv => {
+        writeln!(writer, "# export at block height {}", height)?;
+        writeln!(writer, "{}", columns.join(","))?;
+      }
+      ExportFormat::Jsonl => {
+        writeln!(writer, "{}", serde_json::json!({ "export_height": height }))?;
+      }
+    }
+
+    Ok(Self {
+      writer,
+      format,
+      columns,
+    })
+  }
+
+  fn write_row(&mut self, values: &[String]) -> Result {
+    assert_eq!(values.len(), self.columns.len());
+
+    match self.format {
+      ExportFormat::Csv => {
+        let fields = values
+          .iter()
+          .map(|value| Self::csv_field(value))
+          .collect::<Vec<String>>();
+        writeln!(self.writer, "{}", fields.join(","))?;
+      }
+      ExportFormat::Jsonl => {
+        let object = self
+          .columns
+          .iter()
+          .zip(values)
+          .map(|(column, value)| (column.to_string(), serde_json::Value::from(value.as_str())))
+          .collect::<serde_json::Map<String, serde_json::Value>>();
+        writeln!(self.writer, "{}", serde_json::Value::Object(object))?;
+      }
+    }
+
+    Ok(())
+  }
+
+  fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+      format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+      value.to_string()
+    }
+  }
+
+  fn finish(mut self) -> Result {
+    self.writer.flush()?;
+    Ok(())
+  }
+}
+
+pub(super) const RECEIPT_COLUMNS: &[&str] = &[
+  "height",
+  "txid",
+  "inscription_id",
+  "inscription_number",
+  "op",
+  "from",
+  "to",
+  "tick",
+  "amount",
+  "valid",
+  "error",
+];
+
+pub(super) fn receipt_row(height: u64, txid: &Txid, receipt: &Receipt) -> Vec<String> {
+  let op = match receipt.op {
+    OperationType::Deploy => "deploy",
+    OperationType::Mint => "mint",
+    OperationType::InscribeTransfer => "inscribeTransfer",
+    OperationType::Transfer if matches!(receipt.result, Ok(Event::Burn(_))) => "burn",
+    OperationType::Transfer => "transfer",
+  };
+
+  let (tick, amount, error) = match &receipt.result {
+    Ok(Event::Deploy(deploy)) => (deploy.tick.to_string(), deploy.supply.to_string(), None),
+    Ok(Event::Mint(mint)) => (mint.tick.to_string(), mint.amount.to_string(), None),
+    Ok(Event::InscribeTransfer(inscribe_transfer)) => (
+      inscribe_transfer.tick.to_string(),
+      inscribe_transfer.amount.to_string(),
+      None,
+    ),
+    Ok(Event::Transfer(transfer)) => (transfer.tick.to_string(), transfer.amount.to_string(), None),
+    Ok(Event::Burn(burn)) => (burn.tick.to_string(), burn.amount.to_string(), None),
+    Err(err) => (String::new(), String::new(), Some(err.to_string())),
+  };
+
+  vec![
+    height.to_string(),
+    txid.to_string(),
+    receipt.inscription_id.to_string(),
+    receipt.inscription_number.to_string(),
+    op.to_string(),
+    receipt.from.to_string(),
+    receipt.to.to_string(),
+    tick,
+    amount,
+    error.is_none().to_string(),
+    error.unwrap_or_default(),
+  ]
+}
+
+impl Index {
+  pub(crate) fn export_brc20(
+    &self,
+    filename: &str,
+    kind: BRC20ExportKind,
+    format: ExportFormat,
+  ) -> Result {
+    let rtx = self.database.begin_read()?;
+    let brc20_db = brc20_db::DataStoreReader::new(&rtx);
+    let blocks_indexed = Self::blocks_indexed(&rtx)?;
+
+    log::info!("exporting brc20 {kind:?} to {filename}");
+
+    let writer = BufWriter::new(File::create(filename)?);
+
+    match kind {
+      BRC20ExportKind::Tokens => {
+        let mut writer = RowWriter::new(
+          writer,
+          format,
+          blocks_indexed,
+          &[
+            "tick",
+            "inscription_id",
+            "inscription_number",
+            "supply",
+            "minted",
+            "limit_per_mint",
+            "decimal",
+            "deploy_by",
+            "deployed_number",
+            "deployed_timestamp",
+            "latest_mint_number",
+          ],
+        )?;
+        for token in brc20_db.get_tokens_info()? {
+          writer.write_row(&[
+            token.tick.to_string(),
+            token.inscription_id.to_string(),
+            token.inscription_number.to_string(),
+            token.supply.to_string(),
+            token.minted.to_string(),
+            token.limit_per_mint.to_string(),
+            token.decimal.to_string(),
+            token.deploy_by.to_string(),
+            token.deployed_number.to_string(),
+            token.deployed_timestamp.to_string(),
+            token.latest_mint_number.to_string(),
+          ])?;
+        }
+        writer.finish()?;
+      }
+      BRC20ExportKind::Balances => {
+        let mut writer = RowWriter::new(
+          writer,
+          format,
+          blocks_indexed,
+          &["address", "tick", "overall_balance", "transferable_balance"],
+        )?;
+        for (script_key, balance) in brc20_db.get_all_balances()? {
+          writer.write_row(&[
+            script_key.to_string(),
+            balance.tick.to_string(),
+            balance.overall_balance.to_string(),
+            balance.transferable_balance.to_string(),
+          ])?;
+        }
+        writer.finish()?;
+      }
+      BRC20ExportKind::Transferable => {
+        let mut writer = RowWriter::new(
+          writer,
+          format,
+          blocks_indexed,
+          &[
+            "address",
+            "tick",
+            "inscription_id",
+            "inscription_number",
+            "amount",
+          ],
+        )?;
+        for transferable in brc20_db.get_all_transferable()? {
+          writer.write_row(&[
+            transferable.owner.to_string(),
+            transferable.tick.to_string(),
+            transferable.inscription_id.to_string(),
+            transferable.inscription_number.to_string(),
+            transferable.amount.to_string(),
+          ])?;
+        }
+        writer.finish()?;
+      }
+      BRC20ExportKind::Receipts => {
+        let mut writer = RowWriter::new(writer, format, blocks_indexed, RECEIPT_COLUMNS)?;
+
+        for height in self.options.first_brc20_height()..blocks_indexed {
+          for txid in brc20_db.get_block_txids(height)? {
+            for receipt in brc20_db.get_transaction_receipts(&txid)? {
+              writer.write_row(&receipt_row(height, &txid, &receipt))?;
+            }
+          }
+
+          if SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
+            break;
+          }
+        }
+        writer.finish()?;
+      }
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn export_brc20s(
+    &self,
+    filename: &str,
+    kind: BRC20SExportKind,
+    format: ExportFormat,
+  ) -> Result {
+    let rtx = self.database.begin_read()?;
+    let brc20s_db = brc20s_db::DataStoreReader::new(&rtx);
+    let blocks_indexed = Self::blocks_indexed(&rtx)?;
+
+    log::info!("exporting brc20s {kind:?} to {filename}");
+
+    let writer = BufWriter::new(File::create(filename)?);
+
+    match kind {
+      BRC20SExportKind::Pools => {
+        let mut writer = RowWriter::new(
+          writer,
+          format,
+          blocks_indexed,
+          &[
+            "pid",
+            "pool_type",
+            "inscription_id",
+            "stake",
+            "erate",
+            "minted",
+            "staked",
+            "dmax",
+            "acc_reward_per_share",
+            "last_update_block",
+            "only",
+            "deploy_block",
+            "deploy_block_time",
+          ],
+        )?;
+        for pool in brc20s_db.get_all_poolinfo(0, None)?.0 {
+          writer.write_row(&[
+            pool.pid.as_str().to_string(),
+            pool.ptype.to_string(),
+            pool.inscription_id.to_string(),
+            pool.stake.to_string(),
+            pool.erate.to_string(),
+            pool.minted.to_string(),
+            pool.staked.to_string(),
+            pool.dmax.to_string(),
+            pool.acc_reward_per_share,
+            pool.last_update_block.to_string(),
+            pool.only.to_string(),
+            pool.deploy_block.to_string(),
+            pool.deploy_block_time.to_string(),
+          ])?;
+        }
+        writer.finish()?;
+      }
+      BRC20SExportKind::UserInfos => {
+        let mut writer = RowWriter::new(
+          writer,
+          format,
+          blocks_indexed,
+          &[
+            "address",
+            "pid",
+            "staked",
+            "minted",
+            "pending_reward",
+            "reward_debt",
+            "latest_updated_block",
+          ],
+        )?;
+        for (script_key, user_info) in brc20s_db.get_all_use_info()? {
+          writer.write_row(&[
+            script_key.to_string(),
+            user_info.pid.as_str().to_string(),
+            user_info.staked.to_string(),
+            user_info.minted.to_string(),
+            user_info.pending_reward.to_string(),
+            user_info.reward_debt.to_string(),
+            user_info.latest_updated_block.to_string(),
+          ])?;
+        }
+        writer.finish()?;
+      }
+      BRC20SExportKind::StakeInfos => {
+        // one row is written for each pool the pledged tick is staked in.
+        let mut writer = RowWriter::new(
+          writer,
+          format,
+          blocks_indexed,
+          &[
+            "address",
+            "stake",
+            "pid",
+            "only",
+            "staked",
+            "max_share",
+            "total_only",
+          ],
+        )?;
+        for (script_key, stake_info) in brc20s_db.get_all_user_stakeinfo()? {
+          for (pid, only, staked) in &stake_info.pool_stakes {
+            writer.write_row(&[
+              script_key.to_string(),
+              stake_info.stake.to_string(),
+              pid.as_str().to_string(),
+              only.to_string(),
+              staked.to_string(),
+              stake_info.max_share.to_string(),
+              stake_info.total_only.to_string(),
+            ])?;
+          }
+        }
+        writer.finish()?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn write_rows(format: ExportFormat, rows: &[Vec<String>]) -> String {
+    let mut buffer = Vec::new();
+    let mut writer = RowWriter::new(&mut buffer, format, 100, &["a", "b"]).unwrap();
+    for row in rows {
+      writer.write_row(row).unwrap();
+    }
+    writer.finish().unwrap();
+    String::from_utf8(buffer).unwrap()
+  }
+
+  #[test]
+  fn csv_rows_are_escaped() {
+    assert_eq!(
+      write_rows(
+        ExportFormat::Csv,
+        &[
+          vec!["ordi".into(), "1".into()],
+          vec!["a,b".into(), "say \"hi\"".into()],
+        ]
+      ),
+      "# export at block height 100\na,b\nordi,1\n\"a,b\",\"say \"\"hi\"\"\"\n"
+    );
+  }
+
+  #[test]
+  fn jsonl_rows_are_objects() {
+    assert_eq!(
+      write_rows(ExportFormat::Jsonl, &[vec!["ordi".into(), "1".into()]]),
+      "{\"export_height\":100}\n{\"a\":\"ordi\",\"b\":\"1\"}\n"
+    );
+  }
+}
@@ -0,0 +1,115 @@
+use super::*;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ContentTypeStats {
+  pub(crate) count: u64,
+  pub(crate) bytes: u64,
+}
+
+/// Counts and body sizes of new inscriptions, keyed by content type.
+///
+/// Inscriptions without a content type are recorded under the empty string.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct InscriptionStats {
+  pub(crate) content_types: BTreeMap<String, ContentTypeStats>,
+  pub(crate) cursed: u64,
+  pub(crate) unbound: u64,
+}
+
+impl InscriptionStats {
+  pub(super) fn record(&mut self, inscription: &Inscription, cursed: bool, unbound: bool) {
+    let stats = self
+      .content_types
+      .entry(inscription.content_type().unwrap_or_default().to_string())
+      .or_default();
+    stats.count += 1;
+    stats.bytes += u64::try_from(inscription.content_length().unwrap_or_default()).unwrap();
+
+    if cursed {
+      self.cursed += 1;
+    }
+    if unbound {
+      self.unbound += 1;
+    }
+  }
+
+  pub(crate) fn is_empty(&self) -> bool {
+    self.content_types.is_empty()
+  }
+
+  pub(crate) fn total(&self) -> ContentTypeStats {
+    self
+      .content_types
+      .values()
+      .fold(ContentTypeStats::default(), |total, stats| {
+        ContentTypeStats {
+          count: total.count + stats.count,
+          bytes: total.bytes + stats.bytes,
+        }
+      })
+  }
+
+  /// Aggregates the content type stats by `Media` kind, using the lowercase kind name as the key.
+  pub(crate) fn media(&self) -> BTreeMap<String, ContentTypeStats> {
+    let mut media = BTreeMap::<String, ContentTypeStats>::new();
+    for (content_type, stats) in &self.content_types {
+      let kind = media
+        .entry(
+          format!(
+            "{:?}",
+            content_type.parse::<Media>().unwrap_or(Media::Unknown)
+          )
+          .to_lowercase(),
+        )
+        .or_default();
+      kind.count += stats.count;
+      kind.bytes += stats.bytes;
+    }
+    media
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn record_inscriptions() {
+    let mut stats = InscriptionStats::default();
+    stats.record(&inscription("text/plain", "foo"), false, false);
+    stats.record(&inscription("text/plain", "hello"), true, false);
+    stats.record(&inscription("image/png", "bar"), false, true);
+    stats.record(&Inscription::new(None, None), false, false);
+
+    assert_eq!(
+      stats.content_types.get("text/plain"),
+      Some(&ContentTypeStats { count: 2, bytes: 8 })
+    );
+    assert_eq!(
+      stats.content_types.get(""),
+      Some(&ContentTypeStats { count: 1, bytes: 0 })
+    );
+    assert_eq!(stats.cursed, 1);
+    assert_eq!(stats.unbound, 1);
+    assert_eq!(
+      stats.total(),
+      ContentTypeStats {
+        count: 4,
+        bytes: 11
+      }
+    );
+    assert_eq!(
+      stats.media(),
+      [
+        ("image".to_string(), ContentTypeStats { count: 1, bytes: 3 }),
+        ("text".to_string(), ContentTypeStats { count: 2, bytes: 8 }),
+        (
+          "unknown".to_string(),
+          ContentTypeStats { count: 1, bytes: 0 }
+        ),
+      ]
+      .into_iter()
+      .collect()
+    );
+  }
+}
@@ -1,24 +1,50 @@
 use {
-  self::inscription_updater::InscriptionUpdater,
-  super::{fetcher::Fetcher, *},
-  crate::okx::{
-    datastore::StateReadWrite,
-    protocol::{BlockContext, ProtocolConfig, ProtocolManager},
+  self::{commit_policy::CommitPolicy, inscription_updater::InscriptionUpdater},
+  super::{block_files::BlockFiles, fetcher::Fetcher, *},
+  crate::{
+    inscription::TransactionInscription,
+    okx::{
+      datastore::{ord::ORD_SCRIPT_KEY_TO_INSCRIPTION_NUMBERS, StateReadWrite},
+      protocol::{BlockContext, ProtocolConfig, ProtocolManager},
+    },
+    satribute::{is_nakamoto_block, PIZZA_TXID},
   },
   futures::future::try_join_all,
   std::sync::mpsc,
   tokio::sync::mpsc::{error::TryRecvError, Receiver, Sender},
 };
 
+mod commit_policy;
 mod inscription_updater;
 
+pub(crate) use commit_policy::CommitTrigger;
+
+// The approximate number of bytes written to the inscription and protocol tables for each
+// inscription operation, used to estimate the size of the uncommitted writes.
+const DIRTY_BYTES_PER_INSCRIPTION_OPERATION: u64 = 1024;
+
 pub(crate) struct BlockData {
   pub(crate) header: Header,
   pub(crate) txdata: Vec<(Transaction, Txid)>,
+  // the inscriptions of each transaction, in the order of `txdata`.
+  pub(crate) inscriptions: Vec<Vec<TransactionInscription>>,
 }
 
-impl From<Block> for BlockData {
-  fn from(block: Block) -> Self {
+impl BlockData {
+  /// Computes the txids and, if `parse_inscriptions` is set, parses the inscription envelopes.
+  pub(crate) fn decode(block: Block, parse_inscriptions: bool) -> Self {
+    let inscriptions = block
+      .txdata
+      .iter()
+      .map(|transaction| {
+        if parse_inscriptions {
+          Inscription::from_transaction(transaction)
+        } else {
+          Vec::new()
+        }
+      })
+      .collect();
+
     BlockData {
       header: block.header,
       txdata: block
@@ -29,11 +55,49 @@ impl From<Block> for BlockData {
           (transaction, txid)
         })
         .collect(),
+      inscriptions,
     }
   }
 }
 
+impl From<Block> for BlockData {
+  fn from(block: Block) -> Self {
+    Self::decode(block, true)
+  }
+}
+
+/// The holders of the sats that are uncommon or rarer, maintained along with `SAT_TO_SATPOINT`.
+struct RareSatOwners<'db, 'tx> {
+  script_key_to_rare_sats: MultimapTable<'db, 'tx, &'static str, u64>,
+  rare_sat_to_script_key: Table<'db, 'tx, u64, &'static str>,
+  network: Network,
+}
+
+impl RareSatOwners<'_, '_> {
+  fn update(&mut self, sat: u64, owner: Option<&str>) -> Result {
+    let previous = self
+      .rare_sat_to_script_key
+      .remove(&sat)?
+      .map(|script_key| script_key.value().to_string());
+
+    if let Some(previous) = previous {
+      self
+        .script_key_to_rare_sats
+        .remove(previous.as_str(), &sat)?;
+    }
+
+    if let Some(owner) = owner {
+      self.rare_sat_to_script_key.insert(&sat, owner)?;
+      self.script_key_to_rare_sats.insert(owner, &sat)?;
+    }
+
+    Ok(())
+  }
+}
+
 pub(crate) struct Updater<'index> {
+  commit_policy: CommitPolicy,
+  dirty_bytes: u64,
   range_cache: HashMap<OutPointValue, Vec<u8>>,
   height: u64,
   index: &'index Index,
@@ -47,6 +111,8 @@ pub(crate) struct Updater<'index> {
 impl<'index> Updater<'_> {
   pub(crate) fn new(index: &'index Index) -> Result<Updater<'index>> {
     Ok(Updater {
+      commit_policy: CommitPolicy::new(&index.options),
+      dirty_bytes: 0,
       range_cache: HashMap::new(),
       height: index.block_count()?,
       index,
@@ -87,11 +153,17 @@ impl<'index> Updater<'_> {
       Some(progress_bar)
     };
 
-    let rx = Self::fetch_blocks_from(self.index, self.height, self.index_sats)?;
+    // bitmap parcels are checked against the transaction count of any earlier block.
+    let rx = Self::fetch_blocks_from(
+      self.index,
+      self.height,
+      self.index_sats || self.index.options.enable_index_bitmap || self.index.options.no_txindex,
+    )?;
 
     let (mut outpoint_sender, mut tx_out_receiver) = Self::spawn_fetcher(self.index)?;
 
     let mut uncommitted = 0;
+    let mut write_started = Instant::now();
     while let Ok(block) = rx.recv() {
       self.index_block(
         self.index,
@@ -115,9 +187,16 @@ impl<'index> Updater<'_> {
 
       uncommitted += 1;
 
-      if uncommitted == 200 {
-        self.commit(wtx)?;
+      let elapsed = write_started.elapsed();
+      if let Some(trigger) = self.commit_policy.trigger(
+        uncommitted,
+        elapsed,
+        self.dirty_bytes,
+        starting_height.saturating_sub(self.height),
+      ) {
+        self.commit(wtx, trigger, uncommitted, elapsed)?;
         uncommitted = 0;
+        write_started = Instant::now();
         wtx = self.index.begin_write()?;
         let height = wtx
           .open_table(HEIGHT_TO_BLOCK_HASH)?
@@ -148,7 +227,12 @@ impl<'index> Updater<'_> {
     }
 
     if uncommitted > 0 {
-      self.commit(wtx)?;
+      self.commit(
+        wtx,
+        CommitTrigger::Finished,
+        uncommitted,
+        write_started.elapsed(),
+      )?;
     }
 
     if let Some(progress_bar) = &mut progress_bar {
@@ -158,10 +242,11 @@ impl<'index> Updater<'_> {
     Ok(())
   }
 
+  /// Fetches and decodes blocks on `--block-fetch-workers` threads, yielding them in height order.
   fn fetch_blocks_from(
     index: &Index,
-    mut height: u64,
-    index_sats: bool,
+    height: u64,
+    full_blocks: bool,
   ) -> Result<mpsc::Receiver<BlockData>> {
     let (tx, rx) = mpsc::sync_channel(32);
 
@@ -171,36 +256,170 @@ impl<'index> Updater<'_> {
 
     let first_inscription_height = index.first_inscription_height;
 
-    thread::spawn(move || loop {
-      if let Some(height_limit) = height_limit {
-        if height >= height_limit {
-          break;
-        }
-      }
+    let block_files = if index.options.index_from_block_files {
+      Some((
+        index.options.bitcoin_blocks_dir()?,
+        index.get_chain_network(),
+        index.options.block_files_tip_distance(),
+      ))
+    } else {
+      None
+    };
 
-      match Self::get_block_with_retries(&client, height, index_sats, first_inscription_height) {
-        Ok(Some(block)) => {
-          if let Err(err) = tx.send(block.into()) {
-            log::info!("Block receiver disconnected: {err}");
+    let workers = index.options.block_fetch_workers();
+    let mut clients = Vec::with_capacity(workers);
+    for _ in 0..workers {
+      clients.push(index.options.bitcoin_rpc_client()?);
+    }
+
+    // bounds the number of blocks held in memory while waiting for an earlier block.
+    let window = u64::try_from(workers * 4).unwrap();
+
+    thread::spawn(move || {
+      let block_files = Arc::new(block_files.and_then(|(dir, network, tip_distance)| {
+        Self::open_block_files(&client, &dir, network, height, tip_distance)
+      }));
+
+      let (height_sender, height_receiver) = mpsc::channel::<u64>();
+      let height_receiver = Arc::new(Mutex::new(height_receiver));
+      let (block_sender, block_receiver) = mpsc::channel();
+
+      for client in clients {
+        let height_receiver = height_receiver.clone();
+        let block_sender = block_sender.clone();
+        let block_files = block_files.clone();
+        thread::spawn(move || loop {
+          let Ok(height) = height_receiver.lock().unwrap().recv() else {
+            break;
+          };
+
+          let block = Self::get_block(
+            &client,
+            block_files.as_ref().as_ref(),
+            height,
+            full_blocks,
+            first_inscription_height,
+          )
+          .map(|block| {
+            block.map(|block| BlockData::decode(block, height >= first_inscription_height))
+          });
+
+          if block_sender.send((height, block)).is_err() {
+            break;
+          }
+        });
+      }
+      drop(block_sender);
+
+      let mut next_to_fetch = height;
+      let mut next_to_send = height;
+      let mut end = height_limit;
+      let mut pending = BTreeMap::new();
+
+      loop {
+        while next_to_fetch < next_to_send + window
+          && end.map(|end| next_to_fetch < end).unwrap_or(true)
+        {
+          if height_sender.send(next_to_fetch).is_err() {
             break;
           }
-          height += 1;
+          next_to_fetch += 1;
         }
-        Ok(None) => break,
-        Err(err) => {
-          log::error!("failed to fetch block {height}: {err}");
+
+        if end.map(|end| next_to_send >= end).unwrap_or(false) {
           break;
         }
+
+        let Ok((height, block)) = block_receiver.recv() else {
+          break;
+        };
+
+        match block {
+          Ok(Some(block)) => {
+            pending.insert(height, block);
+          }
+          // blocks after the first missing one are not sent.
+          Ok(None) => end = Some(end.map(|end| end.min(height)).unwrap_or(height)),
+          Err(err) => {
+            log::error!("failed to fetch block {height}: {err}");
+            end = Some(end.map(|end| end.min(height)).unwrap_or(height));
+          }
+        }
+
+        while let Some(block) = pending.remove(&next_to_send) {
+          if end.map(|end| next_to_send >= end).unwrap_or(false) {
+            break;
+          }
+          if let Err(err) = tx.send(block) {
+            log::info!("Block receiver disconnected: {err}");
+            return;
+          }
+          next_to_send += 1;
+        }
       }
     });
 
     Ok(rx)
   }
 
+  fn get_block(
+    client: &Client,
+    block_files: Option<&BlockFiles>,
+    height: u64,
+    full_blocks: bool,
+    first_inscription_height: u64,
+  ) -> Result<Option<Block>> {
+    match block_files {
+      Some(block_files) if height < block_files.len() => {
+        match block_files.read_block(height, full_blocks || height >= first_inscription_height) {
+          Ok(block) => Ok(Some(block)),
+          Err(err) => {
+            log::warn!("failed to read block {height} from block files, fetching over RPC: {err}");
+            Self::get_block_with_retries(client, height, full_blocks, first_inscription_height)
+          }
+        }
+      }
+      _ => Self::get_block_with_retries(client, height, full_blocks, first_inscription_height),
+    }
+  }
+
+  /// Maps the blocks in Bitcoin Core's block files up to `tip_distance` blocks below the tip,
+  /// returning `None` to fetch every block over RPC if there's nothing to read from them.
+  fn open_block_files(
+    client: &Client,
+    dir: &Path,
+    network: Network,
+    height: u64,
+    tip_distance: u64,
+  ) -> Option<BlockFiles> {
+    let result = client
+      .get_block_count()
+      .map_err(anyhow::Error::from)
+      .and_then(|block_count| {
+        let Some(tip) = block_count
+          .checked_sub(tip_distance)
+          .filter(|tip| *tip >= height)
+        else {
+          return Ok(None);
+        };
+        let hash = client.get_block_hash(tip)?;
+        log::info!("Reading blocks {height} to {tip} from {}", dir.display());
+        BlockFiles::open(dir, network, hash).map(Some)
+      });
+
+    match result {
+      Ok(block_files) => block_files,
+      Err(err) => {
+        log::warn!("failed to read block files, fetching blocks over RPC: {err}");
+        None
+      }
+    }
+  }
+
   fn get_block_with_retries(
     client: &Client,
     height: u64,
-    index_sats: bool,
+    full_blocks: bool,
     first_inscription_height: u64,
   ) -> Result<Option<Block>> {
     let mut errors = 0;
@@ -211,7 +430,7 @@ impl<'index> Updater<'_> {
         .and_then(|option| {
           option
             .map(|hash| {
-              if index_sats || height >= first_inscription_height {
+              if full_blocks || height >= first_inscription_height {
                 Ok(client.get_block(&hash)?)
               } else {
                 Ok(Block {
@@ -363,6 +582,12 @@ impl<'index> Updater<'_> {
           if outpoint_to_entry.get(&prev_output.store())?.is_some() {
             continue;
           }
+          // Every output is indexed from genesis with --no-txindex, so nothing is left to fetch
+          if index.options.no_txindex {
+            return Err(anyhow!(
+              "Could not find outpoint {prev_output} in index, it can't be fetched with --no-txindex"
+            ));
+          }
           // We don't know the value of this tx input. Send this outpoint to background thread to be fetched
           outpoint_sender.blocking_send(prev_output)?;
           fetching_outputs_count += 1u64;
@@ -387,6 +612,8 @@ impl<'index> Updater<'_> {
     let mut inscription_id_to_inscription_entry =
       wtx.open_table(INSCRIPTION_ID_TO_INSCRIPTION_ENTRY)?;
     let mut inscription_id_to_satpoint = wtx.open_table(INSCRIPTION_ID_TO_SATPOINT)?;
+    let mut inscription_number_to_content_type =
+      wtx.open_table(INSCRIPTION_NUMBER_TO_CONTENT_TYPE)?;
     let mut inscription_number_to_inscription_id =
       wtx.open_table(INSCRIPTION_NUMBER_TO_INSCRIPTION_ID)?;
     let mut reinscription_id_to_seq_num = wtx.open_table(REINSCRIPTION_ID_TO_SEQUENCE_NUMBER)?;
@@ -394,6 +621,8 @@ impl<'index> Updater<'_> {
     let mut inscription_id_to_children = wtx.open_multimap_table(INSCRIPTION_ID_TO_CHILDREN)?;
     let mut satpoint_to_inscription_id = wtx.open_multimap_table(SATPOINT_TO_INSCRIPTION_ID)?;
     let mut statistic_to_count = wtx.open_table(STATISTIC_TO_COUNT)?;
+    let mut script_key_to_inscription_numbers =
+      wtx.open_multimap_table(ORD_SCRIPT_KEY_TO_INSCRIPTION_NUMBERS)?;
 
     let mut lost_sats = statistic_to_count
       .get(&Statistic::LostSats.key())?
@@ -413,11 +642,14 @@ impl<'index> Updater<'_> {
       tx_out_receiver,
       &mut inscription_id_to_inscription_entry,
       lost_sats,
+      &mut inscription_number_to_content_type,
       &mut inscription_number_to_inscription_id,
       &mut outpoint_to_entry,
       &mut reinscription_id_to_seq_num,
       &mut sat_to_inscription_id,
       &mut satpoint_to_inscription_id,
+      &mut script_key_to_inscription_numbers,
+      index.get_chain_network(),
       block.header.time,
       unbound_inscriptions,
       &mut tx_out_cache,
@@ -425,7 +657,22 @@ impl<'index> Updater<'_> {
 
     if self.index_sats {
       let mut sat_to_satpoint = wtx.open_table(SAT_TO_SATPOINT)?;
+      let mut rare_sat_owners = RareSatOwners {
+        script_key_to_rare_sats: wtx.open_multimap_table(SCRIPT_KEY_TO_RARE_SATS)?,
+        rare_sat_to_script_key: wtx.open_table(RARE_SAT_TO_SCRIPT_KEY)?,
+        network: index.get_chain_network(),
+      };
       let mut outpoint_to_sat_ranges = wtx.open_table(OUTPOINT_TO_SAT_RANGES)?;
+      let mut pizza_sat_ranges = wtx.open_table(PIZZA_SAT_RANGES)?;
+      let pizza_txid = Txid::from_str(PIZZA_TXID)?;
+
+      if index.get_chain_network() == Network::Bitcoin
+        && is_nakamoto_block(Height(self.height), block.header.nonce)
+      {
+        wtx
+          .open_table(NAKAMOTO_HEIGHTS)?
+          .insert(&self.height, &())?;
+      }
 
       let mut coinbase_inputs = VecDeque::new();
 
@@ -436,7 +683,13 @@ impl<'index> Updater<'_> {
         self.sat_ranges_since_flush += 1;
       }
 
-      for (tx_offset, (tx, txid)) in block.txdata.iter().enumerate().skip(1) {
+      for (tx_offset, ((tx, txid), tx_inscriptions)) in block
+        .txdata
+        .iter()
+        .zip(&block.inscriptions)
+        .enumerate()
+        .skip(1)
+      {
         log::trace!("Indexing transaction {tx_offset}…");
 
         let mut input_sat_ranges = VecDeque::new();
@@ -461,10 +714,18 @@ impl<'index> Updater<'_> {
           }
         }
 
+        if *txid == pizza_txid {
+          for (start, end) in &input_sat_ranges {
+            pizza_sat_ranges.insert(start, end)?;
+          }
+        }
+
         self.index_transaction_sats(
           tx,
           *txid,
+          tx_inscriptions,
           &mut sat_to_satpoint,
+          &mut rare_sat_owners,
           &mut input_sat_ranges,
           &mut sat_ranges_written,
           &mut outputs_in_block,
@@ -475,11 +736,15 @@ impl<'index> Updater<'_> {
         coinbase_inputs.extend(input_sat_ranges);
       }
 
-      if let Some((tx, txid)) = block.txdata.get(0) {
+      if let Some(((tx, txid), tx_inscriptions)) =
+        block.txdata.iter().zip(&block.inscriptions).next()
+      {
         self.index_transaction_sats(
           tx,
           *txid,
+          tx_inscriptions,
           &mut sat_to_satpoint,
+          &mut rare_sat_owners,
           &mut coinbase_inputs,
           &mut sat_ranges_written,
           &mut outputs_in_block,
@@ -504,6 +769,7 @@ impl<'index> Updater<'_> {
               }
               .store(),
             )?;
+            rare_sat_owners.update(start, None)?;
           }
 
           lost_sat_ranges.extend_from_slice(&(start, end).store());
@@ -514,8 +780,10 @@ impl<'index> Updater<'_> {
         outpoint_to_sat_ranges.insert(&OutPoint::null().store(), lost_sat_ranges.as_slice())?;
       }
     } else {
-      for (tx, txid) in block.txdata.iter().skip(1).chain(block.txdata.first()) {
-        inscription_updater.index_transaction_inscriptions(tx, *txid, None)?;
+      let transactions = block.txdata.iter().zip(&block.inscriptions);
+      for ((tx, txid), tx_inscriptions) in transactions.clone().skip(1).chain(transactions.take(1))
+      {
+        inscription_updater.index_transaction_inscriptions(tx, *txid, tx_inscriptions, None)?;
       }
     }
 
@@ -528,16 +796,36 @@ impl<'index> Updater<'_> {
     let lost_sats = inscription_updater.lost_sats;
     let unbound_inscriptions = inscription_updater.unbound_inscriptions;
     let operations = inscription_updater.operations.clone();
+    let inscription_stats = std::mem::take(&mut inscription_updater.stats);
+
+    // with --no-txindex every output is kept, so previous outputs never need to be fetched.
+    if index.options.no_txindex {
+      for (tx, txid) in &block.txdata {
+        for (vout, tx_out) in tx.output.iter().enumerate() {
+          tx_out_cache
+            .entry(OutPoint {
+              txid: *txid,
+              vout: vout.try_into().unwrap(),
+            })
+            .or_insert_with(|| tx_out.clone());
+        }
+      }
+    }
 
     // write tx_out to outpoint_to_entry table.
     for (outpoint, tx_out) in tx_out_cache {
       let mut entry = Vec::new();
       tx_out.consensus_encode(&mut entry)?;
       outpoint_to_entry.insert(&outpoint.store(), entry.as_slice())?;
+      self.dirty_bytes += u64::try_from(36 + entry.len()).unwrap();
     }
 
+    self.dirty_bytes += u64::try_from(operations.values().map(Vec::len).sum::<usize>()).unwrap()
+      * DIRTY_BYTES_PER_INSCRIPTION_OPERATION;
+
     std::mem::drop(inscription_id_to_inscription_entry);
     std::mem::drop(outpoint_to_entry);
+    std::mem::drop(script_key_to_inscription_numbers);
 
     // Create a protocol manager to index the block of brc20, brc20s data.
     let config = ProtocolConfig::new_with_options(&index.options);
@@ -555,10 +843,41 @@ impl<'index> Updater<'_> {
 
     statistic_to_count.insert(&Statistic::UnboundInscriptions.key(), &unbound_inscriptions)?;
 
+    if !inscription_stats.is_empty() {
+      let mut content_type_to_inscription_stats =
+        wtx.open_table(CONTENT_TYPE_TO_INSCRIPTION_STATS)?;
+      for (content_type, stats) in &inscription_stats.content_types {
+        let (count, bytes) = content_type_to_inscription_stats
+          .get(content_type.as_str())?
+          .map(|value| value.value())
+          .unwrap_or_default();
+        content_type_to_inscription_stats.insert(
+          content_type.as_str(),
+          &(count + stats.count, bytes + stats.bytes),
+        )?;
+      }
+
+      let cursed_inscriptions = statistic_to_count
+        .get(&Statistic::CursedInscriptions.key())?
+        .map(|cursed_inscriptions| cursed_inscriptions.value())
+        .unwrap_or(0);
+      statistic_to_count.insert(
+        &Statistic::CursedInscriptions.key(),
+        &(cursed_inscriptions + inscription_stats.cursed),
+      )?;
+
+      wtx.open_table(HEIGHT_TO_INSCRIPTION_STATS)?.insert(
+        &self.height,
+        bincode::serialize(&inscription_stats).unwrap().as_slice(),
+      )?;
+    }
+
     height_to_block_hash.insert(&self.height, &block.header.block_hash().store())?;
 
     self.height += 1;
     self.outputs_traversed += outputs_in_block;
+    // sat ranges are cached in memory until the next commit.
+    self.dirty_bytes += sat_ranges_written * 11 + outputs_in_block * 36;
 
     log::info!(
       "Wrote {sat_ranges_written} sat ranges from {outputs_in_block} outputs in {} ms",
@@ -572,7 +891,9 @@ impl<'index> Updater<'_> {
     &mut self,
     tx: &Transaction,
     txid: Txid,
+    tx_inscriptions: &[TransactionInscription],
     sat_to_satpoint: &mut Table<u64, &SatPointValue>,
+    rare_sat_owners: &mut RareSatOwners,
     input_sat_ranges: &mut VecDeque<(u64, u64)>,
     sat_ranges_written: &mut u64,
     outputs_traversed: &mut u64,
@@ -580,7 +901,12 @@ impl<'index> Updater<'_> {
     index_inscriptions: bool,
   ) -> Result {
     if index_inscriptions {
-      inscription_updater.index_transaction_inscriptions(tx, txid, Some(input_sat_ranges))?;
+      inscription_updater.index_transaction_inscriptions(
+        tx,
+        txid,
+        tx_inscriptions,
+        Some(input_sat_ranges),
+      )?;
     }
 
     for (vout, output) in tx.output.iter().enumerate() {
@@ -589,6 +915,7 @@ impl<'index> Updater<'_> {
         txid,
       };
       let mut sats = Vec::new();
+      let mut owner = None;
 
       let mut remaining = output.value;
       while remaining > 0 {
@@ -605,6 +932,10 @@ impl<'index> Updater<'_> {
             }
             .store(),
           )?;
+          let owner = owner.get_or_insert_with(|| {
+            ScriptKey::from_script(&output.script_pubkey, rare_sat_owners.network).to_string()
+          });
+          rare_sat_owners.update(range.0, Some(owner))?;
         }
 
         let count = range.1 - range.0;
@@ -655,10 +986,20 @@ impl<'index> Updater<'_> {
     Ok(())
   }
 
-  fn commit(&mut self, wtx: WriteTransaction) -> Result {
+  fn commit(
+    &mut self,
+    wtx: WriteTransaction,
+    trigger: CommitTrigger,
+    blocks: u64,
+    elapsed: Duration,
+  ) -> Result {
     log::info!(
-      "Committing at block height {}, {} outputs traversed, {} in map, {} cached",
+      "Committing at block height {} on {} trigger, {} blocks in {:.1}s, ~{} MiB uncommitted, {} outputs traversed, {} in map, {} cached",
       self.height,
+      trigger.name(),
+      blocks,
+      elapsed.as_secs_f64(),
+      self.dirty_bytes >> 20,
       self.outputs_traversed,
       self.range_cache.len(),
       self.outputs_cached
@@ -686,6 +1027,8 @@ impl<'index> Updater<'_> {
     Index::increment_statistic(&wtx, Statistic::SatRanges, self.sat_ranges_since_flush)?;
     self.sat_ranges_since_flush = 0;
     Index::increment_statistic(&wtx, Statistic::Commits, 1)?;
+    Index::increment_statistic(&wtx, trigger.statistic(), 1)?;
+    self.dirty_bytes = 0;
 
     wtx.commit()?;
 
@@ -0,0 +1,152 @@
+use super::*;
+
+/// The reason the updater committed a write transaction.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum CommitTrigger {
+  /// `--commit-interval` blocks were indexed.
+  Blocks,
+  /// `--commit-interval-secs` seconds passed since the write transaction began.
+  Elapsed,
+  /// The estimated size of the uncommitted writes reached `--commit-dirty-bytes`.
+  DirtyBytes,
+  /// The indexed block is within `--commit-tip-distance` blocks of the chain tip.
+  Tip,
+  /// The update ran out of blocks or was interrupted.
+  Finished,
+}
+
+impl CommitTrigger {
+  pub(crate) const ALL: [CommitTrigger; 5] = [
+    Self::Blocks,
+    Self::Elapsed,
+    Self::DirtyBytes,
+    Self::Tip,
+    Self::Finished,
+  ];
+
+  pub(crate) fn name(self) -> &'static str {
+    match self {
+      Self::Blocks => "blocks",
+      Self::Elapsed => "elapsed",
+      Self::DirtyBytes => "dirty_bytes",
+      Self::Tip => "tip",
+      Self::Finished => "finished",
+    }
+  }
+
+  pub(crate) fn statistic(self) -> Statistic {
+    match self {
+      Self::Blocks => Statistic::CommitsByBlocks,
+      Self::Elapsed => Statistic::CommitsByElapsed,
+      Self::DirtyBytes => Statistic::CommitsByDirtyBytes,
+      Self::Tip => Statistic::CommitsAtTip,
+      Self::Finished => Statistic::CommitsFinished,
+    }
+  }
+}
+
+/// Decides when the updater commits its write transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CommitPolicy {
+  pub(crate) blocks: u64,
+  pub(crate) interval: Option<Duration>,
+  pub(crate) dirty_bytes: Option<u64>,
+  pub(crate) tip_distance: u64,
+}
+
+impl CommitPolicy {
+  pub(crate) fn new(options: &Options) -> Self {
+    Self {
+      blocks: options.commit_interval(),
+      interval: options.commit_interval_secs.map(Duration::from_secs),
+      dirty_bytes: options.commit_dirty_bytes(),
+      tip_distance: options.commit_tip_distance(),
+    }
+  }
+
+  /// Returns the trigger for committing after a block, given the number of uncommitted blocks,
+  /// the age of the write transaction, the estimated uncommitted bytes and the number of blocks
+  /// between the next block to index and the chain tip.
+  pub(crate) fn trigger(
+    &self,
+    uncommitted: u64,
+    elapsed: Duration,
+    dirty_bytes: u64,
+    blocks_to_tip: u64,
+  ) -> Option<CommitTrigger> {
+    if blocks_to_tip <= self.tip_distance {
+      Some(CommitTrigger::Tip)
+    } else if uncommitted >= self.blocks {
+      Some(CommitTrigger::Blocks)
+    } else if self
+      .dirty_bytes
+      .map(|limit| dirty_bytes >= limit)
+      .unwrap_or(false)
+    {
+      Some(CommitTrigger::DirtyBytes)
+    } else if self
+      .interval
+      .map(|interval| elapsed >= interval)
+      .unwrap_or(false)
+    {
+      Some(CommitTrigger::Elapsed)
+    } else {
+      None
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn policy() -> CommitPolicy {
+    CommitPolicy {
+      blocks: 200,
+      interval: Some(Duration::from_secs(60)),
+      dirty_bytes: Some(1 << 20),
+      tip_distance: 6,
+    }
+  }
+
+  #[test]
+  fn no_trigger() {
+    assert_eq!(
+      policy().trigger(199, Duration::from_secs(59), (1 << 20) - 1, 7),
+      None
+    );
+  }
+
+  #[test]
+  fn triggers() {
+    assert_eq!(
+      policy().trigger(200, Duration::ZERO, 0, 100),
+      Some(CommitTrigger::Blocks)
+    );
+    assert_eq!(
+      policy().trigger(1, Duration::from_secs(60), 0, 100),
+      Some(CommitTrigger::Elapsed)
+    );
+    assert_eq!(
+      policy().trigger(1, Duration::ZERO, 1 << 20, 100),
+      Some(CommitTrigger::DirtyBytes)
+    );
+    assert_eq!(
+      policy().trigger(1, Duration::ZERO, 0, 6),
+      Some(CommitTrigger::Tip)
+    );
+  }
+
+  #[test]
+  fn disabled_triggers() {
+    let policy = CommitPolicy {
+      interval: None,
+      dirty_bytes: None,
+      ..policy()
+    };
+    assert_eq!(
+      policy.trigger(1, Duration::from_secs(3600), u64::MAX, 100),
+      None
+    );
+  }
+}
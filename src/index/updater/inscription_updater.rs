@@ -1,6 +1,9 @@
 use {
   super::*,
-  crate::okx::datastore::ord::operation::{Action, InscriptionOp},
+  crate::{
+    inscription::TransactionInscription,
+    okx::datastore::ord::operation::{Action, InscriptionOp},
+  },
   inscription::Curse,
 };
 
@@ -28,6 +31,7 @@ enum Origin {
 pub(super) struct InscriptionUpdater<'a, 'db, 'tx> {
   flotsam: Vec<Flotsam>,
   pub(super) operations: HashMap<Txid, Vec<InscriptionOp>>,
+  pub(super) stats: InscriptionStats,
   height: u64,
   id_to_children:
     &'a mut MultimapTable<'db, 'tx, &'static InscriptionIdValue, &'static InscriptionIdValue>,
@@ -37,6 +41,7 @@ pub(super) struct InscriptionUpdater<'a, 'db, 'tx> {
   pub(super) lost_sats: u64,
   pub(super) next_cursed_number: i64,
   pub(super) next_number: i64,
+  number_to_content_type: &'a mut Table<'db, 'tx, i64, &'static str>,
   number_to_id: &'a mut Table<'db, 'tx, i64, &'static InscriptionIdValue>,
   outpoint_to_entry: &'a mut Table<'db, 'tx, &'static OutPointValue, &'static [u8]>,
   reward: u64,
@@ -44,6 +49,8 @@ pub(super) struct InscriptionUpdater<'a, 'db, 'tx> {
   sat_to_inscription_id: &'a mut MultimapTable<'db, 'tx, u64, &'static InscriptionIdValue>,
   satpoint_to_id:
     &'a mut MultimapTable<'db, 'tx, &'static SatPointValue, &'static InscriptionIdValue>,
+  script_key_to_numbers: &'a mut MultimapTable<'db, 'tx, &'static str, i64>,
+  network: Network,
   timestamp: u32,
   pub(super) unbound_inscriptions: u64,
   tx_out_cache: &'a mut HashMap<OutPoint, TxOut>,
@@ -62,6 +69,7 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
     tx_out_receiver: &'a mut Receiver<TxOut>,
     id_to_entry: &'a mut Table<'db, 'tx, &'static InscriptionIdValue, InscriptionEntryValue>,
     lost_sats: u64,
+    number_to_content_type: &'a mut Table<'db, 'tx, i64, &'static str>,
     number_to_id: &'a mut Table<'db, 'tx, i64, &'static InscriptionIdValue>,
     outpoint_to_entry: &'a mut Table<'db, 'tx, &'static OutPointValue, &'static [u8]>,
     reinscription_id_to_seq_num: &'a mut Table<'db, 'tx, &'static InscriptionIdValue, u64>,
@@ -72,6 +80,8 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
       &'static SatPointValue,
       &'static InscriptionIdValue,
     >,
+    script_key_to_numbers: &'a mut MultimapTable<'db, 'tx, &'static str, i64>,
+    network: Network,
     timestamp: u32,
     unbound_inscriptions: u64,
     tx_out_cache: &'a mut HashMap<OutPoint, TxOut>,
@@ -93,6 +103,7 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
     Ok(Self {
       flotsam: Vec::new(),
       operations: HashMap::new(),
+      stats: InscriptionStats::default(),
       height,
       id_to_children,
       id_to_satpoint,
@@ -101,12 +112,15 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
       lost_sats,
       next_cursed_number,
       next_number,
+      number_to_content_type,
       number_to_id,
       outpoint_to_entry,
       reward: Height(height).subsidy(),
       reinscription_id_to_seq_num,
       sat_to_inscription_id,
       satpoint_to_id,
+      script_key_to_numbers,
+      network,
       timestamp,
       unbound_inscriptions,
       tx_out_cache,
@@ -117,9 +131,10 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
     &mut self,
     tx: &Transaction,
     txid: Txid,
+    new_inscriptions: &[TransactionInscription],
     input_sat_ranges: Option<&VecDeque<(u64, u64)>>,
   ) -> Result {
-    let mut new_inscriptions = Inscription::from_transaction(tx).into_iter().peekable();
+    let mut new_inscriptions = new_inscriptions.iter().peekable();
     let mut floating_inscriptions = Vec::new();
     let mut inscribed_offsets = BTreeMap::new();
     let mut total_input_value = 0;
@@ -351,6 +366,15 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
     for (vout, tx_out) in tx.output.iter().enumerate() {
       let end = output_value + tx_out.value;
 
+      // cached before the inscriptions are moved, so their new owner can be looked up.
+      self.tx_out_cache.insert(
+        OutPoint {
+          vout: vout.try_into().unwrap(),
+          txid,
+        },
+        tx_out.clone(),
+      );
+
       while let Some(flotsam) = inscriptions.peek() {
         if flotsam.offset >= end {
           break;
@@ -372,14 +396,6 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
       }
 
       output_value = end;
-
-      self.tx_out_cache.insert(
-        OutPoint {
-          vout: vout.try_into().unwrap(),
-          txid,
-        },
-        tx_out.clone(),
-      );
     }
 
     if is_coinbase {
@@ -422,6 +438,24 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
     sat
   }
 
+  /// Returns the owner of an output spent or created in this block, `None` for lost sats.
+  fn script_key_of(&self, outpoint: OutPoint) -> Result<Option<ScriptKey>> {
+    if outpoint == OutPoint::null() || outpoint == unbound_outpoint() {
+      return Ok(None);
+    }
+
+    let tx_out = match self.tx_out_cache.get(&outpoint) {
+      Some(tx_out) => tx_out.clone(),
+      None => Index::transaction_output_by_outpoint(self.outpoint_to_entry, outpoint)?
+        .ok_or_else(|| anyhow!("failed to get transaction output {outpoint}"))?,
+    };
+
+    Ok(Some(ScriptKey::from_script(
+      &tx_out.script_pubkey,
+      self.network,
+    )))
+  }
+
   fn update_inscription_location(
     &mut self,
     input_sat_ranges: Option<&VecDeque<(u64, u64)>>,
@@ -429,20 +463,40 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
     new_satpoint: SatPoint,
   ) -> Result {
     let inscription_id = flotsam.inscription_id.store();
-    let unbound = match flotsam.origin {
+    let (number, unbound) = match flotsam.origin {
       Origin::Old => {
         self
           .satpoint_to_id
           .remove_all(&flotsam.old_satpoint.store())?;
-        false
+
+        let number = self
+          .id_to_entry
+          .get(&inscription_id)?
+          .map(|entry| InscriptionEntry::load(entry.value()).number)
+          .ok_or_else(|| {
+            anyhow!(
+              "failed to get entry of inscription {}",
+              flotsam.inscription_id
+            )
+          })?;
+
+        if let Some(script_key) = self.script_key_of(flotsam.old_satpoint.outpoint)? {
+          self
+            .script_key_to_numbers
+            .remove(script_key.to_string().as_str(), number)?;
+        }
+
+        (number, false)
       }
       Origin::New {
         cursed,
         fee,
         parent,
-        inscription: _,
+        ref inscription,
         unbound,
       } => {
+        self.stats.record(inscription, cursed, unbound);
+
         let number = if cursed {
           let next_cursed_number = self.next_cursed_number;
           self.next_cursed_number -= 1;
@@ -457,6 +511,10 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
 
         self.number_to_id.insert(number, &inscription_id)?;
 
+        if let Some(content_type) = inscription.content_type() {
+          self.number_to_content_type.insert(number, content_type)?;
+        }
+
         let sat = if unbound {
           None
         } else {
@@ -486,10 +544,18 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
             .insert(&parent.store(), &inscription_id)?;
         }
 
-        unbound
+        (number, unbound)
       }
     };
 
+    if !unbound {
+      if let Some(script_key) = self.script_key_of(new_satpoint.outpoint)? {
+        self
+          .script_key_to_numbers
+          .insert(script_key.to_string().as_str(), number)?;
+      }
+    }
+
     let satpoint = if unbound {
       let new_unbound_satpoint = SatPoint {
         outpoint: unbound_outpoint(),
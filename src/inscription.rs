@@ -14,6 +14,7 @@ const PROTOCOL_ID: [u8; 3] = *b"ord";
 const BODY_TAG: [u8; 0] = [];
 const CONTENT_TYPE_TAG: [u8; 1] = [1];
 const PARENT_TAG: [u8; 1] = [3];
+const METADATA_TAG: [u8; 1] = [5];
 
 #[derive(Debug, PartialEq, Clone)]
 pub(crate) enum Curse {
@@ -29,6 +30,9 @@ pub struct Inscription {
   pub content_type: Option<Vec<u8>>,
   pub parent: Option<Vec<u8>>,
   pub unrecognized_even_field: bool,
+  // CBOR encoded metadata, read from the reveal transaction and not stored with the operations.
+  #[serde(skip)]
+  pub metadata: Option<Vec<u8>>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -46,6 +50,7 @@ impl Inscription {
       body,
       parent: None,
       unrecognized_even_field: false,
+      metadata: None,
     }
   }
 
@@ -96,6 +101,7 @@ impl Inscription {
       content_type: Some(content_type.into()),
       parent: parent.map(|id| id.parent_value()),
       unrecognized_even_field: false,
+      metadata: None,
     })
   }
 
@@ -118,6 +124,12 @@ impl Inscription {
         .push_slice(PushBytesBuf::try_from(parent).unwrap());
     }
 
+    if let Some(metadata) = self.metadata.clone() {
+      builder = builder
+        .push_slice(METADATA_TAG)
+        .push_slice(PushBytesBuf::try_from(metadata).unwrap());
+    }
+
     if let Some(body) = &self.body {
       builder = builder.push_slice(BODY_TAG);
       for chunk in body.chunks(520) {
@@ -161,6 +173,10 @@ impl Inscription {
     str::from_utf8(self.content_type.as_ref()?).ok()
   }
 
+  pub(crate) fn metadata(&self) -> Option<&[u8]> {
+    self.metadata.as_deref()
+  }
+
   pub(crate) fn parent(&self) -> Option<InscriptionId> {
     let value = self.parent.as_ref()?;
 
@@ -210,7 +226,7 @@ impl Inscription {
 }
 
 #[derive(Debug, PartialEq)]
-pub(crate) enum InscriptionError {
+pub enum InscriptionError {
   InvalidInscription,
   NoInscription,
   NoTapscript,
@@ -220,12 +236,12 @@ pub(crate) enum InscriptionError {
 type Result<T, E = InscriptionError> = std::result::Result<T, E>;
 
 #[derive(Debug)]
-struct InscriptionParser<'a> {
+pub struct InscriptionParser<'a> {
   instructions: Peekable<Instructions<'a>>,
 }
 
 impl<'a> InscriptionParser<'a> {
-  fn parse(witness: &Witness) -> Result<Vec<Inscription>> {
+  pub fn parse(witness: &Witness) -> Result<Vec<Inscription>> {
     let Some(tapscript) = witness.tapscript() else {
       return Err(InscriptionError::NoTapscript);
     };
@@ -279,6 +295,7 @@ impl<'a> InscriptionParser<'a> {
     let body = fields.remove(BODY_TAG.as_slice());
     let content_type = fields.remove(CONTENT_TYPE_TAG.as_slice());
     let parent = fields.remove(PARENT_TAG.as_slice());
+    let metadata = fields.remove(METADATA_TAG.as_slice());
     let mut unrecognized_even_field = false;
 
     for tag in fields.keys() {
@@ -294,6 +311,7 @@ impl<'a> InscriptionParser<'a> {
       content_type,
       parent,
       unrecognized_even_field,
+      metadata,
     })
   }
 
@@ -436,7 +454,7 @@ mod tests {
         b"ord",
         &[1],
         b"text/plain;charset=utf-8",
-        &[5],
+        &[7],
         b"bar",
         &[],
         b"ord",
@@ -454,6 +472,7 @@ mod tests {
         body: None,
         parent: None,
         unrecognized_even_field: false,
+        metadata: None,
       }]),
     );
   }
@@ -467,6 +486,7 @@ mod tests {
         parent: None,
         body: Some(b"foo".to_vec()),
         unrecognized_even_field: false,
+        metadata: None,
       }]),
     );
   }
@@ -799,6 +819,7 @@ mod tests {
         content_type: None,
         parent: None,
         unrecognized_even_field: false,
+        metadata: None,
       }
       .append_reveal_script(script::Builder::new()),
     );
@@ -812,6 +833,7 @@ mod tests {
         parent: None,
         body: None,
         unrecognized_even_field: false,
+        metadata: None,
       }]
     );
   }
@@ -819,16 +841,47 @@ mod tests {
   #[test]
   fn unknown_odd_fields_are_ignored() {
     assert_eq!(
-      InscriptionParser::parse(&envelope(&[b"ord", &[5], &[0]])),
+      InscriptionParser::parse(&envelope(&[b"ord", &[7], &[0]])),
       Ok(vec![Inscription {
         content_type: None,
         parent: None,
         body: None,
         unrecognized_even_field: false,
+        metadata: None,
       }]),
     );
   }
 
+  #[test]
+  fn metadata_is_parsed() {
+    assert_eq!(
+      InscriptionParser::parse(&envelope(&[b"ord", &[5], &[0xa0], &[], b"foo"])),
+      Ok(vec![Inscription {
+        content_type: None,
+        parent: None,
+        body: Some(b"foo".to_vec()),
+        unrecognized_even_field: false,
+        metadata: Some(vec![0xa0]),
+      }]),
+    );
+  }
+
+  #[test]
+  fn duplicate_metadata_field() {
+    assert_eq!(
+      InscriptionParser::parse(&envelope(&[
+        b"ord",
+        &[5],
+        &[0xa1, 0x61],
+        &[5],
+        &[0x61, 0x01],
+        &[],
+        b"foo"
+      ])),
+      Err(InscriptionError::InvalidInscription),
+    );
+  }
+
   #[test]
   fn unknown_even_fields() {
     assert_eq!(
@@ -838,6 +891,7 @@ mod tests {
         body: None,
         parent: None,
         unrecognized_even_field: true,
+        metadata: None,
       }]),
     );
   }
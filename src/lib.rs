@@ -72,8 +72,30 @@ use {
 
 pub use crate::{
   inscription::Inscription, object::Object, rarity::Rarity, sat::Sat, sat_point::SatPoint,
+  satribute::Satribute,
 };
 
+/// The parsers and reward calculations fuzzed by the targets in `fuzz/`, not a stable API.
+#[doc(hidden)]
+pub mod fuzzing {
+  pub use crate::inscription::{InscriptionError, InscriptionParser};
+
+  pub mod brc20 {
+    pub use crate::okx::protocol::brc20::{deserialize_brc20, Num, RawOperation};
+  }
+
+  pub mod brc20s {
+    pub use crate::okx::{
+      datastore::brc20s::{Pid, PledgedTick, PoolInfo, PoolType, UserInfo},
+      protocol::brc20s::{
+        operation::{deserialize_brc20s, RawOperation},
+        Num,
+      },
+      reward::{update_pool, withdraw_user_reward},
+    };
+  }
+}
+
 #[cfg(test)]
 #[macro_use]
 mod test;
@@ -114,6 +136,7 @@ pub mod rarity;
 mod representation;
 pub mod sat;
 mod sat_point;
+pub mod satribute;
 pub mod subcommand;
 mod tally;
 pub mod templates;
@@ -63,4 +63,13 @@ pub enum BRC20Error {
 
   #[error("invalid integer {0}")]
   InvalidInteger(String),
+
+  #[error("tick: {0} must be deployed with self_mint")]
+  SelfMintRequired(String),
+
+  #[error("tick: {0} can only be minted by the children of its deploy inscription")]
+  SelfMintPermissionDenied(String),
+
+  #[error("transfer inscription {0} to coinbase")]
+  TransferToCoinbase(InscriptionId),
 }
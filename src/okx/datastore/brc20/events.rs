@@ -27,6 +27,7 @@ pub enum Event {
   Mint(MintEvent),
   InscribeTransfer(InscribeTransferEvent),
   Transfer(TransferEvent),
+  Burn(BurnEvent),
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -57,6 +58,12 @@ pub struct TransferEvent {
   pub msg: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct BurnEvent {
+  pub tick: Tick,
+  pub amount: u128,
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
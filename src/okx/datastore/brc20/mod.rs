@@ -25,13 +25,19 @@ pub trait DataStoreReadOnly {
     script_key: &ScriptKey,
     tick: &Tick,
   ) -> Result<Option<Balance>, Self::Error>;
+  fn get_all_balances(&self) -> Result<Vec<(ScriptKey, Balance)>, Self::Error>;
 
   fn get_token_info(&self, tick: &Tick) -> Result<Option<TokenInfo>, Self::Error>;
   fn get_tokens_info(&self) -> Result<Vec<TokenInfo>, Self::Error>;
+  fn search_ticks(&self, keyword: &str, limit: usize) -> Result<Vec<Tick>, Self::Error>;
 
   fn get_transaction_receipts(&self, txid: &Txid) -> Result<Vec<Receipt>, Self::Error>;
 
+  /// The transactions of the block with receipts, in block order.
+  fn get_block_txids(&self, height: u64) -> Result<Vec<Txid>, Self::Error>;
+
   fn get_transferable(&self, script: &ScriptKey) -> Result<Vec<TransferableLog>, Self::Error>;
+  fn get_all_transferable(&self) -> Result<Vec<TransferableLog>, Self::Error>;
   fn get_transferable_by_tick(
     &self,
     script: &ScriptKey,
@@ -47,6 +53,9 @@ pub trait DataStoreReadOnly {
     &self,
     inscription_id: InscriptionId,
   ) -> Result<Option<TransferInfo>, Self::Error>;
+  fn get_all_inscribe_transfer_inscriptions(
+    &self,
+  ) -> Result<Vec<(InscriptionId, TransferInfo)>, Self::Error>;
 }
 
 pub trait DataStoreReadWrite: DataStoreReadOnly {
@@ -65,11 +74,15 @@ pub trait DataStoreReadWrite: DataStoreReadOnly {
     minted_block_number: u64,
   ) -> Result<(), Self::Error>;
 
+  fn update_burned_token_info(&self, tick: &Tick, burned_amt: u128) -> Result<(), Self::Error>;
+
   fn save_transaction_receipts(&self, txid: &Txid, receipts: &[Receipt])
     -> Result<(), Self::Error>;
 
   fn add_transaction_receipt(&self, txid: &Txid, receipt: &Receipt) -> Result<(), Self::Error>;
 
+  fn add_block_txid(&self, height: u64, txid: &Txid) -> Result<(), Self::Error>;
+
   fn insert_transferable(
     &self,
     script: &ScriptKey,
@@ -16,6 +16,9 @@ const BRC20_TRANSFERABLELOG: TableDefinition<&str, &[u8]> =
   TableDefinition::new("BRC20_TRANSFERABLELOG");
 const BRC20_INSCRIBE_TRANSFER: TableDefinition<&[u8; 36], &[u8]> =
   TableDefinition::new("BRC20_INSCRIBE_TRANSFER");
+const BRC20_TICK_SEARCH: TableDefinition<&str, &[u8]> = TableDefinition::new("BRC20_TICK_SEARCH");
+const BRC20_HEIGHT_TO_TXIDS: TableDefinition<u64, &[u8]> =
+  TableDefinition::new("BRC20_HEIGHT_TO_TXIDS");
 
 fn script_tick_key(script: &ScriptKey, tick: &Tick) -> String {
   format!("{}_{}", script, tick.to_lowercase().hex())
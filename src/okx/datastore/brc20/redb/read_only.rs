@@ -2,12 +2,14 @@ use super::*;
 use crate::okx::datastore::brc20::{
   Balance, DataStoreReadOnly, Receipt, Tick, TokenInfo, TransferInfo, TransferableLog,
 };
+use crate::Hash;
 use redb::{
   AccessGuard, Range, ReadOnlyTable, ReadTransaction, ReadableTable, RedbKey, RedbValue,
   StorageError, Table, TableDefinition, WriteTransaction,
 };
 use std::borrow::Borrow;
 use std::ops::RangeBounds;
+use std::str::FromStr;
 
 pub fn try_init_tables<'db, 'a>(
   wtx: &'a WriteTransaction<'db>,
@@ -19,6 +21,22 @@ pub fn try_init_tables<'db, 'a>(
     wtx.open_table(BRC20_EVENTS)?;
     wtx.open_table(BRC20_TRANSFERABLELOG)?;
     wtx.open_table(BRC20_INSCRIBE_TRANSFER)?;
+    wtx.open_table(BRC20_HEIGHT_TO_TXIDS)?;
+  }
+
+  // build the search index for ticks deployed before it was introduced.
+  if rtx.open_table(BRC20_TICK_SEARCH).is_err() {
+    let mut tick_search = wtx.open_table(BRC20_TICK_SEARCH)?;
+    for result in wtx.open_table(BRC20_TOKEN)?.iter()? {
+      let (_, data) = result?;
+      let tick = bincode::deserialize::<TokenInfo>(data.value())
+        .unwrap()
+        .tick;
+      tick_search.insert(
+        tick.to_lowercase().as_str(),
+        bincode::serialize(&tick).unwrap().as_slice(),
+      )?;
+    }
   }
 
   Ok(true)
@@ -123,6 +141,26 @@ impl<'db, 'a> DataStoreReadOnly for DataStoreReader<'db, 'a> {
     )
   }
 
+  fn get_all_balances(&self) -> Result<Vec<(ScriptKey, Balance)>, Self::Error> {
+    Ok(
+      self
+        .wrapper
+        .open_table(BRC20_BALANCES)?
+        .range::<&str>(..)?
+        .flat_map(|result| {
+          result.map(|(key, data)| {
+            // the key is composed of the script and the lowercase tick.
+            let (script, _) = key.value().split_once('_').unwrap();
+            (
+              ScriptKey::from_str(script).unwrap(),
+              bincode::deserialize::<Balance>(data.value()).unwrap(),
+            )
+          })
+        })
+        .collect(),
+    )
+  }
+
   fn get_token_info(&self, tick: &Tick) -> Result<Option<TokenInfo>, Self::Error> {
     Ok(
       self
@@ -146,6 +184,24 @@ impl<'db, 'a> DataStoreReadOnly for DataStoreReader<'db, 'a> {
     )
   }
 
+  fn search_ticks(&self, keyword: &str, limit: usize) -> Result<Vec<Tick>, Self::Error> {
+    let keyword = keyword.to_lowercase();
+    let mut ticks = Vec::new();
+    for result in self
+      .wrapper
+      .open_table(BRC20_TICK_SEARCH)?
+      .range::<&str>(keyword.as_str()..)?
+      .take(limit)
+    {
+      let (lower_tick, data) = result?;
+      if !lower_tick.value().starts_with(&keyword) {
+        break;
+      }
+      ticks.push(bincode::deserialize::<Tick>(data.value()).unwrap());
+    }
+    Ok(ticks)
+  }
+
   fn get_transaction_receipts(&self, txid: &Txid) -> Result<Vec<Receipt>, Self::Error> {
     Ok(
       self
@@ -158,6 +214,18 @@ impl<'db, 'a> DataStoreReadOnly for DataStoreReader<'db, 'a> {
     )
   }
 
+  fn get_block_txids(&self, height: u64) -> Result<Vec<Txid>, Self::Error> {
+    Ok(
+      self
+        .wrapper
+        .open_table(BRC20_HEIGHT_TO_TXIDS)?
+        .get(height)?
+        .map_or(Vec::new(), |v| {
+          bincode::deserialize::<Vec<Txid>>(v.value()).unwrap()
+        }),
+    )
+  }
+
   fn get_transferable(&self, script: &ScriptKey) -> Result<Vec<TransferableLog>, Self::Error> {
     Ok(
       self
@@ -172,6 +240,20 @@ impl<'db, 'a> DataStoreReadOnly for DataStoreReader<'db, 'a> {
     )
   }
 
+  fn get_all_transferable(&self) -> Result<Vec<TransferableLog>, Self::Error> {
+    Ok(
+      self
+        .wrapper
+        .open_table(BRC20_TRANSFERABLELOG)?
+        .range::<&str>(..)?
+        .flat_map(|result| {
+          result.map(|(_, v)| bincode::deserialize::<Vec<TransferableLog>>(v.value()).unwrap())
+        })
+        .flatten()
+        .collect(),
+    )
+  }
+
   fn get_transferable_by_tick(
     &self,
     script: &ScriptKey,
@@ -218,4 +300,28 @@ impl<'db, 'a> DataStoreReadOnly for DataStoreReader<'db, 'a> {
         .map(|v| bincode::deserialize::<TransferInfo>(v.value()).unwrap()),
     )
   }
+
+  fn get_all_inscribe_transfer_inscriptions(
+    &self,
+  ) -> Result<Vec<(InscriptionId, TransferInfo)>, Self::Error> {
+    Ok(
+      self
+        .wrapper
+        .open_table(BRC20_INSCRIBE_TRANSFER)?
+        .range::<&[u8; 36]>(..)?
+        .flat_map(|result| {
+          result.map(|(k, v)| {
+            let (txid, index) = k.value().split_at(32);
+            (
+              InscriptionId {
+                txid: Txid::from_raw_hash(Hash::from_slice(txid).unwrap()),
+                index: u32::from_be_bytes(index.try_into().unwrap()),
+              },
+              bincode::deserialize::<TransferInfo>(v.value()).unwrap(),
+            )
+          })
+        })
+        .collect(),
+    )
+  }
 }
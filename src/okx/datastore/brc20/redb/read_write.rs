@@ -35,6 +35,10 @@ impl<'db, 'a> DataStoreReadOnly for DataStore<'db, 'a> {
     read_only::new_with_wtx(self.wtx).get_balance(script_key, tick)
   }
 
+  fn get_all_balances(&self) -> Result<Vec<(ScriptKey, Balance)>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_all_balances()
+  }
+
   fn get_token_info(&self, tick: &Tick) -> Result<Option<TokenInfo>, Self::Error> {
     read_only::new_with_wtx(self.wtx).get_token_info(tick)
   }
@@ -43,14 +47,26 @@ impl<'db, 'a> DataStoreReadOnly for DataStore<'db, 'a> {
     read_only::new_with_wtx(self.wtx).get_tokens_info()
   }
 
+  fn search_ticks(&self, keyword: &str, limit: usize) -> Result<Vec<Tick>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).search_ticks(keyword, limit)
+  }
+
   fn get_transaction_receipts(&self, txid: &Txid) -> Result<Vec<Receipt>, Self::Error> {
     read_only::new_with_wtx(self.wtx).get_transaction_receipts(txid)
   }
 
+  fn get_block_txids(&self, height: u64) -> Result<Vec<Txid>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_block_txids(height)
+  }
+
   fn get_transferable(&self, script: &ScriptKey) -> Result<Vec<TransferableLog>, Self::Error> {
     read_only::new_with_wtx(self.wtx).get_transferable(script)
   }
 
+  fn get_all_transferable(&self) -> Result<Vec<TransferableLog>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_all_transferable()
+  }
+
   fn get_transferable_by_tick(
     &self,
     script: &ScriptKey,
@@ -73,6 +89,12 @@ impl<'db, 'a> DataStoreReadOnly for DataStore<'db, 'a> {
   ) -> Result<Option<TransferInfo>, Self::Error> {
     read_only::new_with_wtx(self.wtx).get_inscribe_transfer_inscription(inscription_id)
   }
+
+  fn get_all_inscribe_transfer_inscriptions(
+    &self,
+  ) -> Result<Vec<(InscriptionId, TransferInfo)>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_all_inscribe_transfer_inscriptions()
+  }
 }
 
 impl<'db, 'a> DataStoreReadWrite for DataStore<'db, 'a> {
@@ -93,6 +115,10 @@ impl<'db, 'a> DataStoreReadWrite for DataStore<'db, 'a> {
       tick.to_lowercase().hex().as_str(),
       bincode::serialize(new_info).unwrap().as_slice(),
     )?;
+    self.wtx.open_table(BRC20_TICK_SEARCH)?.insert(
+      tick.to_lowercase().as_str(),
+      bincode::serialize(tick).unwrap().as_slice(),
+    )?;
     Ok(())
   }
 
@@ -116,6 +142,20 @@ impl<'db, 'a> DataStoreReadWrite for DataStore<'db, 'a> {
     Ok(())
   }
 
+  fn update_burned_token_info(&self, tick: &Tick, burned_amt: u128) -> Result<(), Self::Error> {
+    let mut info = self
+      .get_token_info(tick)?
+      .unwrap_or_else(|| panic!("token {} not exist", tick.as_str()));
+
+    info.burned = burned_amt;
+
+    self.wtx.open_table(BRC20_TOKEN)?.insert(
+      tick.to_lowercase().hex().as_str(),
+      bincode::serialize(&info).unwrap().as_slice(),
+    )?;
+    Ok(())
+  }
+
   fn save_transaction_receipts(
     &self,
     txid: &Txid,
@@ -134,6 +174,19 @@ impl<'db, 'a> DataStoreReadWrite for DataStore<'db, 'a> {
     self.save_transaction_receipts(txid, &receipts)
   }
 
+  fn add_block_txid(&self, height: u64, txid: &Txid) -> Result<(), Self::Error> {
+    let mut txids = self.get_block_txids(height)?;
+    // the receipts of a transaction are added one after another.
+    if txids.last() != Some(txid) {
+      txids.push(*txid);
+      self
+        .wtx
+        .open_table(BRC20_HEIGHT_TO_TXIDS)?
+        .insert(height, bincode::serialize(&txids).unwrap().as_slice())?;
+    }
+    Ok(())
+  }
+
   fn insert_transferable(
     &self,
     script: &ScriptKey,
@@ -298,6 +351,46 @@ mod tests {
     assert_eq!(all_balances, expect_balances);
   }
 
+  #[test]
+  fn test_get_all_balances() {
+    let dbfile = NamedTempFile::new().unwrap();
+    let db = Database::create(dbfile.path()).unwrap();
+    let wtx = db.begin_write().unwrap();
+    let brc20db = DataStore::new(&wtx);
+
+    let script1 = ScriptKey::from_address(
+      Address::from_str("bc1qhvd6suvqzjcu9pxjhrwhtrlj85ny3n2mqql5w4")
+        .unwrap()
+        .assume_checked(),
+    );
+    let script2 = ScriptKey::from_address(
+      Address::from_str("33iFwdLuRpW1uK1RTRqsoi8rR4NpDzk66k")
+        .unwrap()
+        .assume_checked(),
+    );
+    let balance1 = Balance {
+      tick: Tick::from_str("abcd").unwrap(),
+      overall_balance: 10,
+      transferable_balance: 10,
+    };
+    let balance2 = Balance {
+      tick: Tick::from_str("1234").unwrap(),
+      overall_balance: 30,
+      transferable_balance: 0,
+    };
+
+    brc20db
+      .update_token_balance(&script1, balance1.clone())
+      .unwrap();
+    brc20db
+      .update_token_balance(&script2, balance2.clone())
+      .unwrap();
+
+    let mut all_balances = brc20db.get_all_balances().unwrap();
+    all_balances.sort_by(|a, b| a.1.tick.cmp(&b.1.tick));
+    assert_eq!(all_balances, vec![(script2, balance2), (script1, balance1)]);
+  }
+
   #[test]
   fn test_set_get_balance() {
     let dbfile = NamedTempFile::new().unwrap();
@@ -364,6 +457,8 @@ mod tests {
       deployed_number: 99,
       deployed_timestamp: 11222,
       latest_mint_number: 101,
+      self_mint: false,
+      burned: 0,
     };
 
     brc20db.insert_token_info(&upper_tick, &expect).unwrap();
@@ -401,6 +496,8 @@ mod tests {
       deployed_number: 99,
       deployed_timestamp: 11222,
       latest_mint_number: 101,
+      self_mint: false,
+      burned: 0,
     };
     let expect2 = TokenInfo {
       tick: Tick::from_str("1234").unwrap(),
@@ -421,6 +518,8 @@ mod tests {
       deployed_number: 299,
       deployed_timestamp: 33222,
       latest_mint_number: 2101,
+      self_mint: false,
+      burned: 0,
     };
     let expect3 = TokenInfo {
       tick: Tick::from_str("xyzm").unwrap(),
@@ -441,6 +540,8 @@ mod tests {
       deployed_number: 399,
       deployed_timestamp: 33222,
       latest_mint_number: 3101,
+      self_mint: false,
+      burned: 0,
     };
 
     let expect4 = TokenInfo {
@@ -462,6 +563,8 @@ mod tests {
       deployed_number: 499,
       deployed_timestamp: 44222,
       latest_mint_number: 4101,
+      self_mint: false,
+      burned: 0,
     };
 
     brc20db.insert_token_info(&expect1.tick, &expect1).unwrap();
@@ -476,6 +579,54 @@ mod tests {
     assert_eq!(infos, expect);
   }
 
+  #[test]
+  fn test_search_ticks() {
+    let dbfile = NamedTempFile::new().unwrap();
+    let db = Database::create(dbfile.path()).unwrap();
+    let wtx = db.begin_write().unwrap();
+    let brc20db = DataStore::new(&wtx);
+
+    for tick in ["word", "ordi", "ORDS", "abcd"] {
+      let tick = Tick::from_str(tick).unwrap();
+      let info = TokenInfo {
+        tick: tick.clone(),
+        inscription_id: InscriptionId::from_str(
+          "1111111111111111111111111111111111111111111111111111111111111111i1",
+        )
+        .unwrap(),
+        inscription_number: 1,
+        supply: 100,
+        minted: 10,
+        limit_per_mint: 10,
+        decimal: 1,
+        deploy_by: ScriptKey::from_address(
+          Address::from_str("bc1qhvd6suvqzjcu9pxjhrwhtrlj85ny3n2mqql5w4")
+            .unwrap()
+            .assume_checked(),
+        ),
+        deployed_number: 99,
+        deployed_timestamp: 11222,
+        latest_mint_number: 101,
+        self_mint: false,
+        burned: 0,
+      };
+      brc20db.insert_token_info(&tick, &info).unwrap();
+    }
+
+    assert_eq!(
+      brc20db.search_ticks("Ord", 10).unwrap(),
+      vec![
+        Tick::from_str("ordi").unwrap(),
+        Tick::from_str("ORDS").unwrap(),
+      ]
+    );
+    assert_eq!(
+      brc20db.search_ticks("ord", 1).unwrap(),
+      vec![Tick::from_str("ordi").unwrap()]
+    );
+    assert!(brc20db.search_ticks("xyz", 10).unwrap().is_empty());
+  }
+
   #[test]
   fn test_update_mint_token_info() {
     let dbfile = NamedTempFile::new().unwrap();
@@ -503,6 +654,8 @@ mod tests {
       deployed_number: 99,
       deployed_timestamp: 33222,
       latest_mint_number: 101,
+      self_mint: false,
+      burned: 0,
     };
 
     brc20db.insert_token_info(&tick, &org_info).unwrap();
@@ -518,6 +671,7 @@ mod tests {
       Some(TokenInfo {
         minted: org_info.minted + mint_amount,
         latest_mint_number: mint_block,
+        self_mint: false,
         ..org_info.clone()
       })
     );
@@ -526,6 +680,18 @@ mod tests {
       Some(TokenInfo {
         minted: org_info.minted + mint_amount,
         latest_mint_number: mint_block,
+        self_mint: false,
+        ..org_info.clone()
+      })
+    );
+
+    brc20db.update_burned_token_info(&tick, 5).unwrap();
+    assert_eq!(
+      brc20db.get_token_info(&tick).unwrap(),
+      Some(TokenInfo {
+        minted: org_info.minted + mint_amount,
+        latest_mint_number: mint_block,
+        burned: 5,
         ..org_info
       })
     );
@@ -637,6 +803,28 @@ mod tests {
     assert_eq!(brc20db.get_transaction_receipts(&txid).unwrap(), receipts);
   }
 
+  #[test]
+  fn test_add_get_block_txids() {
+    let dbfile = NamedTempFile::new().unwrap();
+    let db = Database::create(dbfile.path()).unwrap();
+    let wtx = db.begin_write().unwrap();
+    let brc20db = DataStore::new(&wtx);
+
+    let txid1 =
+      Txid::from_str("b61b0172d95e266c18aea0c624db987e971a5d6d4ebc2aaed85da4642d635735").unwrap();
+    let txid2 =
+      Txid::from_str("a61b0172d95e266c18aea0c624db987e971a5d6d4ebc2aaed85da4642d635735").unwrap();
+
+    brc20db.add_block_txid(10, &txid1).unwrap();
+    brc20db.add_block_txid(10, &txid1).unwrap();
+    brc20db.add_block_txid(10, &txid2).unwrap();
+    brc20db.add_block_txid(11, &txid1).unwrap();
+
+    assert_eq!(brc20db.get_block_txids(10).unwrap(), vec![txid1, txid2]);
+    assert_eq!(brc20db.get_block_txids(11).unwrap(), vec![txid1]);
+    assert!(brc20db.get_block_txids(12).unwrap().is_empty());
+  }
+
   #[test]
   fn test_get_transferable_by_tick() {
     let dbfile = NamedTempFile::new().unwrap();
@@ -1062,4 +1250,38 @@ mod tests {
       vec![transferable_log2]
     );
   }
+
+  #[test]
+  fn test_get_all_inscribe_transfer_inscriptions() {
+    let dbfile = NamedTempFile::new().unwrap();
+    let db = Database::create(dbfile.path()).unwrap();
+    let wtx = db.begin_write().unwrap();
+    let brc20db = DataStore::new(&wtx);
+
+    let inscription_id1 =
+      InscriptionId::from_str("1111111111111111111111111111111111111111111111111111111111111111i1")
+        .unwrap();
+    let inscription_id2 =
+      InscriptionId::from_str("2222222222222222222222222222222222222222222222222222222222222222i0")
+        .unwrap();
+    let transfer_info = |amt| TransferInfo {
+      tick: Tick::from_str("abcd").unwrap(),
+      amt,
+    };
+
+    brc20db
+      .insert_inscribe_transfer_inscription(inscription_id1, transfer_info(10))
+      .unwrap();
+    brc20db
+      .insert_inscribe_transfer_inscription(inscription_id2, transfer_info(20))
+      .unwrap();
+    brc20db
+      .remove_inscribe_transfer_inscription(inscription_id2)
+      .unwrap();
+
+    assert_eq!(
+      brc20db.get_all_inscribe_transfer_inscriptions().unwrap(),
+      vec![(inscription_id1, transfer_info(10))]
+    );
+  }
 }
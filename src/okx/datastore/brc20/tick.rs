@@ -3,9 +3,11 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::{fmt::Formatter, str::FromStr};
 
 pub const TICK_BYTE_COUNT: usize = 4;
+/// The length of the ticks that can only be deployed with `self_mint`.
+pub const SELF_MINT_TICK_BYTE_COUNT: usize = 5;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Tick([u8; TICK_BYTE_COUNT]);
+pub struct Tick(Box<[u8]>);
 
 impl FromStr for Tick {
   type Err = BRC20Error;
@@ -13,19 +15,24 @@ impl FromStr for Tick {
   fn from_str(s: &str) -> Result<Self, Self::Err> {
     let bytes = s.as_bytes();
 
-    if bytes.len() != TICK_BYTE_COUNT {
+    if bytes.len() != TICK_BYTE_COUNT && bytes.len() != SELF_MINT_TICK_BYTE_COUNT {
       return Err(BRC20Error::InvalidTickLen(s.to_string()));
     }
 
-    Ok(Self(bytes.try_into().unwrap()))
+    Ok(Self(bytes.into()))
   }
 }
 
 impl Tick {
+  /// Whether the tick has the length of the ticks deployed with `self_mint`.
+  pub fn is_self_mint_len(&self) -> bool {
+    self.0.len() == SELF_MINT_TICK_BYTE_COUNT
+  }
+
   pub fn as_str(&self) -> &str {
     // NOTE: Tick comes from &str by from_str,
     // so it could be calling unwrap when convert to str
-    std::str::from_utf8(self.0.as_slice()).unwrap()
+    std::str::from_utf8(&self.0).unwrap()
   }
 
   pub fn to_lowercase(&self) -> LowerTick {
@@ -106,12 +113,24 @@ mod tests {
     assert!(Tick::from_str("aBc1").is_ok());
     assert!("aBc1".parse::<Tick>().is_ok());
     assert!("ατ".parse::<Tick>().is_ok());
-    assert!("∑ii".parse::<Tick>().is_err());
+    assert!("∑ii".parse::<Tick>().is_ok());
+    assert!("∑iii".parse::<Tick>().is_err());
     assert!("∑i".parse::<Tick>().is_ok());
     assert!("⊢i".parse::<Tick>().is_ok());
-    assert!("⊢ii".parse::<Tick>().is_err());
+    assert!("⊢ii".parse::<Tick>().is_ok());
+    assert!("⊢iii".parse::<Tick>().is_err());
     assert!("≯a".parse::<Tick>().is_ok());
-    assert!("a≯a".parse::<Tick>().is_err());
+    assert!("a≯a".parse::<Tick>().is_ok());
+    assert!("a≯ab".parse::<Tick>().is_err());
+    assert!("abcde".parse::<Tick>().is_ok());
+    assert!("abcdef".parse::<Tick>().is_err());
+  }
+
+  #[test]
+  fn test_self_mint_tick_len() {
+    assert!(!"abcd".parse::<Tick>().unwrap().is_self_mint_len());
+    assert!("abcde".parse::<Tick>().unwrap().is_self_mint_len());
+    assert!("ab≯".parse::<Tick>().unwrap().is_self_mint_len());
   }
   #[test]
   fn test_tick_hex() {
@@ -123,6 +142,10 @@ mod tests {
       Tick::from_str("aBc1").unwrap().to_lowercase().hex(),
       "61626331000000000000000000000000"
     );
+    assert_eq!(
+      Tick::from_str("aBc1E").unwrap().to_lowercase().hex(),
+      "61626331650000000000000000000000"
+    );
   }
 
   #[test]
@@ -14,4 +14,8 @@ pub struct TokenInfo {
   pub deployed_number: u64,
   pub deployed_timestamp: u32,
   pub latest_mint_number: u64,
+  /// Whether only the children of the deploy inscription can mint the tick.
+  pub self_mint: bool,
+  /// The amount burned by transfers sent to OP_RETURN outputs.
+  pub burned: u128,
 }
@@ -34,6 +34,10 @@ pub trait DataStoreReadOnly {
     limit: Option<usize>,
   ) -> Result<(Vec<TickInfo>, usize), Self::Error>;
 
+  // BRC20S_TICK_SEARCH
+  fn search_ticks(&self, keyword: &str, limit: usize) -> Result<Vec<(Tick, TickId)>, Self::Error>;
+  fn get_tick_ids_by_name(&self, name: &str) -> Result<Vec<TickId>, Self::Error>;
+
   // BRC20S_PID_TO_POOLINFO
   fn get_pid_to_poolinfo(&self, pid: &Pid) -> Result<Option<PoolInfo>, Self::Error>;
 
@@ -45,6 +49,15 @@ pub trait DataStoreReadOnly {
     limit: Option<usize>,
   ) -> Result<(Vec<PoolInfo>, usize), Self::Error>;
 
+  // BRC20S_POOL_SNAPSHOTS
+  fn get_pool_snapshots(
+    &self,
+    pid: &Pid,
+    from: u64,
+    to: u64,
+    limit: usize,
+  ) -> Result<Vec<PoolSnapshot>, Self::Error>;
+
   // BRC20S_USER_STAKEINFO
   fn get_user_stakeinfo(
     &self,
@@ -52,6 +65,8 @@ pub trait DataStoreReadOnly {
     pledged_tick: &PledgedTick,
   ) -> Result<Option<StakeInfo>, Self::Error>;
 
+  fn get_all_user_stakeinfo(&self) -> Result<Vec<(ScriptKey, StakeInfo)>, Self::Error>;
+
   // BRC20S_PID_TO_USERINFO
   fn get_pid_to_use_info(
     &self,
@@ -59,6 +74,11 @@ pub trait DataStoreReadOnly {
     pid: &Pid,
   ) -> Result<Option<UserInfo>, Self::Error>;
 
+  fn get_all_use_info(&self) -> Result<Vec<(ScriptKey, UserInfo)>, Self::Error>;
+
+  // BRC20S_PID_TO_USERS
+  fn get_pid_to_users(&self, pid: &Pid) -> Result<Vec<(ScriptKey, UserInfo)>, Self::Error>;
+
   // BRC20S_STAKE_TICKID_TO_PID
   fn get_tickid_stake_to_pid(
     &self,
@@ -127,6 +147,9 @@ pub trait DataStoreReadWrite: DataStoreReadOnly {
   // BRC20S_PID_TO_POOLINFO
   fn set_pid_to_poolinfo(&self, pid: &Pid, pool_info: &PoolInfo) -> Result<(), Self::Error>;
 
+  // BRC20S_POOL_SNAPSHOTS
+  fn set_pool_snapshot(&self, pid: &Pid, snapshot: &PoolSnapshot) -> Result<(), Self::Error>;
+
   // BRC20S_USER_STAKEINFO
   fn set_user_stakeinfo(
     &self,
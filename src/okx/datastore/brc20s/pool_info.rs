@@ -148,6 +148,28 @@ impl std::fmt::Display for PoolInfo {
   }
 }
 
+/// The state of a pool at the end of a block in which it changed.
+#[derive(Debug, PartialEq, Deserialize, Serialize, Clone)]
+pub struct PoolSnapshot {
+  pub block_num: u64,
+  pub staked: u128,
+  pub minted: u128,
+  pub acc_reward_per_share: String,
+  pub last_update_block: u64,
+}
+
+impl PoolSnapshot {
+  pub fn new(pool_info: &PoolInfo, block_num: u64) -> Self {
+    Self {
+      block_num,
+      staked: pool_info.staked,
+      minted: pool_info.minted,
+      acc_reward_per_share: pool_info.acc_reward_per_share.clone(),
+      last_update_block: pool_info.last_update_block,
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -3,7 +3,7 @@ mod read_write;
 
 pub use self::{read_only::try_init_tables, read_only::DataStoreReader, read_write::DataStore};
 
-use crate::okx::datastore::brc20s::{Pid, PledgedTick, TickId};
+use crate::okx::datastore::brc20s::{Pid, PledgedTick, Tick, TickId};
 use crate::okx::datastore::ScriptKey;
 use crate::InscriptionId;
 use bitcoin::Txid;
@@ -29,6 +29,11 @@ const BRC20S_TXID_TO_RECEIPTS: TableDefinition<&str, &[u8]> =
   TableDefinition::new("BRC20S_TXID_TO_RECEIPTS");
 const BRC20S_INSCRIBE_TRANSFER: TableDefinition<&[u8; 36], &[u8]> =
   TableDefinition::new("BRC20S_INSCRIBE_TRANSFER");
+const BRC20S_TICK_SEARCH: TableDefinition<&str, &[u8]> = TableDefinition::new("BRC20S_TICK_SEARCH");
+const BRC20S_PID_TO_USERS: TableDefinition<&str, &[u8]> =
+  TableDefinition::new("BRC20S_PID_TO_USERS");
+const BRC20S_POOL_SNAPSHOTS: TableDefinition<&str, &[u8]> =
+  TableDefinition::new("BRC20S_POOL_SNAPSHOTS");
 
 fn script_tickid_key(script: &ScriptKey, tick_id: &TickId) -> String {
   format!("{}_{}", script, tick_id.hex())
@@ -42,10 +47,32 @@ fn script_tickid_inscriptionid_key(
   format!("{}_{}_{}", script, tick_id.hex(), inscriptionid)
 }
 
+fn tick_search_key(name: &Tick, tick_id: &TickId) -> String {
+  format!("{}_{}", name.as_str().to_lowercase(), tick_id.hex())
+}
+
 fn script_pid_key(script: &ScriptKey, pid: &Pid) -> String {
   format!("{}_{}", script, pid.hex(),)
 }
 
+fn pid_script_key(pid: &Pid, script: &ScriptKey) -> String {
+  format!("{}_{}", pid.hex(), script)
+}
+
+fn min_pid_script_key(pid: &Pid) -> String {
+  format!("{}_", pid.hex())
+}
+
+// '`' is the character following '_', so every script of the pid sorts below it.
+fn max_pid_script_key(pid: &Pid) -> String {
+  format!("{}`", pid.hex())
+}
+
+// the block is zero padded so that the snapshots of a pool sort by height.
+fn pid_block_key(pid: &Pid, block_num: u64) -> String {
+  format!("{}_{:016x}", pid.hex(), block_num)
+}
+
 fn script_pledged_key(script: &ScriptKey, pledged_tick: &PledgedTick) -> String {
   let pledged_key = match pledged_tick {
     PledgedTick::Native => hex::encode("btc"),
@@ -1,13 +1,13 @@
 use super::*;
 use crate::okx::datastore::brc20s::{
-  Balance, DataStoreReadOnly, InscriptionOperation, Pid, PledgedTick, PoolInfo, Receipt, StakeInfo,
-  TickId, TickInfo, TransferInfo, TransferableAsset, UserInfo,
+  Balance, DataStoreReadOnly, InscriptionOperation, Pid, PledgedTick, PoolInfo, PoolSnapshot,
+  Receipt, StakeInfo, Tick, TickId, TickInfo, TransferInfo, TransferableAsset, UserInfo,
 };
 use redb::{
   AccessGuard, Range, ReadOnlyTable, ReadTransaction, ReadableTable, RedbKey, RedbValue,
   StorageError, Table, TableDefinition, WriteTransaction,
 };
-use std::{borrow::Borrow, ops::RangeBounds};
+use std::{borrow::Borrow, ops::RangeBounds, str::FromStr};
 
 pub fn try_init_tables<'db, 'a>(
   wtx: &'a WriteTransaction<'db>,
@@ -24,6 +24,21 @@ pub fn try_init_tables<'db, 'a>(
     wtx.open_table(BRC20S_TRANSFERABLE_ASSETS)?;
     wtx.open_table(BRC20S_TXID_TO_RECEIPTS)?;
     wtx.open_table(BRC20S_INSCRIBE_TRANSFER)?;
+    wtx.open_table(BRC20S_PID_TO_USERS)?;
+    wtx.open_table(BRC20S_POOL_SNAPSHOTS)?;
+  }
+
+  // build the search index for ticks deployed before it was introduced.
+  if rtx.open_table(BRC20S_TICK_SEARCH).is_err() {
+    let mut tick_search = wtx.open_table(BRC20S_TICK_SEARCH)?;
+    for result in wtx.open_table(BRC20S_TICKINFO)?.iter()? {
+      let (_, data) = result?;
+      let tick_info = bincode::deserialize::<TickInfo>(data.value()).unwrap();
+      tick_search.insert(
+        tick_search_key(&tick_info.name, &tick_info.tick_id).as_str(),
+        bincode::serialize(&tick_info.name).unwrap().as_slice(),
+      )?;
+    }
   }
 
   Ok(true)
@@ -158,6 +173,53 @@ impl<'db, 'a> DataStoreReadOnly for DataStoreReader<'db, 'a> {
     Ok((tickinfos, usize::try_from(total).unwrap()))
   }
 
+  // BRC20S_TICK_SEARCH
+  fn search_ticks(&self, keyword: &str, limit: usize) -> Result<Vec<(Tick, TickId)>, Self::Error> {
+    let keyword = keyword.to_lowercase();
+    let mut ticks = Vec::new();
+    for result in self
+      .wrapper
+      .open_table(BRC20S_TICK_SEARCH)?
+      .range::<&str>(keyword.as_str()..)?
+    {
+      let (key, data) = result?;
+      if ticks.len() == limit || !key.value().starts_with(&keyword) {
+        break;
+      }
+      // the key is composed of the lowercase name and the tick id.
+      let (lower_name, tick_id) = key.value().rsplit_once('_').unwrap();
+      if lower_name.starts_with(&keyword) {
+        ticks.push((
+          bincode::deserialize::<Tick>(data.value()).unwrap(),
+          TickId::from_str(tick_id).unwrap(),
+        ));
+      }
+    }
+    Ok(ticks)
+  }
+
+  // BRC20S_TICK_SEARCH
+  fn get_tick_ids_by_name(&self, name: &str) -> Result<Vec<TickId>, Self::Error> {
+    let name = name.to_lowercase();
+    let prefix = format!("{name}_");
+    let mut tick_ids = Vec::new();
+    for result in self
+      .wrapper
+      .open_table(BRC20S_TICK_SEARCH)?
+      .range::<&str>(prefix.as_str()..)?
+    {
+      let (key, _) = result?;
+      if !key.value().starts_with(&prefix) {
+        break;
+      }
+      let (lower_name, tick_id) = key.value().rsplit_once('_').unwrap();
+      if lower_name == name {
+        tick_ids.push(TickId::from_str(tick_id).unwrap());
+      }
+    }
+    Ok(tick_ids)
+  }
+
   // BRC20S_PID_TO_POOLINFO
   fn get_pid_to_poolinfo(&self, pid: &Pid) -> Result<Option<PoolInfo>, Self::Error> {
     Ok(
@@ -206,6 +268,27 @@ impl<'db, 'a> DataStoreReadOnly for DataStoreReader<'db, 'a> {
     Ok((pools, usize::try_from(total).unwrap()))
   }
 
+  // BRC20S_POOL_SNAPSHOTS
+  fn get_pool_snapshots(
+    &self,
+    pid: &Pid,
+    from: u64,
+    to: u64,
+    limit: usize,
+  ) -> Result<Vec<PoolSnapshot>, Self::Error> {
+    Ok(
+      self
+        .wrapper
+        .open_table(BRC20S_POOL_SNAPSHOTS)?
+        .range(pid_block_key(pid, from).as_str()..=pid_block_key(pid, to).as_str())?
+        .take(limit)
+        .flat_map(|result| {
+          result.map(|(_, data)| bincode::deserialize::<PoolSnapshot>(data.value()).unwrap())
+        })
+        .collect(),
+    )
+  }
+
   // BRC20S_USER_STAKEINFO
   fn get_user_stakeinfo(
     &self,
@@ -221,6 +304,26 @@ impl<'db, 'a> DataStoreReadOnly for DataStoreReader<'db, 'a> {
     )
   }
 
+  fn get_all_user_stakeinfo(&self) -> Result<Vec<(ScriptKey, StakeInfo)>, Self::Error> {
+    Ok(
+      self
+        .wrapper
+        .open_table(BRC20S_USER_STAKEINFO)?
+        .range::<&str>(..)?
+        .flat_map(|result| {
+          result.map(|(key, data)| {
+            // the key is composed of the script and the pledged tick.
+            let (script, _) = key.value().split_once('_').unwrap();
+            (
+              ScriptKey::from_str(script).unwrap(),
+              bincode::deserialize::<StakeInfo>(data.value()).unwrap(),
+            )
+          })
+        })
+        .collect(),
+    )
+  }
+
   // BRC20S_PID_TO_USERINFO
   fn get_pid_to_use_info(
     &self,
@@ -236,6 +339,47 @@ impl<'db, 'a> DataStoreReadOnly for DataStoreReader<'db, 'a> {
     )
   }
 
+  fn get_all_use_info(&self) -> Result<Vec<(ScriptKey, UserInfo)>, Self::Error> {
+    Ok(
+      self
+        .wrapper
+        .open_table(BRC20S_PID_TO_USERINFO)?
+        .range::<&str>(..)?
+        .flat_map(|result| {
+          result.map(|(key, data)| {
+            // the key is composed of the script and the pid.
+            let (script, _) = key.value().split_once('_').unwrap();
+            (
+              ScriptKey::from_str(script).unwrap(),
+              bincode::deserialize::<UserInfo>(data.value()).unwrap(),
+            )
+          })
+        })
+        .collect(),
+    )
+  }
+
+  // BRC20S_PID_TO_USERS
+  fn get_pid_to_users(&self, pid: &Pid) -> Result<Vec<(ScriptKey, UserInfo)>, Self::Error> {
+    let user_info_table = self.wrapper.open_table(BRC20S_PID_TO_USERINFO)?;
+    let mut users = Vec::new();
+    for result in self
+      .wrapper
+      .open_table(BRC20S_PID_TO_USERS)?
+      .range(min_pid_script_key(pid).as_str()..max_pid_script_key(pid).as_str())?
+    {
+      let (_, data) = result?;
+      let script_key = bincode::deserialize::<ScriptKey>(data.value()).unwrap();
+      if let Some(user_info) = user_info_table.get(script_pid_key(&script_key, pid).as_str())? {
+        users.push((
+          script_key,
+          bincode::deserialize::<UserInfo>(user_info.value()).unwrap(),
+        ));
+      }
+    }
+    Ok(users)
+  }
+
   // BRC20S_STAKE_TICKID_TO_PID
   fn get_tickid_stake_to_pid(
     &self,
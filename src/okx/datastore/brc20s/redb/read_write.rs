@@ -1,8 +1,9 @@
 use super::*;
 use crate::{
   okx::datastore::brc20s::{
-    Balance, DataStoreReadOnly, DataStoreReadWrite, InscriptionOperation, Pid, PoolInfo, Receipt,
-    StakeInfo, TickId, TickInfo, TransferInfo, TransferableAsset, UserInfo,
+    Balance, DataStoreReadOnly, DataStoreReadWrite, InscriptionOperation, Pid, PoolInfo,
+    PoolSnapshot, Receipt, StakeInfo, Tick, TickId, TickInfo, TransferInfo, TransferableAsset,
+    UserInfo,
   },
   InscriptionId,
 };
@@ -43,6 +44,16 @@ impl<'db, 'a> DataStoreReadOnly for DataStore<'db, 'a> {
     read_only::new_with_wtx(self.wtx).get_all_tick_info(start, limit)
   }
 
+  // BRC20S_TICK_SEARCH
+  fn search_ticks(&self, keyword: &str, limit: usize) -> Result<Vec<(Tick, TickId)>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).search_ticks(keyword, limit)
+  }
+
+  // BRC20S_TICK_SEARCH
+  fn get_tick_ids_by_name(&self, name: &str) -> Result<Vec<TickId>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_tick_ids_by_name(name)
+  }
+
   // BRC20S_PID_TO_POOLINFO
   fn get_pid_to_poolinfo(&self, pid: &Pid) -> Result<Option<PoolInfo>, Self::Error> {
     read_only::new_with_wtx(self.wtx).get_pid_to_poolinfo(pid)
@@ -60,6 +71,17 @@ impl<'db, 'a> DataStoreReadOnly for DataStore<'db, 'a> {
     read_only::new_with_wtx(self.wtx).get_all_poolinfo(start, limit)
   }
 
+  // BRC20S_POOL_SNAPSHOTS
+  fn get_pool_snapshots(
+    &self,
+    pid: &Pid,
+    from: u64,
+    to: u64,
+    limit: usize,
+  ) -> Result<Vec<PoolSnapshot>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_pool_snapshots(pid, from, to, limit)
+  }
+
   // BRC20S_USER_STAKEINFO
   fn get_user_stakeinfo(
     &self,
@@ -69,6 +91,10 @@ impl<'db, 'a> DataStoreReadOnly for DataStore<'db, 'a> {
     read_only::new_with_wtx(self.wtx).get_user_stakeinfo(script_key, pledged_tick)
   }
 
+  fn get_all_user_stakeinfo(&self) -> Result<Vec<(ScriptKey, StakeInfo)>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_all_user_stakeinfo()
+  }
+
   // BRC20S_PID_TO_USERINFO
   fn get_pid_to_use_info(
     &self,
@@ -78,6 +104,14 @@ impl<'db, 'a> DataStoreReadOnly for DataStore<'db, 'a> {
     read_only::new_with_wtx(self.wtx).get_pid_to_use_info(script_key, pid)
   }
 
+  fn get_all_use_info(&self) -> Result<Vec<(ScriptKey, UserInfo)>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_all_use_info()
+  }
+
+  fn get_pid_to_users(&self, pid: &Pid) -> Result<Vec<(ScriptKey, UserInfo)>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_pid_to_users(pid)
+  }
+
   // BRC20S_STAKE_TICKID_TO_PID
   fn get_tickid_stake_to_pid(
     &self,
@@ -179,6 +213,10 @@ impl<'db, 'a> DataStoreReadWrite for DataStore<'db, 'a> {
       tick_id.hex().as_str(),
       bincode::serialize(tick_info).unwrap().as_slice(),
     )?;
+    self.wtx.open_table(BRC20S_TICK_SEARCH)?.insert(
+      tick_search_key(&tick_info.name, tick_id).as_str(),
+      bincode::serialize(&tick_info.name).unwrap().as_slice(),
+    )?;
     Ok(())
   }
 
@@ -191,6 +229,15 @@ impl<'db, 'a> DataStoreReadWrite for DataStore<'db, 'a> {
     Ok(())
   }
 
+  // BRC20S_POOL_SNAPSHOTS
+  fn set_pool_snapshot(&self, pid: &Pid, snapshot: &PoolSnapshot) -> Result<(), Self::Error> {
+    self.wtx.open_table(BRC20S_POOL_SNAPSHOTS)?.insert(
+      pid_block_key(pid, snapshot.block_num).as_str(),
+      bincode::serialize(snapshot).unwrap().as_slice(),
+    )?;
+    Ok(())
+  }
+
   // BRC20S_USER_STAKEINFO
   fn set_user_stakeinfo(
     &self,
@@ -216,6 +263,10 @@ impl<'db, 'a> DataStoreReadWrite for DataStore<'db, 'a> {
       script_pid_key(script_key, pid).as_str(),
       bincode::serialize(user_info).unwrap().as_slice(),
     )?;
+    self.wtx.open_table(BRC20S_PID_TO_USERS)?.insert(
+      pid_script_key(pid, script_key).as_str(),
+      bincode::serialize(script_key).unwrap().as_slice(),
+    )?;
     Ok(())
   }
 
@@ -826,6 +877,75 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_search_ticks() {
+    let dbfile = NamedTempFile::new().unwrap();
+    let db = Database::create(dbfile.path()).unwrap();
+    let wtx = db.begin_write().unwrap();
+    let brc20s_db = DataStore::new(&wtx);
+
+    let tick_info = TickInfo {
+      tick_id: TickId::from_str("f7c515d6b1").unwrap(),
+      name: Tick::from_str("words").unwrap(),
+      inscription_id: InscriptionId::from_str(
+        "2111111111111111111111111111111111111111111111111111111111111111i1",
+      )
+      .unwrap(),
+      allocated: 100,
+      decimal: 1,
+      circulation: 100,
+      supply: 100,
+      deployer: ScriptKey::from_address(
+        Address::from_str("33iFwdLuRpW1uK1RTRqsoi8rR4NpDzk66k")
+          .unwrap()
+          .assume_checked(),
+      ),
+      deploy_block: 100,
+      deploy_block_time: 100000,
+      latest_mint_block: 100,
+      pids: vec![],
+    };
+
+    for (tick_id, name) in [
+      ("f7c515d6b1", "words"),
+      ("f7c515d6b2", "ORDS"),
+      ("f7c515d6b3", "ords"),
+      ("f7c515d6b4", "abcd"),
+    ] {
+      let mut tick_info = tick_info.clone();
+      tick_info.tick_id = TickId::from_str(tick_id).unwrap();
+      tick_info.name = Tick::from_str(name).unwrap();
+      brc20s_db
+        .set_tick_info(&tick_info.tick_id, &tick_info)
+        .unwrap();
+    }
+
+    assert_eq!(
+      brc20s_db.search_ticks("Ord", 10).unwrap(),
+      vec![
+        (
+          Tick::from_str("ORDS").unwrap(),
+          TickId::from_str("f7c515d6b2").unwrap()
+        ),
+        (
+          Tick::from_str("ords").unwrap(),
+          TickId::from_str("f7c515d6b3").unwrap()
+        ),
+      ]
+    );
+    assert_eq!(brc20s_db.search_ticks("ord", 1).unwrap().len(), 1);
+    assert!(brc20s_db.search_ticks("xyz", 10).unwrap().is_empty());
+
+    assert_eq!(
+      brc20s_db.get_tick_ids_by_name("Ords").unwrap(),
+      vec![
+        TickId::from_str("f7c515d6b2").unwrap(),
+        TickId::from_str("f7c515d6b3").unwrap()
+      ]
+    );
+    assert!(brc20s_db.get_tick_ids_by_name("ord").unwrap().is_empty());
+  }
+
   #[test]
   fn test_pid_to_use_info() {
     let dbfile = NamedTempFile::new().unwrap();
@@ -861,6 +981,118 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_pool_snapshots() {
+    let dbfile = NamedTempFile::new().unwrap();
+    let db = Database::create(dbfile.path()).unwrap();
+    let wtx = db.begin_write().unwrap();
+    let brc20s_db = DataStore::new(&wtx);
+
+    let pid = Pid::from_str("1234567890#01").unwrap();
+    let other_pid = Pid::from_str("1234567890#02").unwrap();
+    let snapshot = |block_num: u64, staked: u128| PoolSnapshot {
+      block_num,
+      staked,
+      minted: 0,
+      acc_reward_per_share: "0".to_string(),
+      last_update_block: block_num,
+    };
+
+    // 0x100 sorts after 0xff only because the height is zero padded.
+    for (block_num, staked) in [(0xff, 1), (0x100, 2), (0x100, 3), (0x2000, 4)] {
+      brc20s_db
+        .set_pool_snapshot(&pid, &snapshot(block_num, staked))
+        .unwrap();
+    }
+    brc20s_db
+      .set_pool_snapshot(&other_pid, &snapshot(0x100, 5))
+      .unwrap();
+
+    assert_eq!(
+      brc20s_db
+        .get_pool_snapshots(&pid, 0, u64::MAX, usize::MAX)
+        .unwrap(),
+      vec![snapshot(0xff, 1), snapshot(0x100, 3), snapshot(0x2000, 4)]
+    );
+    assert_eq!(
+      brc20s_db
+        .get_pool_snapshots(&pid, 0x100, 0x2000, usize::MAX)
+        .unwrap(),
+      vec![snapshot(0x100, 3), snapshot(0x2000, 4)]
+    );
+    assert_eq!(
+      brc20s_db.get_pool_snapshots(&pid, 0, u64::MAX, 1).unwrap(),
+      vec![snapshot(0xff, 1)]
+    );
+    assert_eq!(
+      brc20s_db
+        .get_pool_snapshots(&other_pid, 0, u64::MAX, usize::MAX)
+        .unwrap(),
+      vec![snapshot(0x100, 5)]
+    );
+  }
+
+  #[test]
+  fn test_pid_to_users() {
+    let dbfile = NamedTempFile::new().unwrap();
+    let db = Database::create(dbfile.path()).unwrap();
+    let wtx = db.begin_write().unwrap();
+    let brc20s_db = DataStore::new(&wtx);
+
+    let pid = Pid::from_str("1234567890#01").unwrap();
+    let other_pid = Pid::from_str("1234567890#02").unwrap();
+    let script_key1 = ScriptKey::from_address(
+      Address::from_str("33iFwdLuRpW1uK1RTRqsoi8rR4NpDzk66k")
+        .unwrap()
+        .assume_checked(),
+    );
+    let script_key2 = ScriptKey::from_address(
+      Address::from_str("bc1qhvd6suvqzjcu9pxjhrwhtrlj85ny3n2mqql5w4")
+        .unwrap()
+        .assume_checked(),
+    );
+    let user_info = |pid: &Pid, staked: u128| UserInfo {
+      pid: pid.clone(),
+      staked,
+      minted: 0,
+      pending_reward: 0,
+      reward_debt: 0,
+      latest_updated_block: 0,
+    };
+
+    brc20s_db
+      .set_pid_to_use_info(&script_key1, &pid, &user_info(&pid, 10))
+      .unwrap();
+    brc20s_db
+      .set_pid_to_use_info(&script_key2, &pid, &user_info(&pid, 20))
+      .unwrap();
+    brc20s_db
+      .set_pid_to_use_info(&script_key1, &other_pid, &user_info(&other_pid, 30))
+      .unwrap();
+    // updating a user must not duplicate it.
+    brc20s_db
+      .set_pid_to_use_info(&script_key1, &pid, &user_info(&pid, 40))
+      .unwrap();
+
+    let mut users = brc20s_db.get_pid_to_users(&pid).unwrap();
+    users.sort_by_key(|(_, user_info)| user_info.staked);
+    assert_eq!(
+      users,
+      vec![
+        (script_key2.clone(), user_info(&pid, 20)),
+        (script_key1.clone(), user_info(&pid, 40)),
+      ]
+    );
+    assert_eq!(
+      brc20s_db.get_pid_to_users(&other_pid).unwrap(),
+      vec![(script_key1, user_info(&other_pid, 30))]
+    );
+    assert!(brc20s_db
+      .get_pid_to_users(&Pid::from_str("1234567890#03").unwrap())
+      .unwrap()
+      .is_empty());
+  }
+
   #[test]
   fn test_transferable_assets() {
     let dbfile = NamedTempFile::new().unwrap();
@@ -179,6 +179,7 @@ impl PledgedTick {
     matches!(self, PledgedTick::BRC20Tick(_))
   }
 
+  #[allow(clippy::should_implement_trait)]
   pub fn from_str(str: &str) -> Self {
     match str {
       NATIVE_TOKEN => PledgedTick::Native,
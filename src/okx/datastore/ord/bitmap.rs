@@ -27,9 +27,43 @@ impl District {
   }
 }
 
+/// A parcel `index.N.bitmap` of district `N`, one for each transaction of block `N`.
+pub struct Parcel {
+  pub index: u64,
+  pub district: u64,
+}
+
+impl Parcel {
+  pub fn parse(bytes: &[u8]) -> Result<Self> {
+    let pattern = r"^(0|[1-9][0-9]*)\.(0|[1-9][0-9]*)\.bitmap$";
+    // pattern must be validated
+    let content = std::str::from_utf8(bytes)?;
+    let re = Regex::new(pattern).unwrap();
+    if let Some(capture) = re.captures(content) {
+      if let (Some(index), Some(district)) = (capture.get(1), capture.get(2)) {
+        return Ok(Self {
+          index: index.as_str().parse()?,
+          district: district.as_str().parse()?,
+        });
+      }
+    }
+    Err(anyhow!("No match found."))
+  }
+
+  pub fn to_collection_key(&self) -> String {
+    format!("{}_{}_{}", BITMAP_KEY, self.district, self.index)
+  }
+}
+
+impl std::fmt::Display for Parcel {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}.{}.bitmap", self.index, self.district)
+  }
+}
+
 #[cfg(test)]
 mod tests {
-  use super::District;
+  use super::{District, Parcel};
 
   #[test]
   fn validate_regex() {
@@ -48,5 +82,29 @@ mod tests {
     assert!(District::parse("111".as_bytes()).is_err());
     assert!(District::parse("01.bitmap".as_bytes()).is_err());
     assert!(District::parse((u64::MAX.to_string() + "1.bitmap").as_bytes()).is_err());
+    assert!(District::parse("0.40.bitmap".as_bytes()).is_err());
+  }
+
+  #[test]
+  fn validate_parcel_regex() {
+    let parcel = Parcel::parse("0.40.bitmap".as_bytes()).unwrap();
+    assert_eq!(parcel.index, 0);
+    assert_eq!(parcel.district, 40);
+    assert_eq!(parcel.to_collection_key(), "BITMAP_40_0");
+    assert_eq!(parcel.to_string(), "0.40.bitmap");
+
+    let parcel = Parcel::parse("12.840000.bitmap".as_bytes()).unwrap();
+    assert_eq!(parcel.index, 12);
+    assert_eq!(parcel.district, 840000);
+  }
+
+  #[test]
+  fn invalidate_parcel_regex() {
+    assert!(Parcel::parse("40.bitmap".as_bytes()).is_err());
+    assert!(Parcel::parse(".40.bitmap".as_bytes()).is_err());
+    assert!(Parcel::parse("01.40.bitmap".as_bytes()).is_err());
+    assert!(Parcel::parse("1.040.bitmap".as_bytes()).is_err());
+    assert!(Parcel::parse("1.2.3.bitmap".as_bytes()).is_err());
+    assert!(Parcel::parse((u64::MAX.to_string() + "1.40.bitmap").as_bytes()).is_err());
   }
 }
@@ -4,11 +4,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum CollectionKind {
   BitMap,
+  Name,
+  BitMapParcel,
+  Parent,
 }
 impl ToString for CollectionKind {
   fn to_string(&self) -> String {
     match self {
       CollectionKind::BitMap => String::from("bitmap"),
+      CollectionKind::Name => String::from("name"),
+      CollectionKind::BitMapParcel => String::from("bitmap_parcel"),
+      CollectionKind::Parent => String::from("parent"),
     }
   }
 }
@@ -1,9 +1,10 @@
 pub use self::{
-  operation::{Action, InscriptionOp},
-  redb::{OrdDbReadWriter, OrdDbReader},
+  operation::{Action, InscriptionOp, InscriptionTransfer},
+  redb::{OrdDbReadWriter, OrdDbReader, ORD_SCRIPT_KEY_TO_INSCRIPTION_NUMBERS},
 };
 
 use {
+  super::ScriptKey,
   crate::{InscriptionId, Result},
   bitcoin::{OutPoint, TxOut, Txid},
   collections::CollectionKind,
@@ -11,6 +12,7 @@ use {
 };
 pub mod bitmap;
 pub mod collections;
+pub mod names;
 pub mod operation;
 pub mod redb;
 
@@ -25,6 +27,18 @@ pub trait DataStoreReadOnly {
 
   fn get_transaction_operations(&self, txid: &Txid) -> Result<Vec<InscriptionOp>, Self::Error>;
 
+  fn get_inscription_history(
+    &self,
+    inscription_id: InscriptionId,
+    start: usize,
+    limit: usize,
+  ) -> Result<Vec<InscriptionTransfer>, Self::Error>;
+
+  fn get_latest_inscription_transfer(
+    &self,
+    inscription_id: InscriptionId,
+  ) -> Result<Option<InscriptionTransfer>, Self::Error>;
+
   fn get_collections_of_inscription(
     &self,
     inscription_id: InscriptionId,
@@ -34,6 +48,24 @@ pub trait DataStoreReadOnly {
     &self,
     collection_key: &str,
   ) -> Result<Option<InscriptionId>, Self::Error>;
+
+  fn get_inscription_numbers_by_script_key(
+    &self,
+    script_key: &ScriptKey,
+  ) -> Result<Vec<i64>, Self::Error>;
+
+  fn get_transaction_inputs(&self, txid: &Txid) -> Result<Option<Vec<OutPoint>>, Self::Error>;
+
+  fn get_block_tx_count(&self, height: u64) -> Result<Option<u64>, Self::Error>;
+
+  fn get_children_count(&self, parent: InscriptionId) -> Result<u64, Self::Error>;
+
+  fn get_children(
+    &self,
+    parent: InscriptionId,
+    start: u64,
+    limit: u64,
+  ) -> Result<Vec<InscriptionId>, Self::Error>;
 }
 
 pub trait DataStoreReadWrite: DataStoreReadOnly {
@@ -45,6 +77,12 @@ pub trait DataStoreReadWrite: DataStoreReadOnly {
     operations: &[InscriptionOp],
   ) -> Result<(), Self::Error>;
 
+  fn append_inscription_history(
+    &self,
+    inscription_id: InscriptionId,
+    transfer: &InscriptionTransfer,
+  ) -> Result<(), Self::Error>;
+
   fn set_inscription_by_collection_key(
     &self,
     key: &str,
@@ -56,4 +94,20 @@ pub trait DataStoreReadWrite: DataStoreReadOnly {
     inscription_id: InscriptionId,
     kind: &[CollectionKind],
   ) -> Result<(), Self::Error>;
+
+  fn save_block_transaction_inputs(
+    &self,
+    height: u64,
+    transactions: &[(Txid, Vec<OutPoint>)],
+  ) -> Result<(), Self::Error>;
+
+  fn prune_transaction_inputs(&self, below_height: u64) -> Result<(), Self::Error>;
+
+  fn set_block_tx_count(&self, height: u64, tx_count: u64) -> Result<(), Self::Error>;
+
+  fn add_inscription_child(
+    &self,
+    parent: InscriptionId,
+    child: InscriptionId,
+  ) -> Result<(), Self::Error>;
 }
@@ -0,0 +1,251 @@
+use {super::*, anyhow::anyhow, std::str::FromStr};
+
+const NAME_KEY: &str = r"NAME";
+
+/// The characters allowed in the names of a namespace.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Charset {
+  /// Any character except whitespace, control characters and dots.
+  Any,
+  /// Lowercase ASCII letters, digits and hyphens, without leading or trailing hyphens.
+  Hostname,
+}
+
+impl FromStr for Charset {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    match s {
+      "any" => Ok(Self::Any),
+      "hostname" => Ok(Self::Hostname),
+      _ => Err(anyhow!(
+        "invalid charset `{s}`, expected `any` or `hostname`"
+      )),
+    }
+  }
+}
+
+impl std::fmt::Display for Charset {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      Self::Any => write!(f, "any"),
+      Self::Hostname => write!(f, "hostname"),
+    }
+  }
+}
+
+/// A namespace of first-is-first names, like the `sats` of `satoshi.sats`, written
+/// `<NAME>:<CHARSET>:<MAX_LENGTH>` like `sats:any:64`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Namespace {
+  pub name: String,
+  pub charset: Charset,
+  pub max_length: usize,
+}
+
+impl FromStr for Namespace {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    let mut parts = s.split(':');
+    let (Some(name), Some(charset), Some(max_length), None) =
+      (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+      return Err(anyhow!(
+        "invalid namespace `{s}`, expected `<NAME>:<CHARSET>:<MAX_LENGTH>`"
+      ));
+    };
+
+    let name = name.to_lowercase();
+    if name.is_empty()
+      || !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    {
+      return Err(anyhow!("invalid namespace name `{name}`"));
+    }
+    if name == "bitmap" {
+      return Err(anyhow!("`bitmap` is indexed with --enable-index-bitmap"));
+    }
+
+    let max_length = max_length
+      .parse::<usize>()
+      .ok()
+      .filter(|max_length| *max_length > 0)
+      .ok_or_else(|| anyhow!("invalid maximum name length `{max_length}`"))?;
+
+    Ok(Self {
+      name,
+      charset: charset.parse()?,
+      max_length,
+    })
+  }
+}
+
+impl std::fmt::Display for Namespace {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}:{}:{}", self.name, self.charset, self.max_length)
+  }
+}
+
+impl Namespace {
+  fn is_valid_label(&self, label: &str) -> bool {
+    if label.is_empty() || label.chars().count() > self.max_length {
+      return false;
+    }
+
+    match self.charset {
+      Charset::Any => !label
+        .chars()
+        .any(|c| c.is_whitespace() || c.is_control() || c == '.'),
+      Charset::Hostname => {
+        label
+          .chars()
+          .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+          && !label.starts_with('-')
+          && !label.ends_with('-')
+      }
+    }
+  }
+}
+
+/// A case folded name, registered by the first inscription whose content is the name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Name {
+  pub label: String,
+  pub namespace: String,
+}
+
+impl Name {
+  /// Parses the content of an inscription, either the name itself or a
+  /// `{"p":"sns","op":"reg","name":"..."}` registration, surrounded by optional whitespace.
+  pub fn parse(bytes: &[u8], namespaces: &[Namespace]) -> Result<Self> {
+    let content = std::str::from_utf8(bytes)?.trim();
+
+    let name = match serde_json::from_str::<serde_json::Value>(content) {
+      Ok(value) if value.is_object() => {
+        if value["p"] != "sns" || value["op"] != "reg" {
+          return Err(anyhow!("not a name registration"));
+        }
+        value["name"]
+          .as_str()
+          .ok_or_else(|| anyhow!("missing name"))?
+          .trim()
+          .to_lowercase()
+      }
+      _ => content.to_lowercase(),
+    };
+
+    let (label, namespace) = name
+      .rsplit_once('.')
+      .ok_or_else(|| anyhow!("missing namespace"))?;
+
+    let namespace = namespaces
+      .iter()
+      .find(|candidate| candidate.name == namespace)
+      .ok_or_else(|| anyhow!("unknown namespace `{namespace}`"))?;
+
+    if !namespace.is_valid_label(label) {
+      return Err(anyhow!("invalid name `{name}`"));
+    }
+
+    Ok(Self {
+      label: label.to_string(),
+      namespace: namespace.name.clone(),
+    })
+  }
+
+  pub fn to_collection_key(&self) -> String {
+    format!("{}_{}_{}", NAME_KEY, self.namespace, self.label)
+  }
+}
+
+impl std::fmt::Display for Name {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}.{}", self.label, self.namespace)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn namespaces() -> Vec<Namespace> {
+    ["sats:any:64", "unisat:any:64", "btc:hostname:63"]
+      .iter()
+      .map(|namespace| namespace.parse().unwrap())
+      .collect()
+  }
+
+  fn parse(content: &str) -> Result<Name> {
+    Name::parse(content.as_bytes(), &namespaces())
+  }
+
+  #[test]
+  fn parse_names() {
+    let name = parse("satoshi.sats").unwrap();
+    assert_eq!(name.label, "satoshi");
+    assert_eq!(name.namespace, "sats");
+    assert_eq!(name.to_string(), "satoshi.sats");
+    assert_eq!(name.to_collection_key(), "NAME_sats_satoshi");
+
+    assert_eq!(parse(" Satoshi.SATS\n").unwrap(), name);
+    assert_eq!(
+      parse(r#"{"p":"sns","op":"reg","name":"Satoshi.sats"}"#).unwrap(),
+      name
+    );
+    assert_eq!(parse("🐶.unisat").unwrap().label, "🐶");
+    assert_eq!(parse("my-node.btc").unwrap().label, "my-node");
+  }
+
+  #[test]
+  fn invalid_names() {
+    assert!(parse("satoshi").is_err());
+    assert!(parse(".sats").is_err());
+    assert!(parse("sat oshi.sats").is_err());
+    assert!(parse("a.b.sats").is_err());
+    assert!(parse("satoshi.bitmap").is_err());
+    assert!(parse("satoshi.eth").is_err());
+    assert!(parse(&format!("{}.sats", "a".repeat(65))).is_err());
+    assert!(parse("🐶.btc").is_err());
+    assert!(parse("-node.btc").is_err());
+    assert!(parse(r#"{"p":"sns","op":"transfer","name":"satoshi.sats"}"#).is_err());
+    assert!(Name::parse(&[0xff], &namespaces()).is_err());
+  }
+
+  #[test]
+  fn parse_namespaces() {
+    let namespace = "BTC:hostname:63".parse::<Namespace>().unwrap();
+    assert_eq!(
+      namespace,
+      Namespace {
+        name: "btc".into(),
+        charset: Charset::Hostname,
+        max_length: 63,
+      }
+    );
+    assert_eq!(namespace.to_string(), "btc:hostname:63");
+    assert_eq!(
+      "sats:any:10".parse::<Namespace>().unwrap(),
+      Namespace {
+        name: "sats".into(),
+        charset: Charset::Any,
+        max_length: 10,
+      }
+    );
+
+    for invalid in [
+      "",
+      "sats",
+      "sats:any",
+      "sats:any:64:1",
+      "sats.x:any:64",
+      "bitmap:any:64",
+      "sats:ascii:64",
+      "sats:any:0",
+      "sats:any:x",
+    ] {
+      assert!(invalid.parse::<Namespace>().is_err(), "{invalid}");
+    }
+  }
+}
@@ -1,5 +1,5 @@
 use {
-  crate::{Inscription, InscriptionId, SatPoint},
+  crate::{okx::datastore::ScriptKey, Inscription, InscriptionId, SatPoint},
   bitcoin::Txid,
   serde::{Deserialize, Serialize},
 };
@@ -25,3 +25,14 @@ pub enum Action {
   },
   Transfer,
 }
+
+// a location of an inscription, recorded at its genesis and on every transfer.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct InscriptionTransfer {
+  pub height: u64,
+  pub txid: Txid,
+  pub genesis: bool,
+  pub satpoint: SatPoint,
+  // `None` if the inscription is unbound or lost in the coinbase.
+  pub owner: Option<ScriptKey>,
+}
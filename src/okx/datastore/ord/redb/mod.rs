@@ -5,11 +5,38 @@ pub use self::{
   read_only::OrdDbReader,
   read_write::{try_init_tables, OrdDbReadWriter},
 };
-use {super::CollectionKind, redb::TableDefinition};
+use {
+  super::CollectionKind,
+  crate::InscriptionId,
+  redb::{MultimapTableDefinition, TableDefinition},
+};
 
 const ORD_TX_TO_OPERATIONS: TableDefinition<&str, &[u8]> =
   TableDefinition::new("ORD_TX_TO_OPERATIONS");
 const COLLECTIONS_KEY_TO_INSCRIPTION_ID: TableDefinition<&str, &[u8; 36]> =
   TableDefinition::new("COLLECTIONS_KEY_TO_INSCRIPTION_ID");
+// Locations of each inscription, one row per transfer keyed by `history_key`.
+const ORD_INSCRIPTION_ID_TO_HISTORY: TableDefinition<&str, &[u8]> =
+  TableDefinition::new("ORD_INSCRIPTION_ID_TO_HISTORY");
 const COLLECTIONS_INSCRIPTION_ID_TO_KINDS: TableDefinition<&[u8; 36], &[u8]> =
   TableDefinition::new("COLLECTIONS_INSCRIPTION_ID_TO_KINDS");
+// Verified children of each parent inscription, keyed by the parent and the order of the child.
+const COLLECTIONS_PARENT_TO_CHILDREN: TableDefinition<(&[u8; 36], u64), &[u8; 36]> =
+  TableDefinition::new("COLLECTIONS_PARENT_TO_CHILDREN");
+// Input outpoints of the transactions of recent blocks, and their txids by block height for pruning.
+const ORD_TXID_TO_INPUTS: TableDefinition<&[u8; 32], &[u8]> =
+  TableDefinition::new("ORD_TXID_TO_INPUTS");
+const ORD_HEIGHT_TO_CACHED_TXIDS: TableDefinition<u64, &[u8]> =
+  TableDefinition::new("ORD_HEIGHT_TO_CACHED_TXIDS");
+// Number of transactions of each block, for bitmap parcels.
+const ORD_HEIGHT_TO_TX_COUNT: TableDefinition<u64, u64> =
+  TableDefinition::new("ORD_HEIGHT_TO_TX_COUNT");
+// Inscription numbers currently held by each script key, maintained by the inscription updater.
+pub const ORD_SCRIPT_KEY_TO_INSCRIPTION_NUMBERS: MultimapTableDefinition<&str, i64> =
+  MultimapTableDefinition::new("ORD_SCRIPT_KEY_TO_INSCRIPTION_NUMBERS");
+
+// the height and the order of the transfer in the block are zero-padded to keep the rows of an
+// inscription in chronological order.
+fn history_key(inscription_id: InscriptionId, height: u64, index: u64) -> String {
+  format!("{inscription_id}_{height:020}_{index:020}")
+}
@@ -2,7 +2,10 @@ use {
   super::*,
   crate::{
     index::{INSCRIPTION_ID_TO_INSCRIPTION_ENTRY, OUTPOINT_TO_ENTRY},
-    okx::datastore::ord::{DataStoreReadOnly, InscriptionOp},
+    okx::datastore::{
+      ord::{DataStoreReadOnly, InscriptionOp, InscriptionTransfer},
+      ScriptKey,
+    },
     Hash, InscriptionId, Result,
   },
   bitcoin::{
@@ -10,10 +13,11 @@ use {
     OutPoint, TxOut, Txid,
   },
   redb::{
-    AccessGuard, ReadOnlyTable, ReadTransaction, ReadableTable, RedbKey, RedbValue, StorageError,
-    Table, TableDefinition, WriteTransaction,
+    AccessGuard, MultimapTable, MultimapTableDefinition, MultimapValue, Range,
+    ReadOnlyMultimapTable, ReadOnlyTable, ReadTransaction, ReadableMultimapTable, ReadableTable,
+    RedbKey, RedbValue, StorageError, Table, TableDefinition, WriteTransaction,
   },
-  std::{borrow::Borrow, io},
+  std::{borrow::Borrow, io, ops::RangeBounds},
 };
 
 pub struct OrdDbReader<'db, 'a> {
@@ -50,6 +54,20 @@ impl<'db, 'a> ReaderWrapper<'db, 'a> {
       Self::Wtx(wtx) => Ok(TableWrapper::WtxTable(wtx.open_table(definition)?)),
     }
   }
+
+  fn open_multimap_table<K: RedbKey + 'static, V: RedbKey + 'static>(
+    &self,
+    definition: MultimapTableDefinition<'_, K, V>,
+  ) -> Result<MultimapTableWrapper<'db, '_, K, V>, redb::Error> {
+    match self {
+      Self::Rtx(rtx) => Ok(MultimapTableWrapper::RtxTable(
+        rtx.open_multimap_table(definition)?,
+      )),
+      Self::Wtx(wtx) => Ok(MultimapTableWrapper::WtxTable(
+        wtx.open_multimap_table(definition)?,
+      )),
+    }
+  }
 }
 
 enum TableWrapper<'db, 'txn, K: RedbKey + 'static, V: RedbValue + 'static> {
@@ -70,6 +88,37 @@ impl<'db, 'txn, K: RedbKey + 'static, V: RedbValue + 'static> TableWrapper<'db,
       Self::WtxTable(wtx_table) => wtx_table.get(key),
     }
   }
+
+  fn range<'a: 'b, 'b, KR>(
+    &'a self,
+    range: impl RangeBounds<KR> + 'b,
+  ) -> Result<Range<'a, K, V>, StorageError>
+  where
+    K: 'a,
+    KR: Borrow<K::SelfType<'b>> + 'b,
+  {
+    match self {
+      Self::RtxTable(rtx_table) => rtx_table.range(range),
+      Self::WtxTable(wtx_table) => wtx_table.range(range),
+    }
+  }
+}
+
+enum MultimapTableWrapper<'db, 'txn, K: RedbKey + 'static, V: RedbKey + 'static> {
+  RtxTable(ReadOnlyMultimapTable<'txn, K, V>),
+  WtxTable(MultimapTable<'db, 'txn, K, V>),
+}
+
+impl<'db, 'txn, K: RedbKey + 'static, V: RedbKey + 'static> MultimapTableWrapper<'db, 'txn, K, V> {
+  fn get<'a>(&self, key: impl Borrow<K::SelfType<'a>>) -> Result<MultimapValue<V>, StorageError>
+  where
+    K: 'a,
+  {
+    match self {
+      Self::RtxTable(rtx_table) => rtx_table.get(key),
+      Self::WtxTable(wtx_table) => wtx_table.get(key),
+    }
+  }
 }
 
 impl<'db, 'a> DataStoreReadOnly for OrdDbReader<'db, 'a> {
@@ -107,6 +156,61 @@ impl<'db, 'a> DataStoreReadOnly for OrdDbReader<'db, 'a> {
         }),
     )
   }
+  fn get_inscription_numbers_by_script_key(
+    &self,
+    script_key: &ScriptKey,
+  ) -> Result<Vec<i64>, Self::Error> {
+    Ok(
+      self
+        .wrapper
+        .open_multimap_table(ORD_SCRIPT_KEY_TO_INSCRIPTION_NUMBERS)?
+        .get(script_key.to_string().as_str())?
+        .flat_map(|result| result.map(|number| number.value()))
+        .collect(),
+    )
+  }
+
+  fn get_inscription_history(
+    &self,
+    inscription_id: InscriptionId,
+    start: usize,
+    limit: usize,
+  ) -> Result<Vec<InscriptionTransfer>, Self::Error> {
+    let first = history_key(inscription_id, 0, 0);
+    let last = history_key(inscription_id, u64::MAX, u64::MAX);
+
+    Ok(
+      self
+        .wrapper
+        .open_table(ORD_INSCRIPTION_ID_TO_HISTORY)?
+        .range::<&str>(first.as_str()..=last.as_str())?
+        .skip(start)
+        .take(limit)
+        .map(|result| {
+          result.map(|(_, data)| bincode::deserialize::<InscriptionTransfer>(data.value()).unwrap())
+        })
+        .collect::<Result<_, _>>()?,
+    )
+  }
+
+  fn get_latest_inscription_transfer(
+    &self,
+    inscription_id: InscriptionId,
+  ) -> Result<Option<InscriptionTransfer>, Self::Error> {
+    let first = history_key(inscription_id, 0, 0);
+    let last = history_key(inscription_id, u64::MAX, u64::MAX);
+
+    Ok(
+      self
+        .wrapper
+        .open_table(ORD_INSCRIPTION_ID_TO_HISTORY)?
+        .range::<&str>(first.as_str()..=last.as_str())?
+        .next_back()
+        .transpose()?
+        .map(|(_, data)| bincode::deserialize::<InscriptionTransfer>(data.value()).unwrap()),
+    )
+  }
+
   fn get_number_by_inscription_id(
     &self,
     inscription_id: InscriptionId,
@@ -138,6 +242,31 @@ impl<'db, 'a> DataStoreReadOnly for OrdDbReader<'db, 'a> {
     )
   }
 
+  fn get_transaction_inputs(&self, txid: &Txid) -> Result<Option<Vec<OutPoint>>, Self::Error> {
+    Ok(
+      self
+        .wrapper
+        .open_table(ORD_TXID_TO_INPUTS)?
+        .get(&txid.as_raw_hash().to_byte_array())?
+        .map(|v| {
+          v.value()
+            .chunks_exact(36)
+            .map(|outpoint| Decodable::consensus_decode(&mut io::Cursor::new(outpoint)).unwrap())
+            .collect()
+        }),
+    )
+  }
+
+  fn get_block_tx_count(&self, height: u64) -> Result<Option<u64>, Self::Error> {
+    Ok(
+      self
+        .wrapper
+        .open_table(ORD_HEIGHT_TO_TX_COUNT)?
+        .get(height)?
+        .map(|v| v.value()),
+    )
+  }
+
   fn get_transaction_operations(&self, txid: &Txid) -> Result<Vec<InscriptionOp>, Self::Error> {
     Ok(
       self
@@ -149,4 +278,51 @@ impl<'db, 'a> DataStoreReadOnly for OrdDbReader<'db, 'a> {
         }),
     )
   }
+
+  fn get_children_count(&self, parent: InscriptionId) -> Result<u64, Self::Error> {
+    let mut key = [0; 36];
+    let (txid, index) = key.split_at_mut(32);
+    txid.copy_from_slice(parent.txid.as_ref());
+    index.copy_from_slice(&parent.index.to_be_bytes());
+
+    Ok(
+      self
+        .wrapper
+        .open_table(COLLECTIONS_PARENT_TO_CHILDREN)?
+        .range((&key, 0)..=(&key, u64::MAX))?
+        .next_back()
+        .transpose()?
+        .map_or(0, |(key, _)| key.value().1 + 1),
+    )
+  }
+
+  fn get_children(
+    &self,
+    parent: InscriptionId,
+    start: u64,
+    limit: u64,
+  ) -> Result<Vec<InscriptionId>, Self::Error> {
+    let mut key = [0; 36];
+    let (txid, index) = key.split_at_mut(32);
+    txid.copy_from_slice(parent.txid.as_ref());
+    index.copy_from_slice(&parent.index.to_be_bytes());
+
+    Ok(
+      self
+        .wrapper
+        .open_table(COLLECTIONS_PARENT_TO_CHILDREN)?
+        .range((&key, start)..=(&key, u64::MAX))?
+        .take(usize::try_from(limit).unwrap_or(usize::MAX))
+        .map(|result| {
+          result.map(|(_, child)| {
+            let (txid, index) = child.value().split_at(32);
+            InscriptionId {
+              txid: Txid::from_raw_hash(Hash::from_slice(txid).unwrap()),
+              index: u32::from_be_bytes(index.try_into().unwrap()),
+            }
+          })
+        })
+        .collect::<Result<_, _>>()?,
+    )
+  }
 }
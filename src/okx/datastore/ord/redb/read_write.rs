@@ -2,11 +2,14 @@ use {
   super::*,
   crate::{
     index::OUTPOINT_TO_ENTRY,
-    okx::datastore::ord::{DataStoreReadOnly, DataStoreReadWrite, InscriptionOp},
-    InscriptionId, Result,
+    okx::datastore::{
+      ord::{DataStoreReadOnly, DataStoreReadWrite, InscriptionOp, InscriptionTransfer},
+      ScriptKey,
+    },
+    Hash, InscriptionId, Result,
   },
   bitcoin::{consensus::Encodable, OutPoint, TxOut, Txid},
-  redb::{ReadTransaction, WriteTransaction},
+  redb::{ReadTransaction, ReadableTable, WriteTransaction},
 };
 
 pub fn try_init_tables<'db, 'a>(
@@ -18,6 +21,25 @@ pub fn try_init_tables<'db, 'a>(
     wtx.open_table(COLLECTIONS_KEY_TO_INSCRIPTION_ID)?;
     wtx.open_table(COLLECTIONS_INSCRIPTION_ID_TO_KINDS)?;
   }
+  if rtx.open_table(ORD_INSCRIPTION_ID_TO_HISTORY).is_err() {
+    wtx.open_table(ORD_INSCRIPTION_ID_TO_HISTORY)?;
+  }
+  if rtx.open_table(ORD_TXID_TO_INPUTS).is_err() {
+    wtx.open_table(ORD_TXID_TO_INPUTS)?;
+    wtx.open_table(ORD_HEIGHT_TO_CACHED_TXIDS)?;
+  }
+  if rtx.open_table(COLLECTIONS_PARENT_TO_CHILDREN).is_err() {
+    wtx.open_table(COLLECTIONS_PARENT_TO_CHILDREN)?;
+  }
+  if rtx.open_table(ORD_HEIGHT_TO_TX_COUNT).is_err() {
+    wtx.open_table(ORD_HEIGHT_TO_TX_COUNT)?;
+  }
+  if rtx
+    .open_multimap_table(ORD_SCRIPT_KEY_TO_INSCRIPTION_NUMBERS)
+    .is_err()
+  {
+    wtx.open_multimap_table(ORD_SCRIPT_KEY_TO_INSCRIPTION_NUMBERS)?;
+  }
   Ok(true)
 }
 
@@ -53,6 +75,22 @@ impl<'db, 'a> DataStoreReadOnly for OrdDbReadWriter<'db, 'a> {
   ) -> Result<Vec<InscriptionOp>, Self::Error> {
     read_only::new_with_wtx(self.wtx).get_transaction_operations(txid)
   }
+
+  fn get_inscription_history(
+    &self,
+    inscription_id: InscriptionId,
+    start: usize,
+    limit: usize,
+  ) -> Result<Vec<InscriptionTransfer>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_inscription_history(inscription_id, start, limit)
+  }
+
+  fn get_latest_inscription_transfer(
+    &self,
+    inscription_id: InscriptionId,
+  ) -> Result<Option<InscriptionTransfer>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_latest_inscription_transfer(inscription_id)
+  }
   // collections
   fn get_collection_inscription_id(&self, key: &str) -> Result<Option<InscriptionId>, Self::Error> {
     read_only::new_with_wtx(self.wtx).get_collection_inscription_id(key)
@@ -63,6 +101,34 @@ impl<'db, 'a> DataStoreReadOnly for OrdDbReadWriter<'db, 'a> {
   ) -> Result<Option<Vec<CollectionKind>>, Self::Error> {
     read_only::new_with_wtx(self.wtx).get_collections_of_inscription(inscription_id)
   }
+
+  fn get_inscription_numbers_by_script_key(
+    &self,
+    script_key: &ScriptKey,
+  ) -> Result<Vec<i64>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_inscription_numbers_by_script_key(script_key)
+  }
+
+  fn get_transaction_inputs(&self, txid: &Txid) -> Result<Option<Vec<OutPoint>>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_transaction_inputs(txid)
+  }
+
+  fn get_block_tx_count(&self, height: u64) -> Result<Option<u64>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_block_tx_count(height)
+  }
+
+  fn get_children_count(&self, parent: InscriptionId) -> Result<u64, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_children_count(parent)
+  }
+
+  fn get_children(
+    &self,
+    parent: InscriptionId,
+    start: u64,
+    limit: u64,
+  ) -> Result<Vec<InscriptionId>, Self::Error> {
+    read_only::new_with_wtx(self.wtx).get_children(parent, start, limit)
+  }
 }
 
 impl<'db, 'a> DataStoreReadWrite for OrdDbReadWriter<'db, 'a> {
@@ -94,6 +160,40 @@ impl<'db, 'a> DataStoreReadWrite for OrdDbReadWriter<'db, 'a> {
     )?;
     Ok(())
   }
+  fn append_inscription_history(
+    &self,
+    inscription_id: InscriptionId,
+    transfer: &InscriptionTransfer,
+  ) -> Result<(), Self::Error> {
+    let mut history = self.wtx.open_table(ORD_INSCRIPTION_ID_TO_HISTORY)?;
+
+    // the transfer follows the last one of the inscription in the same block, if any.
+    let index = {
+      let first = history_key(inscription_id, transfer.height, 0);
+      let last = history_key(inscription_id, transfer.height, u64::MAX);
+      history
+        .range::<&str>(first.as_str()..=last.as_str())?
+        .next_back()
+        .transpose()?
+        .map_or(0, |(key, _)| {
+          key
+            .value()
+            .rsplit_once('_')
+            .unwrap()
+            .1
+            .parse::<u64>()
+            .unwrap()
+            + 1
+        })
+    };
+
+    history.insert(
+      history_key(inscription_id, transfer.height, index).as_str(),
+      bincode::serialize(transfer).unwrap().as_slice(),
+    )?;
+    Ok(())
+  }
+
   fn set_inscription_by_collection_key(
     &self,
     key: &str,
@@ -125,6 +225,79 @@ impl<'db, 'a> DataStoreReadWrite for OrdDbReadWriter<'db, 'a> {
       .insert(&key, bincode::serialize(&kind).unwrap().as_slice())?;
     Ok(())
   }
+
+  fn save_block_transaction_inputs(
+    &self,
+    height: u64,
+    transactions: &[(Txid, Vec<OutPoint>)],
+  ) -> Result<(), Self::Error> {
+    let mut txid_to_inputs = self.wtx.open_table(ORD_TXID_TO_INPUTS)?;
+    let mut txids = Vec::with_capacity(transactions.len() * 32);
+    for (txid, inputs) in transactions {
+      let mut value = Vec::with_capacity(inputs.len() * 36);
+      for outpoint in inputs {
+        outpoint.consensus_encode(&mut value)?;
+      }
+      txid_to_inputs.insert(&txid.as_raw_hash().to_byte_array(), value.as_slice())?;
+      txids.extend_from_slice(txid.as_ref());
+    }
+    self
+      .wtx
+      .open_table(ORD_HEIGHT_TO_CACHED_TXIDS)?
+      .insert(height, txids.as_slice())?;
+    Ok(())
+  }
+
+  fn prune_transaction_inputs(&self, below_height: u64) -> Result<(), Self::Error> {
+    let mut height_to_txids = self.wtx.open_table(ORD_HEIGHT_TO_CACHED_TXIDS)?;
+    let mut txid_to_inputs = self.wtx.open_table(ORD_TXID_TO_INPUTS)?;
+
+    let heights = height_to_txids
+      .range(..below_height)?
+      .map(|result| result.map(|(height, _)| height.value()))
+      .collect::<Result<Vec<u64>, _>>()?;
+
+    for height in heights {
+      if let Some(txids) = height_to_txids.remove(height)? {
+        for txid in txids.value().chunks_exact(32) {
+          txid_to_inputs.remove(<&[u8; 32]>::try_from(txid).unwrap())?;
+        }
+      }
+    }
+    Ok(())
+  }
+
+  fn set_block_tx_count(&self, height: u64, tx_count: u64) -> Result<(), Self::Error> {
+    self
+      .wtx
+      .open_table(ORD_HEIGHT_TO_TX_COUNT)?
+      .insert(height, tx_count)?;
+    Ok(())
+  }
+
+  fn add_inscription_child(
+    &self,
+    parent: InscriptionId,
+    child: InscriptionId,
+  ) -> Result<(), Self::Error> {
+    let order = self.get_children_count(parent)?;
+
+    let mut key = [0; 36];
+    let (txid, index) = key.split_at_mut(32);
+    txid.copy_from_slice(parent.txid.as_ref());
+    index.copy_from_slice(&parent.index.to_be_bytes());
+
+    let mut value = [0; 36];
+    let (txid, index) = value.split_at_mut(32);
+    txid.copy_from_slice(child.txid.as_ref());
+    index.copy_from_slice(&child.index.to_be_bytes());
+
+    self
+      .wtx
+      .open_table(COLLECTIONS_PARENT_TO_CHILDREN)?
+      .insert((&key, order), &value)?;
+    Ok(())
+  }
 }
 
 #[cfg(test)]
@@ -195,4 +368,176 @@ mod tests {
       vec![operation]
     );
   }
+
+  #[test]
+  fn test_inscription_history() {
+    let dbfile = NamedTempFile::new().unwrap();
+    let db = Database::create(dbfile.path()).unwrap();
+    let wtx = db.begin_write().unwrap();
+    let ord_db = OrdDbReadWriter::new(&wtx);
+    let txid =
+      Txid::from_str("b61b0172d95e266c18aea0c624db987e971a5d6d4ebc2aaed85da4642d635735").unwrap();
+    let inscription_id = InscriptionId { txid, index: 1 };
+
+    assert!(ord_db
+      .get_inscription_history(inscription_id, 0, usize::MAX)
+      .unwrap()
+      .is_empty());
+    assert_eq!(
+      ord_db
+        .get_latest_inscription_transfer(inscription_id)
+        .unwrap(),
+      None
+    );
+
+    let genesis = InscriptionTransfer {
+      height: 1,
+      txid,
+      genesis: true,
+      satpoint: SatPoint {
+        outpoint: OutPoint { txid, vout: 0 },
+        offset: 0,
+      },
+      owner: Some(ScriptKey::from_str("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").unwrap()),
+    };
+    let transfer = InscriptionTransfer {
+      height: 2,
+      txid,
+      genesis: false,
+      satpoint: SatPoint {
+        outpoint: OutPoint::null(),
+        offset: 10,
+      },
+      owner: None,
+    };
+    let last_transfer = InscriptionTransfer {
+      satpoint: SatPoint {
+        outpoint: OutPoint { txid, vout: 1 },
+        offset: 0,
+      },
+      ..transfer.clone()
+    };
+    for transfer in [&genesis, &transfer, &last_transfer] {
+      ord_db
+        .append_inscription_history(inscription_id, transfer)
+        .unwrap();
+    }
+    // the history of another inscription whose id starts with the same characters.
+    ord_db
+      .append_inscription_history(InscriptionId { txid, index: 10 }, &genesis)
+      .unwrap();
+
+    assert_eq!(
+      ord_db
+        .get_inscription_history(inscription_id, 0, usize::MAX)
+        .unwrap(),
+      vec![genesis, transfer.clone(), last_transfer.clone()]
+    );
+    assert_eq!(
+      ord_db
+        .get_inscription_history(inscription_id, 1, 1)
+        .unwrap(),
+      vec![transfer]
+    );
+    assert_eq!(
+      ord_db
+        .get_latest_inscription_transfer(inscription_id)
+        .unwrap(),
+      Some(last_transfer)
+    );
+  }
+
+  #[test]
+  fn test_transaction_inputs() {
+    let dbfile = NamedTempFile::new().unwrap();
+    let db = Database::create(dbfile.path()).unwrap();
+    let wtx = db.begin_write().unwrap();
+    let ord_db = OrdDbReadWriter::new(&wtx);
+
+    let txid = |n: u8| Txid::from_raw_hash(Hash::from_slice(&[n; 32]).unwrap());
+    let inputs = vec![
+      OutPoint {
+        txid: txid(1),
+        vout: 0,
+      },
+      OutPoint {
+        txid: txid(2),
+        vout: 3,
+      },
+    ];
+
+    ord_db
+      .save_block_transaction_inputs(10, &[(txid(3), inputs.clone())])
+      .unwrap();
+    ord_db
+      .save_block_transaction_inputs(11, &[(txid(4), Vec::new())])
+      .unwrap();
+
+    assert_eq!(
+      ord_db.get_transaction_inputs(&txid(3)).unwrap(),
+      Some(inputs)
+    );
+    assert_eq!(
+      ord_db.get_transaction_inputs(&txid(4)).unwrap(),
+      Some(Vec::new())
+    );
+    assert_eq!(ord_db.get_transaction_inputs(&txid(5)).unwrap(), None);
+
+    ord_db.prune_transaction_inputs(11).unwrap();
+
+    assert_eq!(ord_db.get_transaction_inputs(&txid(3)).unwrap(), None);
+    assert_eq!(
+      ord_db.get_transaction_inputs(&txid(4)).unwrap(),
+      Some(Vec::new())
+    );
+  }
+
+  #[test]
+  fn test_block_tx_count() {
+    let dbfile = NamedTempFile::new().unwrap();
+    let db = Database::create(dbfile.path()).unwrap();
+    let wtx = db.begin_write().unwrap();
+    let ord_db = OrdDbReadWriter::new(&wtx);
+
+    assert_eq!(ord_db.get_block_tx_count(5).unwrap(), None);
+
+    ord_db.set_block_tx_count(0, 1).unwrap();
+    ord_db.set_block_tx_count(5, 2000).unwrap();
+
+    assert_eq!(ord_db.get_block_tx_count(0).unwrap(), Some(1));
+    assert_eq!(ord_db.get_block_tx_count(5).unwrap(), Some(2000));
+    assert_eq!(ord_db.get_block_tx_count(6).unwrap(), None);
+  }
+
+  #[test]
+  fn test_inscription_children() {
+    let dbfile = NamedTempFile::new().unwrap();
+    let db = Database::create(dbfile.path()).unwrap();
+    let wtx = db.begin_write().unwrap();
+    let ord_db = OrdDbReadWriter::new(&wtx);
+
+    let id = |n: u8, index: u32| InscriptionId {
+      txid: Txid::from_raw_hash(Hash::from_slice(&[n; 32]).unwrap()),
+      index,
+    };
+    let parent = id(1, 0);
+
+    assert_eq!(ord_db.get_children_count(parent).unwrap(), 0);
+    assert!(ord_db.get_children(parent, 0, 10).unwrap().is_empty());
+
+    // children are kept in the order they were added, not in id order.
+    ord_db.add_inscription_child(parent, id(3, 1)).unwrap();
+    ord_db.add_inscription_child(parent, id(2, 0)).unwrap();
+    ord_db.add_inscription_child(parent, id(3, 0)).unwrap();
+    ord_db.add_inscription_child(id(1, 1), id(4, 0)).unwrap();
+
+    assert_eq!(ord_db.get_children_count(parent).unwrap(), 3);
+    assert_eq!(ord_db.get_children_count(id(1, 1)).unwrap(), 1);
+    assert_eq!(
+      ord_db.get_children(parent, 0, u64::MAX).unwrap(),
+      vec![id(3, 1), id(2, 0), id(3, 0)]
+    );
+    assert_eq!(ord_db.get_children(parent, 1, 1).unwrap(), vec![id(2, 0)]);
+    assert!(ord_db.get_children(parent, 3, 10).unwrap().is_empty());
+  }
 }
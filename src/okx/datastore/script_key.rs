@@ -1,6 +1,9 @@
 use bitcoin::{address, Address, Network, Script, ScriptHash};
 use serde::{Deserialize, Serialize};
-use std::fmt::{Display, Formatter};
+use std::{
+  fmt::{Display, Formatter},
+  str::FromStr,
+};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub enum ScriptKey {
@@ -32,6 +35,18 @@ impl Display for ScriptKey {
     )
   }
 }
+
+impl FromStr for ScriptKey {
+  type Err = address::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match ScriptHash::from_str(s) {
+      Ok(script_hash) => Ok(ScriptKey::ScriptHash(script_hash)),
+      Err(_) => Ok(ScriptKey::Address(Address::from_str(s)?)),
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -71,6 +86,24 @@ mod tests {
       )
     );
   }
+  #[test]
+  fn test_script_key_from_str() {
+    let script_key =
+      ScriptKey::Address(Address::from_str("bc1qhvd6suvqzjcu9pxjhrwhtrlj85ny3n2mqql5w4").unwrap());
+    assert_eq!(
+      ScriptKey::from_str(&script_key.to_string()).unwrap(),
+      script_key
+    );
+    let script_key = ScriptKey::ScriptHash(
+      ScriptHash::from_str("df65c8a338dce7900824e7bd18c336656ca19e57").unwrap(),
+    );
+    assert_eq!(
+      ScriptKey::from_str(&script_key.to_string()).unwrap(),
+      script_key
+    );
+    assert!(ScriptKey::from_str("invalid").is_err());
+  }
+
   #[test]
   fn test_script_key_serialize() {
     let script_key =
@@ -13,13 +13,18 @@ mod msg_resolver;
 mod num;
 mod operation;
 mod params;
+mod version;
 
 use self::error::Error;
 pub(crate) use self::{
   error::JSONError,
   msg_executor::{execute, ExecutionMessage},
-  num::Num,
   operation::{deserialize_brc20_operation, Deploy, Mint, Operation, Transfer},
+  version::{get_config_by_height, VersionHeights},
+};
+pub use self::{
+  num::Num,
+  operation::{deserialize_brc20, RawOperation},
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -31,4 +36,6 @@ pub struct Message {
   pub new_satpoint: Option<SatPoint>,
   pub op: Operation,
   pub sat_in_outputs: bool,
+  /// The verified parent of the inscription.
+  pub parent: Option<InscriptionId>,
 }
@@ -1,7 +1,4 @@
-use super::{
-  params::{BIGDECIMAL_TEN, MAXIMUM_SUPPLY, MAX_DECIMAL_WIDTH},
-  *,
-};
+use super::{params::BIGDECIMAL_TEN, version::Config, *};
 
 use crate::okx::datastore::brc20 as brc20_store;
 use crate::okx::datastore::ord as ord_store;
@@ -9,8 +6,8 @@ use crate::okx::datastore::ord as ord_store;
 use crate::{
   okx::{
     datastore::brc20::{
-      BRC20Error, Balance, DeployEvent, Event, InscribeTransferEvent, MintEvent, Receipt, Tick,
-      TokenInfo, TransferEvent, TransferInfo, TransferableLog,
+      BRC20Error, Balance, BurnEvent, DeployEvent, Event, InscribeTransferEvent, MintEvent,
+      Receipt, Tick, TokenInfo, TransferEvent, TransferInfo, TransferableLog,
     },
     protocol::{
       brc20::{Message, Mint, Operation},
@@ -33,7 +30,10 @@ pub struct ExecutionMessage {
   pub(self) new_satpoint: SatPoint,
   pub(self) from: ScriptKey,
   pub(self) to: Option<ScriptKey>,
+  /// Whether the inscription was sent to an OP_RETURN output.
+  pub(self) to_op_return: bool,
   pub(self) op: Operation,
+  pub(self) parent: Option<InscriptionId>,
 }
 
 impl ExecutionMessage {
@@ -42,6 +42,12 @@ impl ExecutionMessage {
     msg: &Message,
     network: Network,
   ) -> Result<Self> {
+    let to_script = if msg.sat_in_outputs {
+      Some(utils::get_tx_out_on_satpoint(msg.new_satpoint.unwrap(), ord_store)?.script_pubkey)
+    } else {
+      None
+    };
+
     Ok(Self {
       txid: msg.txid,
       inscription_id: msg.inscription_id,
@@ -51,36 +57,45 @@ impl ExecutionMessage {
         .new_satpoint
         .ok_or(anyhow!("new satpoint cannot be None"))?,
       from: utils::get_script_key_on_satpoint(msg.old_satpoint, ord_store, network)?,
-      to: if msg.sat_in_outputs {
-        Some(utils::get_script_key_on_satpoint(
-          msg.new_satpoint.unwrap(),
-          ord_store,
-          network,
-        )?)
-      } else {
-        None
-      },
+      to: to_script
+        .as_ref()
+        .map(|script| ScriptKey::from_script(script, network)),
+      to_op_return: to_script.is_some_and(|script| script.is_op_return()),
       op: msg.op.clone(),
+      parent: msg.parent,
     })
   }
 }
 
 pub fn execute<'a, O: ord_store::DataStoreReadOnly, N: brc20_store::DataStoreReadWrite>(
   context: BlockContext,
+  config: Config,
   ord_store: &'a O,
   brc20_store: &'a N,
   msg: &ExecutionMessage,
 ) -> Result<Option<Receipt>> {
   log::debug!("BRC20 execute message: {:?}", msg);
   let event = match &msg.op {
-    Operation::Deploy(deploy) => {
-      process_deploy(context, ord_store, brc20_store, msg, deploy.clone())
+    Operation::Deploy(deploy) => process_deploy(
+      context,
+      &config,
+      ord_store,
+      brc20_store,
+      msg,
+      deploy.clone(),
+    ),
+    Operation::Mint(mint) => {
+      process_mint(context, &config, ord_store, brc20_store, msg, mint.clone())
     }
-    Operation::Mint(mint) => process_mint(context, ord_store, brc20_store, msg, mint.clone()),
-    Operation::InscribeTransfer(transfer) => {
-      process_inscribe_transfer(context, ord_store, brc20_store, msg, transfer.clone())
-    }
-    Operation::Transfer(_) => process_transfer(context, ord_store, brc20_store, msg),
+    Operation::InscribeTransfer(transfer) => process_inscribe_transfer(
+      context,
+      &config,
+      ord_store,
+      brc20_store,
+      msg,
+      transfer.clone(),
+    ),
+    Operation::Transfer(_) => process_transfer(context, &config, ord_store, brc20_store, msg),
   };
 
   let receipt = Receipt {
@@ -103,21 +118,53 @@ pub fn execute<'a, O: ord_store::DataStoreReadOnly, N: brc20_store::DataStoreRea
   brc20_store
     .add_transaction_receipt(&msg.txid, &receipt)
     .map_err(|e| anyhow!("failed to add transaction receipt to state! error: {e}"))?;
+  brc20_store
+    .add_block_txid(context.blockheight, &msg.txid)
+    .map_err(|e| anyhow!("failed to add block transaction to state! error: {e}"))?;
 
   Ok(Some(receipt))
 }
 
+/// The receiver of an inscribed deploy, mint or transfer. Inscriptions inscribed to the coinbase
+/// are credited to the inscriber if the rules allow it.
+fn inscribe_receiver(config: &Config, msg: &ExecutionMessage) -> Result<ScriptKey, BRC20Error> {
+  match msg.to.clone() {
+    Some(to) => Ok(to),
+    None if config.allow_inscribe_to_coinbase => Ok(msg.from.clone()),
+    None => Err(BRC20Error::InscribeToCoinbase),
+  }
+}
+
+/// Parses the tick of an inscribed operation. Five-byte ticks are only valid once self mint is
+/// activated, so they fail with the same error as any other invalid length before that.
+fn parse_tick(config: &Config, tick: &str) -> Result<Tick, BRC20Error> {
+  let tick = tick.parse::<Tick>()?;
+  if tick.is_self_mint_len() && !config.allow_self_mint {
+    return Err(BRC20Error::InvalidTickLen(tick.to_string()));
+  }
+  Ok(tick)
+}
+
 fn process_deploy<'a, O: ord_store::DataStoreReadOnly, N: brc20_store::DataStoreReadWrite>(
   context: BlockContext,
+  config: &Config,
   _ord_store: &'a O,
   brc20_store: &'a N,
   msg: &ExecutionMessage,
   deploy: Deploy,
 ) -> Result<Event, Error<N>> {
   // ignore inscribe inscription to coinbase.
-  let to_script_key = msg.to.clone().ok_or(BRC20Error::InscribeToCoinbase)?;
+  let to_script_key = inscribe_receiver(config, msg)?;
+
+  let tick = parse_tick(config, &deploy.tick)?;
 
-  let tick = deploy.tick.parse::<Tick>()?;
+  // five-byte ticks can only be minted by the children of the deploy inscription.
+  let self_mint = tick.is_self_mint_len();
+  if self_mint && !deploy.is_self_mint() {
+    return Err(Error::BRC20Error(BRC20Error::SelfMintRequired(
+      tick.to_string(),
+    )));
+  }
 
   if let Some(stored_tick_info) = brc20_store
     .get_token_info(&tick)
@@ -128,30 +175,37 @@ fn process_deploy<'a, O: ord_store::DataStoreReadOnly, N: brc20_store::DataStore
     )));
   }
 
-  let dec = Num::from_str(&deploy.decimals.map_or(MAX_DECIMAL_WIDTH.to_string(), |v| v))?
-    .checked_to_u8()?;
-  if dec > MAX_DECIMAL_WIDTH {
+  let dec = Num::from_str(
+    &deploy
+      .decimals
+      .map_or(config.max_decimal_width.to_string(), |v| v),
+  )?
+  .checked_to_u8()?;
+  if dec > config.max_decimal_width {
     return Err(Error::BRC20Error(BRC20Error::DecimalsTooLarge(dec)));
   }
   let base = BIGDECIMAL_TEN.checked_powu(u64::from(dec))?;
 
-  let supply = Num::from_str(&deploy.max_supply)?;
+  let max_supply = Num::from(config.max_supply);
+  let mut supply = Num::from_str(&deploy.max_supply)?;
 
-  if supply.sign() == Sign::NoSign
-    || supply > MAXIMUM_SUPPLY.to_owned()
-    || supply.scale() > i64::from(dec)
-  {
+  // a self mint tick without max supply can be minted up to the maximum supply.
+  if self_mint && supply.sign() == Sign::NoSign {
+    supply = max_supply.clone();
+  }
+
+  if supply.sign() == Sign::NoSign || supply > max_supply || supply.scale() > i64::from(dec) {
     return Err(Error::BRC20Error(BRC20Error::InvalidSupply(
       supply.to_string(),
     )));
   }
 
-  let limit = Num::from_str(&deploy.mint_limit.map_or(deploy.max_supply, |v| v))?;
+  let limit = match deploy.mint_limit {
+    Some(limit) => Num::from_str(&limit)?,
+    None => supply.clone(),
+  };
 
-  if limit.sign() == Sign::NoSign
-    || limit > MAXIMUM_SUPPLY.to_owned()
-    || limit.scale() > i64::from(dec)
-  {
+  if limit.sign() == Sign::NoSign || limit > max_supply || limit.scale() > i64::from(dec) {
     return Err(Error::BRC20Error(BRC20Error::MintLimitOutOfRange(
       tick.to_lowercase().to_string(),
       limit.to_string(),
@@ -173,6 +227,8 @@ fn process_deploy<'a, O: ord_store::DataStoreReadOnly, N: brc20_store::DataStore
     deployed_number: context.blockheight,
     latest_mint_number: context.blockheight,
     deployed_timestamp: context.blocktime,
+    self_mint,
+    burned: 0u128,
   };
   brc20_store
     .insert_token_info(&tick, &new_info)
@@ -188,21 +244,28 @@ fn process_deploy<'a, O: ord_store::DataStoreReadOnly, N: brc20_store::DataStore
 
 fn process_mint<'a, O: ord_store::DataStoreReadOnly, N: brc20_store::DataStoreReadWrite>(
   context: BlockContext,
+  config: &Config,
   _ord_store: &'a O,
   brc20_store: &'a N,
   msg: &ExecutionMessage,
   mint: Mint,
 ) -> Result<Event, Error<N>> {
   // ignore inscribe inscription to coinbase.
-  let to_script_key = msg.to.clone().ok_or(BRC20Error::InscribeToCoinbase)?;
+  let to_script_key = inscribe_receiver(config, msg)?;
 
-  let tick = mint.tick.parse::<Tick>()?;
+  let tick = parse_tick(config, &mint.tick)?;
 
   let token_info = brc20_store
     .get_token_info(&tick)
     .map_err(|e| Error::LedgerError(e))?
     .ok_or(BRC20Error::TickNotFound(tick.to_string()))?;
 
+  if token_info.self_mint && msg.parent != Some(token_info.inscription_id) {
+    return Err(Error::BRC20Error(BRC20Error::SelfMintPermissionDenied(
+      token_info.tick.to_string(),
+    )));
+  }
+
   let base = BIGDECIMAL_TEN.checked_powu(u64::from(token_info.decimal))?;
 
   let mut amt = Num::from_str(&mint.amount)?;
@@ -279,15 +342,16 @@ fn process_inscribe_transfer<
   N: brc20_store::DataStoreReadWrite,
 >(
   _context: BlockContext,
+  config: &Config,
   _ord_store: &'a O,
   brc20_store: &'a N,
   msg: &ExecutionMessage,
   transfer: Transfer,
 ) -> Result<Event, Error<N>> {
   // ignore inscribe inscription to coinbase.
-  let to_script_key = msg.to.clone().ok_or(BRC20Error::InscribeToCoinbase)?;
+  let to_script_key = inscribe_receiver(config, msg)?;
 
-  let tick = transfer.tick.parse::<Tick>()?;
+  let tick = parse_tick(config, &transfer.tick)?;
 
   let token_info = brc20_store
     .get_token_info(&tick)
@@ -362,10 +426,17 @@ fn process_inscribe_transfer<
 
 fn process_transfer<'a, O: ord_store::DataStoreReadOnly, N: brc20_store::DataStoreReadWrite>(
   _context: BlockContext,
+  config: &Config,
   _ord_store: &'a O,
   brc20_store: &'a N,
   msg: &ExecutionMessage,
 ) -> Result<Event, Error<N>> {
+  if msg.to.is_none() && !config.redirect_transfer_to_coinbase {
+    return Err(Error::BRC20Error(BRC20Error::TransferToCoinbase(
+      msg.inscription_id,
+    )));
+  }
+
   let transferable = brc20_store
     .get_transferable_by_id(&msg.from, &msg.inscription_id)
     .map_err(|e| Error::LedgerError(e))?
@@ -405,6 +476,30 @@ fn process_transfer<'a, O: ord_store::DataStoreReadOnly, N: brc20_store::DataSto
     .update_token_balance(&msg.from, from_balance)
     .map_err(|e| Error::LedgerError(e))?;
 
+  brc20_store
+    .remove_transferable(&msg.from, &tick, msg.inscription_id)
+    .map_err(|e| Error::LedgerError(e))?;
+
+  brc20_store
+    .remove_inscribe_transfer_inscription(msg.inscription_id)
+    .map_err(|e| Error::LedgerError(e))?;
+
+  // burn the amount if transfer to OP_RETURN.
+  if msg.to_op_return && config.burn_transfer_to_op_return {
+    let burned = Into::<Num>::into(token_info.burned)
+      .checked_add(&amt)?
+      .checked_to_u128()?;
+
+    brc20_store
+      .update_burned_token_info(&tick, burned)
+      .map_err(|e| Error::LedgerError(e))?;
+
+    return Ok(Event::Burn(BurnEvent {
+      tick: token_info.tick,
+      amount: amt.checked_to_u128()?,
+    }));
+  }
+
   // redirect receiver to sender if transfer to conibase.
   let mut out_msg = None;
 
@@ -429,14 +524,6 @@ fn process_transfer<'a, O: ord_store::DataStoreReadOnly, N: brc20_store::DataSto
     .update_token_balance(&to_script_key, to_balance)
     .map_err(|e| Error::LedgerError(e))?;
 
-  brc20_store
-    .remove_transferable(&msg.from, &tick, msg.inscription_id)
-    .map_err(|e| Error::LedgerError(e))?;
-
-  brc20_store
-    .remove_inscribe_transfer_inscription(msg.inscription_id)
-    .map_err(|e| Error::LedgerError(e))?;
-
   Ok(Event::Transfer(TransferEvent {
     msg: out_msg,
     tick: token_info.tick,
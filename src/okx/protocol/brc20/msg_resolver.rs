@@ -17,6 +17,7 @@ impl Message {
     brc20_store: &N,
     new_inscriptions: &[Inscription],
     op: &InscriptionOp,
+    parent: Option<InscriptionId>,
   ) -> Result<Option<Message>> {
     log::debug!("BRC20 resolving the message from {:?}", op);
     let sat_in_outputs = op
@@ -68,6 +69,7 @@ impl Message {
       new_satpoint: op.new_satpoint,
       op: brc20_operation,
       sat_in_outputs,
+      parent,
     }))
   }
 }
@@ -154,7 +156,10 @@ mod tests {
     let (inscriptions, op) = create_inscribe_operation(
       r#"{ "p": "brc-20s","op": "deploy", "tick": "ordi", "max": "1000", "lim": "10" }"#,
     );
-    assert_matches!(Message::resolve(&brc20_store, &inscriptions, &op), Ok(None));
+    assert_matches!(
+      Message::resolve(&brc20_store, &inscriptions, &op, None),
+      Ok(None)
+    );
   }
 
   #[test]
@@ -175,7 +180,10 @@ mod tests {
       },
       ..op
     };
-    assert_matches!(Message::resolve(&brc20_store, &inscriptions, &op), Ok(None));
+    assert_matches!(
+      Message::resolve(&brc20_store, &inscriptions, &op, None),
+      Ok(None)
+    );
 
     let op2 = InscriptionOp {
       action: Action::New {
@@ -186,7 +194,7 @@ mod tests {
       ..op
     };
     assert_matches!(
-      Message::resolve(&brc20_store, &inscriptions, &op2),
+      Message::resolve(&brc20_store, &inscriptions, &op2, None),
       Ok(None)
     );
     let op3 = InscriptionOp {
@@ -198,7 +206,7 @@ mod tests {
       ..op
     };
     assert_matches!(
-      Message::resolve(&brc20_store, &inscriptions, &op3),
+      Message::resolve(&brc20_store, &inscriptions, &op3, None),
       Ok(None)
     );
   }
@@ -223,11 +231,13 @@ mod tests {
         max_supply: "1000".to_string(),
         mint_limit: Some("10".to_string()),
         decimals: None,
+        self_mint: None,
       }),
       sat_in_outputs: true,
+      parent: None,
     };
     assert_matches!(
-      Message::resolve(&brc20_store, &inscriptions, &op),
+      Message::resolve(&brc20_store, &inscriptions, &op, None),
       Ok(Some(_result_msg))
     );
   }
@@ -241,7 +251,7 @@ mod tests {
 
     // inscribe transfer not found
     let op = create_transfer_operation();
-    assert_matches!(Message::resolve(&brc20_store, &[], &op), Ok(None));
+    assert_matches!(Message::resolve(&brc20_store, &[], &op, None), Ok(None));
 
     // non-first transfer operations.
     let op1 = InscriptionOp {
@@ -255,7 +265,7 @@ mod tests {
       },
       ..op
     };
-    assert_matches!(Message::resolve(&brc20_store, &[], &op1), Ok(None));
+    assert_matches!(Message::resolve(&brc20_store, &[], &op1, None), Ok(None));
   }
 
   #[test]
@@ -287,8 +297,12 @@ mod tests {
         amount: "100".to_string(),
       }),
       sat_in_outputs: true,
+      parent: None,
     };
 
-    assert_matches!(Message::resolve(&brc20_store, &[], &op), Ok(Some(_msg)));
+    assert_matches!(
+      Message::resolve(&brc20_store, &[], &op, None),
+      Ok(Some(_msg))
+    );
   }
 }
@@ -10,6 +10,15 @@ pub struct Deploy {
   pub mint_limit: Option<String>,
   #[serde(rename = "dec")]
   pub decimals: Option<String>,
+  #[serde(rename = "self_mint", skip_serializing_if = "Option::is_none")]
+  pub self_mint: Option<String>,
+}
+
+impl Deploy {
+  /// Whether the deploy restricts the mints to the children of its inscription.
+  pub fn is_self_mint(&self) -> bool {
+    self.self_mint.as_deref() == Some("true")
+  }
 }
 
 #[cfg(test)]
@@ -24,6 +33,7 @@ mod tests {
       max_supply: "12000".to_string(),
       mint_limit: Some("12".to_string()),
       decimals: Some("11".to_string()),
+      self_mint: None,
     };
 
     assert_eq!(
@@ -50,10 +60,31 @@ mod tests {
         max_supply: "12000".to_string(),
         mint_limit: Some("12".to_string()),
         decimals: Some("11".to_string()),
+        self_mint: None,
       })
     );
   }
 
+  #[test]
+  fn test_deserialize_self_mint() {
+    let RawOperation::Deploy(deploy) = deserialize_brc20(
+      r#"{"p":"brc-20","op":"deploy","tick":"abcde","max":"0","lim":"12","self_mint":"true"}"#,
+    )
+    .unwrap() else {
+      panic!("not a deploy");
+    };
+    assert_eq!(deploy.self_mint, Some("true".to_string()));
+    assert!(deploy.is_self_mint());
+
+    let RawOperation::Deploy(deploy) = deserialize_brc20(
+      r#"{"p":"brc-20","op":"deploy","tick":"abcde","max":"0","self_mint":"false"}"#,
+    )
+    .unwrap() else {
+      panic!("not a deploy");
+    };
+    assert!(!deploy.is_self_mint());
+  }
+
   #[test]
   fn test_loss_require_key() {
     assert_eq!(
@@ -74,6 +105,7 @@ mod tests {
         max_supply: "100".to_string(),
         mint_limit: None,
         decimals: Some("10".to_string()),
+        self_mint: None,
       })
     );
 
@@ -86,6 +118,7 @@ mod tests {
         max_supply: "100".to_string(),
         mint_limit: Some("10".to_string()),
         decimals: None,
+        self_mint: None,
       })
     );
 
@@ -97,6 +130,7 @@ mod tests {
         max_supply: "100".to_string(),
         mint_limit: None,
         decimals: None,
+        self_mint: None,
       })
     );
   }
@@ -111,6 +145,7 @@ mod tests {
         max_supply: "300".to_string(),
         mint_limit: Some("20".to_string()),
         decimals: Some("17".to_string()),
+        self_mint: None,
       })
     );
   }
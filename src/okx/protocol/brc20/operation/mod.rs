@@ -30,7 +30,7 @@ impl Operation {
 
 #[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
 #[serde(tag = "op")]
-enum RawOperation {
+pub enum RawOperation {
   #[serde(rename = "deploy")]
   Deploy(Deploy),
   #[serde(rename = "mint")]
@@ -80,7 +80,7 @@ pub(crate) fn deserialize_brc20_operation(
   }
 }
 
-fn deserialize_brc20(s: &str) -> Result<RawOperation, JSONError> {
+pub fn deserialize_brc20(s: &str) -> Result<RawOperation, JSONError> {
   let value: Value = serde_json::from_str(s).map_err(|_| JSONError::InvalidJson)?;
   if value.get("p") != Some(&json!(PROTOCOL_LITERAL)) {
     return Err(JSONError::NotBRC20Json);
@@ -115,7 +115,8 @@ mod tests {
         tick: "ordi".to_string(),
         max_supply,
         mint_limit: Some(mint_limit),
-        decimals: None
+        decimals: None,
+        self_mint: None,
       })
     );
   }
@@ -220,6 +221,7 @@ mod tests {
         max_supply: "12000".to_string(),
         mint_limit: Some("12".to_string()),
         decimals: Some("11".to_string()),
+        self_mint: None,
       }),
     );
     let inscription = crate::inscription(
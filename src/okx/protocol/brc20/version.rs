@@ -0,0 +1,111 @@
+use super::params::MAX_DECIMAL_WIDTH;
+
+/// The BRC20 rules in effect at a height, consensus-affecting changes switch the version.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+  /// The maximum supply and mint limit of a tick, before applying its decimals.
+  pub max_supply: u64,
+  /// The maximum decimals of a tick.
+  pub max_decimal_width: u8,
+  /// Whether deploys, mints and transfers inscribed to the coinbase are credited to the inscriber
+  /// instead of being rejected.
+  pub allow_inscribe_to_coinbase: bool,
+  /// Whether a transfer sent to the coinbase is credited back to the sender instead of being
+  /// rejected.
+  pub redirect_transfer_to_coinbase: bool,
+  /// Whether five-byte ticks deployed with `self_mint` are accepted.
+  pub allow_self_mint: bool,
+  /// Whether a transfer sent to an OP_RETURN output burns the amount instead of crediting the
+  /// script.
+  pub burn_transfer_to_op_return: bool,
+}
+
+/// The heights at which the versions activate, which default to the ones of the chain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VersionHeights {
+  pub self_mint: u64,
+  pub burn: u64,
+}
+
+// start at the first BRC20 height, block 779832 on mainnet.
+pub const fn genesis() -> Config {
+  Config {
+    max_supply: u64::MAX,
+    max_decimal_width: MAX_DECIMAL_WIDTH,
+    allow_inscribe_to_coinbase: false,
+    redirect_transfer_to_coinbase: true,
+    allow_self_mint: false,
+    burn_transfer_to_op_return: false,
+  }
+}
+
+// start at the first self mint height, block 837090 on mainnet.
+pub const fn self_mint() -> Config {
+  Config {
+    max_supply: u64::MAX,
+    max_decimal_width: MAX_DECIMAL_WIDTH,
+    allow_inscribe_to_coinbase: false,
+    redirect_transfer_to_coinbase: true,
+    allow_self_mint: true,
+    burn_transfer_to_op_return: false,
+  }
+}
+
+// start at the first burn height, which is not activated on mainnet yet.
+pub const fn burn() -> Config {
+  Config {
+    max_supply: u64::MAX,
+    max_decimal_width: MAX_DECIMAL_WIDTH,
+    allow_inscribe_to_coinbase: false,
+    redirect_transfer_to_coinbase: true,
+    allow_self_mint: true,
+    burn_transfer_to_op_return: true,
+  }
+}
+
+pub fn get_config_by_height(heights: VersionHeights, blockheight: u64) -> Config {
+  // the versions build on each other, the index refuses a burn height below the self mint one.
+  match blockheight {
+    n if n >= heights.burn => burn(),
+    n if n >= heights.self_mint => self_mint(),
+    _ => genesis(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn versions_switch_at_their_heights() {
+    let heights = VersionHeights {
+      self_mint: 837090,
+      burn: 840000,
+    };
+    assert_eq!(get_config_by_height(heights, 779832), genesis());
+    assert_eq!(get_config_by_height(heights, 837089), genesis());
+    assert_eq!(get_config_by_height(heights, 837090), self_mint());
+    assert_eq!(get_config_by_height(heights, 839999), self_mint());
+    assert_eq!(get_config_by_height(heights, 840000), burn());
+    assert_eq!(
+      get_config_by_height(
+        VersionHeights {
+          self_mint: 0,
+          burn: u64::MAX
+        },
+        0
+      ),
+      self_mint()
+    );
+    assert_eq!(
+      get_config_by_height(
+        VersionHeights {
+          self_mint: 837090,
+          burn: 837090
+        },
+        837090
+      ),
+      burn()
+    );
+  }
+}
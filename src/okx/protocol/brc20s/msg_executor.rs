@@ -7,8 +7,8 @@ use crate::okx::{
     },
     brc20s::{
       Balance, DeployPoolEvent, DeployTickEvent, DepositEvent, Event, InscribeTransferEvent,
-      MintEvent, PassiveWithdrawEvent, Pid, PoolInfo, Receipt, StakeInfo, Tick, TickId, TickInfo,
-      TransferEvent, TransferInfo, TransferableAsset, UserInfo, WithdrawEvent,
+      MintEvent, PassiveWithdrawEvent, Pid, PoolInfo, PoolSnapshot, Receipt, StakeInfo, Tick,
+      TickId, TickInfo, TransferEvent, TransferInfo, TransferableAsset, UserInfo, WithdrawEvent,
     },
     ScriptKey,
   },
@@ -560,6 +560,9 @@ fn process_stake<'a, M: brc20::DataStoreReadWrite, N: brc20s::DataStoreReadWrite
   brc20s_store
     .set_pid_to_poolinfo(&pool_id, &pool)
     .map_err(|e| Error::LedgerError(e))?;
+  brc20s_store
+    .set_pool_snapshot(&pool_id, &PoolSnapshot::new(&pool, context.blockheight))
+    .map_err(|e| Error::LedgerError(e))?;
 
   Ok(Event::Deposit(DepositEvent {
     pid: pool_id,
@@ -669,6 +672,9 @@ fn process_unstake<'a, M: brc20::DataStoreReadWrite, N: brc20s::DataStoreReadWri
   brc20s_store
     .set_pid_to_poolinfo(&pool_id, &pool)
     .map_err(|e| Error::LedgerError(e))?;
+  brc20s_store
+    .set_pool_snapshot(&pool_id, &PoolSnapshot::new(&pool, context.blockheight))
+    .map_err(|e| Error::LedgerError(e))?;
 
   brc20s_store
     .set_user_stakeinfo(&to_script_key, &stake_tick, &user_stakeinfo)
@@ -850,6 +856,12 @@ fn process_mint<'a, M: brc20::DataStoreReadWrite, N: brc20s::DataStoreReadWrite>
   brc20s_store
     .set_pid_to_poolinfo(&pool_id, &pool_info)
     .map_err(|e| Error::LedgerError(e))?;
+  brc20s_store
+    .set_pool_snapshot(
+      &pool_id,
+      &PoolSnapshot::new(&pool_info, context.blockheight),
+    )
+    .map_err(|e| Error::LedgerError(e))?;
 
   // update tick info
   brc20s_store
@@ -1173,6 +1185,8 @@ mod tests {
       deployed_number: 0,
       deployed_timestamp: 0,
       latest_mint_number: 0,
+      self_mint: false,
+      burned: 0,
     };
     brc20_store.insert_token_info(&token, &token_info);
 
@@ -1352,6 +1366,8 @@ mod tests {
       deployed_number: 0,
       deployed_timestamp: 0,
       latest_mint_number: 0,
+      self_mint: false,
+      burned: 0,
     };
     brc20_data_store.insert_token_info(&token, &token_info);
 
@@ -1492,6 +1508,8 @@ mod tests {
         deployed_number: 0,
         deployed_timestamp: 0,
         latest_mint_number: 0,
+        self_mint: false,
+        burned: 0,
       };
       brc20_data_store.insert_token_info(&token, &token_info);
 
@@ -1508,6 +1526,8 @@ mod tests {
         deployed_number: 0,
         deployed_timestamp: 0,
         latest_mint_number: 0,
+        self_mint: false,
+        burned: 0,
       };
       brc20_data_store.insert_token_info(&token, &token_info);
 
@@ -1524,6 +1544,8 @@ mod tests {
         deployed_number: 0,
         deployed_timestamp: 0,
         latest_mint_number: 0,
+        self_mint: false,
+        burned: 0,
       };
       brc20_data_store.insert_token_info(&token, &token_info);
     }
@@ -3029,6 +3051,8 @@ mod tests {
       deployed_number: 0,
       deployed_timestamp: 0,
       latest_mint_number: 0,
+      self_mint: false,
+      burned: 0,
     };
     brc20_data_store.insert_token_info(&token, &token_info);
     let balance = BRC20Balance {
@@ -3211,6 +3235,22 @@ mod tests {
       assert_eq!(expect_poolinfo, serde_json::to_string(&pool_info).unwrap());
       assert_eq!(expect_stakeinfo, serde_json::to_string(&stakeinfo).unwrap());
       assert_eq!(expect_userinfo, serde_json::to_string(&userinfo).unwrap());
+
+      // each deposit appends a snapshot of the pool at its block.
+      let snapshots = brc20s_data_store
+        .get_pool_snapshots(&pid, 0, u64::MAX, usize::MAX)
+        .unwrap();
+      assert_eq!(
+        serde_json::to_string(&snapshots).unwrap(),
+        r#"[{"block_num":20,"staked":1000000000,"minted":0,"acc_reward_per_share":"0","last_update_block":20},{"block_num":30,"staked":2000000000,"minted":1000000,"acc_reward_per_share":"1000000000000000","last_update_block":30}]"#
+      );
+      assert_eq!(
+        brc20s_data_store
+          .get_pool_snapshots(&pid, 21, 30, usize::MAX)
+          .unwrap()
+          .len(),
+        1
+      );
     }
 
     // invalid inscribe to coinbase
@@ -3669,6 +3709,8 @@ mod tests {
       deployed_number: 0,
       deployed_timestamp: 0,
       latest_mint_number: 0,
+      self_mint: false,
+      burned: 0,
     };
     brc20_data_store.insert_token_info(&token, &token_info);
     let balance = BRC20Balance {
@@ -3894,6 +3936,8 @@ mod tests {
       deployed_number: 0,
       deployed_timestamp: 0,
       latest_mint_number: 0,
+      self_mint: false,
+      burned: 0,
     };
     brc20_data_store.insert_token_info(&token, &token_info);
     let balance = BRC20Balance {
@@ -4180,6 +4224,8 @@ mod tests {
       deployed_number: 0,
       deployed_timestamp: 0,
       latest_mint_number: 0,
+      self_mint: false,
+      burned: 0,
     };
     brc20_data_store.insert_token_info(&token, &token_info);
     let balance = BRC20Balance {
@@ -4414,6 +4460,8 @@ mod tests {
       deployed_number: 0,
       deployed_timestamp: 0,
       latest_mint_number: 0,
+      self_mint: false,
+      burned: 0,
     };
     brc20_data_store.insert_token_info(&token, &token_info);
     let balance = BRC20Balance {
@@ -4863,6 +4911,8 @@ mod tests {
       deployed_number: 0,
       deployed_timestamp: 0,
       latest_mint_number: 0,
+      self_mint: false,
+      burned: 0,
     };
     let _ = brc20_data_store.insert_token_info(&token, &token_info);
     let balance = BRC20Balance {
@@ -5285,6 +5335,8 @@ mod tests {
       deployed_number: 0,
       deployed_timestamp: 0,
       latest_mint_number: 0,
+      self_mint: false,
+      burned: 0,
     };
     let _ = brc20_data_store.insert_token_info(&token, &token_info);
     let balance = BRC20Balance {
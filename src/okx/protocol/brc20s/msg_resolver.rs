@@ -19,7 +19,7 @@ use {
 
 impl Message {
   pub(crate) fn resolve<'a, O: ord::DataStoreReadOnly, M: brc20s::DataStoreReadOnly>(
-    client: &Client,
+    client: Option<&Client>,
     ord_store: &'a O,
     brc20s_store: &'a M,
     new_inscriptions: &[Inscription],
@@ -89,69 +89,125 @@ impl Message {
   }
 }
 
+/// Locates the sat at `satpoint`, an output of the commit transaction, among the commit
+/// transaction inputs.
+///
+/// The commit transaction and the spent outputs are read from the transaction inputs cached for
+/// recent blocks and from the ord store. Bitcoin Core is only asked for them when `client` is set,
+/// otherwise they must be found locally, so that every index resolves the commit the same way.
 fn get_commit_input_satpoint<O: ord::DataStoreReadOnly>(
-  client: &Client,
+  client: Option<&Client>,
   ord_store: &O,
   satpoint: SatPoint,
   outpoint_to_txout_cache: &mut HashMap<OutPoint, TxOut>,
 ) -> Result<SatPoint> {
-  let commit_transaction =
-    &Index::get_transaction_retries(client, satpoint.outpoint.txid)?.ok_or(anyhow!(
-      "failed to BRC20S message commit transaction! error: {} not found",
-      satpoint.outpoint.txid
-    ))?;
+  let commit_txid = satpoint.outpoint.txid;
+  let commit_inputs = match ord_store
+    .get_transaction_inputs(&commit_txid)
+    .map_err(|e| anyhow!("failed to get transaction inputs from state! error: {e}"))?
+  {
+    Some(inputs) => inputs,
+    None => {
+      let Some(client) = client else {
+        return Err(anyhow!(
+          "failed to get BRC20S message commit transaction! error: {commit_txid} precedes the cached transaction inputs, raise --transaction-cache-blocks and rebuild the index"
+        ));
+      };
+      let commit_transaction =
+        Index::get_transaction_retries(client, commit_txid)?.ok_or(anyhow!(
+          "failed to BRC20S message commit transaction! error: {} not found",
+          commit_txid
+        ))?;
+      for (vout, tx_out) in commit_transaction.output.into_iter().enumerate() {
+        outpoint_to_txout_cache.insert(
+          OutPoint {
+            txid: commit_txid,
+            vout: u32::try_from(vout).unwrap(),
+          },
+          tx_out,
+        );
+      }
+      commit_transaction
+        .input
+        .iter()
+        .map(|input| input.previous_output)
+        .collect()
+    }
+  };
 
   // get satoshi offset
-  let mut offset = 0;
-  for (vout, output) in commit_transaction.output.iter().enumerate() {
-    if vout < usize::try_from(satpoint.outpoint.vout).unwrap() {
-      offset += output.value;
-      continue;
-    }
-    offset += satpoint.offset;
-    break;
+  let mut offset = satpoint.offset;
+  for vout in 0..satpoint.outpoint.vout {
+    offset += get_tx_out(
+      client,
+      ord_store,
+      OutPoint {
+        txid: commit_txid,
+        vout,
+      },
+      outpoint_to_txout_cache,
+    )?
+    .value;
   }
 
   let mut input_value = 0;
-  for input in &commit_transaction.input {
-    let value = if let Some(tx_out) = ord_store
-      .get_outpoint_to_txout(input.previous_output)
-      .map_err(|e| anyhow!("failed to get tx out from state! error: {e}"))?
-    {
-      tx_out.value
-    } else if let Some(tx_out) = Index::get_transaction_retries(client, input.previous_output.txid)?
-      .map(|tx| {
-        tx.output
-          .get(usize::try_from(input.previous_output.vout).unwrap())
-          .unwrap()
-          .clone()
-      })
-    {
-      outpoint_to_txout_cache.insert(input.previous_output, tx_out.clone());
-      tx_out.value
-    } else {
-      return Err(anyhow!(
-        "failed to get tx out! error: {} not found",
-        input.previous_output
-      ));
-    };
+  for previous_output in commit_inputs {
+    let value = get_tx_out(client, ord_store, previous_output, outpoint_to_txout_cache)?.value;
 
-    input_value += value;
-    if input_value >= offset {
+    if input_value + value >= offset {
       return Ok(SatPoint {
-        outpoint: input.previous_output,
-        offset: value - input_value + offset,
+        outpoint: previous_output,
+        offset: offset - input_value,
       });
     }
+    input_value += value;
   }
   Err(anyhow!("no match found for the commit offset!"))
 }
+
+fn get_tx_out<O: ord::DataStoreReadOnly>(
+  client: Option<&Client>,
+  ord_store: &O,
+  outpoint: OutPoint,
+  outpoint_to_txout_cache: &mut HashMap<OutPoint, TxOut>,
+) -> Result<TxOut> {
+  if let Some(tx_out) = outpoint_to_txout_cache.get(&outpoint) {
+    return Ok(tx_out.clone());
+  }
+
+  if let Some(tx_out) = ord_store
+    .get_outpoint_to_txout(outpoint)
+    .map_err(|e| anyhow!("failed to get tx out from state! error: {e}"))?
+  {
+    return Ok(tx_out);
+  }
+
+  let Some(client) = client else {
+    return Err(anyhow!(
+      "failed to get tx out! error: {outpoint} not found in the index"
+    ));
+  };
+
+  let tx_out = Index::get_transaction_retries(client, outpoint.txid)?
+    .and_then(|tx| {
+      tx.output
+        .into_iter()
+        .nth(usize::try_from(outpoint.vout).unwrap())
+    })
+    .ok_or(anyhow!(
+      "failed to get tx out! error: {} not found",
+      outpoint
+    ))?;
+
+  outpoint_to_txout_cache.insert(outpoint, tx_out.clone());
+  Ok(tx_out)
+}
 #[cfg(test)]
 mod tests {
   use super::*;
   use crate::okx::datastore::{
     brc20s::{redb::DataStore, DataStoreReadWrite, Tick, TickId, TransferInfo},
-    ord::OrdDbReadWriter,
+    ord::{DataStoreReadWrite as _, OrdDbReadWriter},
   };
   use bitcoin::OutPoint;
   use bitcoincore_rpc::{Auth, Client};
@@ -239,7 +295,7 @@ mod tests {
     );
     assert_matches!(
       Message::resolve(
-        &client,
+        Some(&client),
         &ord_store,
         &brc20s_store,
         &inscriptions,
@@ -274,7 +330,7 @@ mod tests {
     };
     assert_matches!(
       Message::resolve(
-        &client,
+        Some(&client),
         &ord_store,
         &brc20s_store,
         &inscriptions,
@@ -294,7 +350,7 @@ mod tests {
     };
     assert_matches!(
       Message::resolve(
-        &client,
+        Some(&client),
         &ord_store,
         &brc20s_store,
         &inscriptions,
@@ -313,7 +369,7 @@ mod tests {
     };
     assert_matches!(
       Message::resolve(
-        &client,
+        Some(&client),
         &ord_store,
         &brc20s_store,
         &inscriptions,
@@ -338,7 +394,7 @@ mod tests {
     let op = create_transfer_operation();
     assert_matches!(
       Message::resolve(
-        &client,
+        Some(&client),
         &ord_store,
         &brc20s_store,
         &[],
@@ -362,7 +418,7 @@ mod tests {
     };
     assert_matches!(
       Message::resolve(
-        &client,
+        Some(&client),
         &ord_store,
         &brc20s_store,
         &[],
@@ -412,7 +468,7 @@ mod tests {
     };
     assert_matches!(
       Message::resolve(
-        &client,
+        Some(&client),
         &ord_store,
         &brc20s_store,
         &[],
@@ -422,4 +478,87 @@ mod tests {
       Ok(Some(_msg))
     );
   }
+
+  #[test]
+  fn test_commit_input_satpoint_from_cache() {
+    let db_file = NamedTempFile::new().unwrap();
+    let db = Database::create(db_file.path()).unwrap();
+    let wtx = db.begin_write().unwrap();
+    let ord_store = OrdDbReadWriter::new(&wtx);
+
+    let outpoint = |txid: &str, vout: u32| OutPoint {
+      txid: Txid::from_str(txid).unwrap(),
+      vout,
+    };
+    let commit_txid = "1111111111111111111111111111111111111111111111111111111111111111";
+    let first_input = outpoint(
+      "2111111111111111111111111111111111111111111111111111111111111111",
+      0,
+    );
+    let second_input = outpoint(
+      "3111111111111111111111111111111111111111111111111111111111111111",
+      2,
+    );
+
+    for (outpoint, value) in [
+      (first_input, 100),
+      (second_input, 500),
+      (outpoint(commit_txid, 0), 300),
+      (outpoint(commit_txid, 1), 300),
+    ] {
+      ord_store
+        .set_outpoint_to_txout(
+          outpoint,
+          &TxOut {
+            value,
+            script_pubkey: Default::default(),
+          },
+        )
+        .unwrap();
+    }
+
+    let satpoint = SatPoint {
+      outpoint: outpoint(commit_txid, 1),
+      offset: 50,
+    };
+
+    // the commit transaction is neither cached nor fetched without a client.
+    assert!(get_commit_input_satpoint(None, &ord_store, satpoint, &mut HashMap::new()).is_err());
+
+    ord_store
+      .save_block_transaction_inputs(
+        1,
+        &[(
+          Txid::from_str(commit_txid).unwrap(),
+          vec![first_input, second_input],
+        )],
+      )
+      .unwrap();
+
+    assert_eq!(
+      get_commit_input_satpoint(None, &ord_store, satpoint, &mut HashMap::new()).unwrap(),
+      SatPoint {
+        outpoint: second_input,
+        offset: 250,
+      }
+    );
+
+    // a spent output which is not stored locally is not fetched either.
+    ord_store
+      .save_block_transaction_inputs(
+        2,
+        &[(
+          Txid::from_str(commit_txid).unwrap(),
+          vec![
+            outpoint(
+              "4111111111111111111111111111111111111111111111111111111111111111",
+              0,
+            ),
+            second_input,
+          ],
+        )],
+      )
+      .unwrap();
+    assert!(get_commit_input_satpoint(None, &ord_store, satpoint, &mut HashMap::new()).is_err());
+  }
 }
@@ -14,11 +14,15 @@ use {
 
 pub struct CallManager<'a, RW: StateRWriter> {
   state_store: &'a RW,
+  config: &'a ProtocolConfig,
 }
 
 impl<'a, RW: StateRWriter> CallManager<'a, RW> {
-  pub fn new(state_store: &'a RW) -> Self {
-    Self { state_store }
+  pub fn new(state_store: &'a RW, config: &'a ProtocolConfig) -> Self {
+    Self {
+      state_store,
+      config,
+    }
   }
 
   pub fn execute_message(&self, context: BlockContext, msg: &Message) -> Result {
@@ -26,6 +30,7 @@ impl<'a, RW: StateRWriter> CallManager<'a, RW> {
     let receipt = match msg {
       Message::BRC20(msg) => brc20_proto::execute(
         context,
+        brc20_proto::get_config_by_height(self.config.brc20_version_heights, context.blockheight),
         self.state_store.ord(),
         self.state_store.brc20(),
         &brc20_proto::ExecutionMessage::from_message(self.state_store.ord(), msg, context.network)?,
@@ -48,17 +53,22 @@ impl<'a, RW: StateRWriter> CallManager<'a, RW> {
     // convert receipt to internal call message
     match receipt.unwrap() {
       Receipt::BRC20(brc20_receipt) => {
-        if let Ok(brc20_store::Event::Transfer(brc20_transfer)) = brc20_receipt.result {
-          let ptick = brc20s_store::PledgedTick::BRC20Tick(brc20_transfer.tick.clone());
+        // both transfers and burns decrease the balance of the sender.
+        if let Ok(
+          brc20_store::Event::Transfer(brc20_store::TransferEvent { tick, amount, .. })
+          | brc20_store::Event::Burn(brc20_store::BurnEvent { tick, amount }),
+        ) = brc20_receipt.result
+        {
+          let ptick = brc20s_store::PledgedTick::BRC20Tick(tick.clone());
           match convert_pledged_tick_without_decimal(
             &ptick,
-            brc20_transfer.amount,
+            amount,
             self.state_store.brc20s(),
             self.state_store.brc20(),
           ) {
             Ok(amt) => {
               let passive_unstake = brc20s_proto::PassiveUnStake {
-                stake: brc20_transfer.tick.to_string(),
+                stake: tick.to_string(),
                 amount: amt.to_string(),
               };
               if let Message::BRC20(_) = msg {
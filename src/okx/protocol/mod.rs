@@ -5,6 +5,8 @@ pub(crate) mod message;
 pub(crate) mod ord;
 pub(crate) mod protocol_manager;
 pub(crate) mod resolve_manager;
+#[cfg(test)]
+mod scenario;
 mod utils;
 
 pub use self::protocol_manager::ProtocolManager;
@@ -15,7 +17,7 @@ use {
     message::{Message, Receipt},
     resolve_manager::MsgResolveManager,
   },
-  crate::Options,
+  crate::{okx::datastore::ord::names::Namespace, Options},
   bitcoin::Network,
 };
 
@@ -29,9 +31,13 @@ pub struct BlockContext {
 pub struct ProtocolConfig {
   first_inscription_height: u64,
   first_brc20_height: Option<u64>,
+  brc20_version_heights: brc20::VersionHeights,
   first_brc20s_height: Option<u64>,
   enable_ord_receipts: bool,
   enable_index_bitmap: bool,
+  name_namespaces: Vec<Namespace>,
+  transaction_cache_blocks: u64,
+  txindex: bool,
 }
 
 impl ProtocolConfig {
@@ -43,6 +49,10 @@ impl ProtocolConfig {
       } else {
         None
       },
+      brc20_version_heights: brc20::VersionHeights {
+        self_mint: options.first_brc20_self_mint_height(),
+        burn: options.first_brc20_burn_height(),
+      },
       first_brc20s_height: if options.enable_index_brc20s {
         Some(options.first_brc20s_height())
       } else {
@@ -50,6 +60,9 @@ impl ProtocolConfig {
       },
       enable_ord_receipts: options.enable_save_ord_receipts,
       enable_index_bitmap: options.enable_index_bitmap,
+      name_namespaces: options.index_names.clone(),
+      transaction_cache_blocks: options.transaction_cache_blocks(),
+      txindex: !options.no_txindex,
     };
 
     if config.first_brc20s_height.is_some() && config.first_brc20_height.is_none() {
@@ -1,9 +1,10 @@
 use {
   super::*,
   crate::{
+    index::BlockData,
     okx::{
       datastore::ord::{
-        bitmap::District,
+        bitmap::{District, Parcel},
         collections::CollectionKind,
         operation::{Action, InscriptionOp},
       },
@@ -15,45 +16,52 @@ use {
   std::collections::HashMap,
 };
 
+/// Records the transaction count of the block, which parcels of its district are checked against,
+/// and indexes the districts and parcels inscribed in it.
 pub fn index_bitmap<O: DataStoreReadWrite>(
   ord_store: &O,
   context: BlockContext,
+  block: &BlockData,
   operations: &HashMap<Txid, Vec<InscriptionOp>>,
 ) -> Result<u64> {
-  let mut count = 0;
-
-  // ignore transferred or cursed inscriptions.
-  let mut positive_inscriptions = operations
-    .values()
-    .flatten()
-    .cloned()
-    .filter(|op| {
-      !op.inscription_number.unwrap().is_negative() && matches!(op.action, Action::New { .. })
-    })
-    .collect::<Vec<_>>();
+  ord_store
+    .set_block_tx_count(
+      context.blockheight,
+      u64::try_from(block.txdata.len()).unwrap(),
+    )
+    .map_err(|e| {
+      anyhow!(
+        "failed to set transaction count! height: {} error: {e}",
+        context.blockheight
+      )
+    })?;
 
-  // sort by inscription number.
-  positive_inscriptions.sort_by_key(|op| op.inscription_number.unwrap());
+  let mut count = 0;
 
-  for op in positive_inscriptions.into_iter() {
+  for op in new_positive_inscriptions(operations) {
     match op.action {
       Action::New {
         cursed: _,
         unbound: _,
         inscription,
       } => {
-        if let Some((inscription_id, district)) =
+        if let Some((inscription_id, parcel)) =
+          index_parcel(ord_store, &inscription, op.inscription_id)?
+        {
+          let key = parcel.to_collection_key();
+          ord_store
+            .set_inscription_by_collection_key(&key, inscription_id)
+            .map_err(|e| anyhow!("failed to store collection! key: {key}, error: {e}"))?;
+          add_inscription_collection(ord_store, inscription_id, CollectionKind::BitMapParcel)?;
+          count += 1;
+        } else if let Some((inscription_id, district)) =
           index_district(ord_store, context, inscription, op.inscription_id)?
         {
           let key = district.to_collection_key();
           ord_store
             .set_inscription_by_collection_key(&key, inscription_id)
             .map_err(|e| anyhow!("failed to store collection! key: {key}, error: {e}"))?;
-          ord_store
-            .set_inscription_attributes(inscription_id, &[CollectionKind::BitMap])
-            .map_err(|e| {
-              anyhow!("failed to store inscription attributes! id: {inscription_id} error: {e}")
-            })?;
+          add_inscription_collection(ord_store, inscription_id, CollectionKind::BitMap)?;
           count += 1;
         }
       }
@@ -100,3 +108,81 @@ fn index_district<O: DataStoreReadWrite>(
   }
   Ok(None)
 }
+
+/// A parcel is valid if it is the first inscription of the parcel, its index is below the number
+/// of transactions of the district's block, and it is inscribed to the current holder of the
+/// district.
+fn index_parcel<O: DataStoreReadWrite>(
+  ord_store: &O,
+  inscription: &Inscription,
+  inscription_id: InscriptionId,
+) -> Result<Option<(InscriptionId, Parcel)>> {
+  let Some(parcel) = inscription
+    .body()
+    .and_then(|content| Parcel::parse(content).ok())
+  else {
+    return Ok(None);
+  };
+
+  let district_key = District {
+    number: parcel.district,
+  }
+  .to_collection_key();
+  let Some(district_id) = ord_store
+    .get_collection_inscription_id(&district_key)
+    .map_err(|e| anyhow!("failed to get collection inscription! key: {district_key} error: {e}"))?
+  else {
+    log::info!("parcel of unregistered district! parcel: {parcel} inscription_id {inscription_id}");
+    return Ok(None);
+  };
+
+  let collection_key = parcel.to_collection_key();
+  if ord_store
+    .get_collection_inscription_id(&collection_key)
+    .map_err(|e| anyhow!("failed to get collection inscription! key: {collection_key} error: {e}"))?
+    .is_some()
+  {
+    log::info!("duplicate parcel! parcel: {parcel} inscription_id {inscription_id}");
+    return Ok(None);
+  }
+
+  // the district was registered, so its block is already indexed.
+  let tx_count = ord_store
+    .get_block_tx_count(parcel.district)
+    .map_err(|e| anyhow!("failed to get transaction count! height: {} error: {e}", parcel.district))?
+    .ok_or_else(|| {
+      anyhow!(
+        "transaction count of block {} is not indexed, rebuild the index with --enable-index-bitmap",
+        parcel.district
+      )
+    })?;
+  if parcel.index >= tx_count {
+    log::info!(
+      "parcel out of range! parcel: {parcel} transactions: {tx_count} inscription_id {inscription_id}"
+    );
+    return Ok(None);
+  }
+
+  let district_holder = current_holder(ord_store, district_id)?;
+  if district_holder.is_none() || current_holder(ord_store, inscription_id)? != district_holder {
+    log::info!(
+      "parcel not inscribed to the district holder! parcel: {parcel} inscription_id {inscription_id}"
+    );
+    return Ok(None);
+  }
+
+  log::info!("found valid parcel! parcel: {parcel} inscription_id {inscription_id}");
+  Ok(Some((inscription_id, parcel)))
+}
+
+fn current_holder<O: DataStoreReadWrite>(
+  ord_store: &O,
+  inscription_id: InscriptionId,
+) -> Result<Option<ScriptKey>> {
+  Ok(
+    ord_store
+      .get_latest_inscription_transfer(inscription_id)
+      .map_err(|e| anyhow!("failed to get inscription history! id: {inscription_id} error: {e}"))?
+      .and_then(|transfer| transfer.owner),
+  )
+}
@@ -1,12 +1,61 @@
 use {
+  super::BlockContext,
   crate::{
-    okx::datastore::ord::{DataStoreReadWrite, InscriptionOp},
-    Result,
+    index::BlockData,
+    okx::datastore::{
+      ord::{
+        collections::CollectionKind, Action, DataStoreReadWrite, InscriptionOp, InscriptionTransfer,
+      },
+      ScriptKey,
+    },
+    unbound_outpoint, InscriptionId, Result,
   },
   anyhow::anyhow,
-  bitcoin::Txid,
+  bitcoin::{OutPoint, Txid},
 };
 pub mod bitmap;
+pub mod names;
+
+/// Returns the new inscriptions of the block that are neither cursed nor transferred, ordered by
+/// inscription number.
+fn new_positive_inscriptions(
+  operations: &std::collections::HashMap<Txid, Vec<InscriptionOp>>,
+) -> Vec<InscriptionOp> {
+  let mut positive_inscriptions = operations
+    .values()
+    .flatten()
+    .filter(|op| {
+      !op.inscription_number.unwrap().is_negative() && matches!(op.action, Action::New { .. })
+    })
+    .cloned()
+    .collect::<Vec<_>>();
+
+  positive_inscriptions.sort_by_key(|op| op.inscription_number.unwrap());
+
+  positive_inscriptions
+}
+
+/// Adds a collection to those of the inscription.
+fn add_inscription_collection<O: DataStoreReadWrite>(
+  ord_store: &O,
+  inscription_id: InscriptionId,
+  kind: CollectionKind,
+) -> Result<()> {
+  let mut kinds = ord_store
+    .get_collections_of_inscription(inscription_id)
+    .map_err(|e| anyhow!("failed to get inscription attributes! id: {inscription_id} error: {e}"))?
+    .unwrap_or_default();
+
+  if !kinds.contains(&kind) {
+    kinds.push(kind);
+    ord_store
+      .set_inscription_attributes(inscription_id, &kinds)
+      .map_err(|e| {
+        anyhow!("failed to store inscription attributes! id: {inscription_id} error: {e}")
+      })?;
+  }
+  Ok(())
+}
 
 pub fn save_transaction_operations<O: DataStoreReadWrite>(
   ord_store: &O,
@@ -17,3 +66,122 @@ pub fn save_transaction_operations<O: DataStoreReadWrite>(
     .save_transaction_operations(txid, tx_operations)
     .map_err(|e| anyhow!("failed to set transaction ordinals operations to state! error: {e}"))
 }
+
+pub fn save_inscription_history<O: DataStoreReadWrite>(
+  ord_store: &O,
+  context: BlockContext,
+  tx_operations: &[InscriptionOp],
+) -> Result<()> {
+  for op in tx_operations {
+    let Some(satpoint) = op.new_satpoint else {
+      continue;
+    };
+
+    let owner = if satpoint.outpoint == OutPoint::null() || satpoint.outpoint == unbound_outpoint()
+    {
+      None
+    } else {
+      let tx_out = ord_store
+        .get_outpoint_to_txout(satpoint.outpoint)
+        .map_err(|e| anyhow!("failed to get tx out from state! error: {e}"))?
+        .ok_or_else(|| {
+          anyhow!(
+            "failed to get tx out! error: {} not found",
+            satpoint.outpoint
+          )
+        })?;
+      Some(ScriptKey::from_script(
+        &tx_out.script_pubkey,
+        context.network,
+      ))
+    };
+
+    ord_store
+      .append_inscription_history(
+        op.inscription_id,
+        &InscriptionTransfer {
+          height: context.blockheight,
+          txid: op.txid,
+          genesis: matches!(op.action, Action::New { .. }),
+          satpoint,
+          owner,
+        },
+      )
+      .map_err(|e| anyhow!("failed to append inscription history to state! error: {e}"))?;
+  }
+  Ok(())
+}
+
+/// The parent of a new inscription, if it is verified. A parent is verified if it is spent or
+/// inscribed by the same transaction, which is the rule the inscription updater applies.
+pub fn verified_parent(
+  op: &InscriptionOp,
+  tx_operations: &[InscriptionOp],
+) -> Option<InscriptionId> {
+  match &op.action {
+    Action::New { inscription, .. } => inscription.parent().filter(|parent| {
+      tx_operations
+        .iter()
+        .any(|candidate| candidate.inscription_id == *parent)
+    }),
+    Action::Transfer => None,
+  }
+}
+
+/// Adds the new inscriptions of the transaction to the children of their verified parents, in
+/// inscription order.
+pub fn save_inscription_children<O: DataStoreReadWrite>(
+  ord_store: &O,
+  tx_operations: &[InscriptionOp],
+) -> Result<u64> {
+  let mut children = tx_operations
+    .iter()
+    .filter_map(|op| verified_parent(op, tx_operations).map(|parent| (parent, op.inscription_id)))
+    .collect::<Vec<(InscriptionId, InscriptionId)>>();
+
+  children.sort_by_key(|(_, child)| child.index);
+
+  for (parent, child) in &children {
+    ord_store
+      .add_inscription_child(*parent, *child)
+      .map_err(|e| anyhow!("failed to add inscription child! parent: {parent} error: {e}"))?;
+    add_inscription_collection(ord_store, *parent, CollectionKind::Parent)?;
+  }
+
+  Ok(children.len().try_into().unwrap())
+}
+
+/// Saves the inputs of the block's transactions and prunes those of blocks more than
+/// `cache_blocks` blocks below it.
+pub fn cache_transaction_inputs<O: DataStoreReadWrite>(
+  ord_store: &O,
+  context: BlockContext,
+  block: &BlockData,
+  cache_blocks: Option<u64>,
+) -> Result<()> {
+  let transactions = block
+    .txdata
+    .iter()
+    .skip(1)
+    .map(|(tx, txid)| {
+      (
+        *txid,
+        tx.input
+          .iter()
+          .map(|input| input.previous_output)
+          .collect::<Vec<OutPoint>>(),
+      )
+    })
+    .collect::<Vec<(Txid, Vec<OutPoint>)>>();
+
+  ord_store
+    .save_block_transaction_inputs(context.blockheight, &transactions)
+    .map_err(|e| anyhow!("failed to save transaction inputs to state! error: {e}"))?;
+
+  if let Some(height) = cache_blocks.and_then(|blocks| context.blockheight.checked_sub(blocks)) {
+    ord_store
+      .prune_transaction_inputs(height)
+      .map_err(|e| anyhow!("failed to prune transaction inputs from state! error: {e}"))?;
+  }
+  Ok(())
+}
@@ -0,0 +1,60 @@
+use {
+  super::*,
+  crate::{
+    okx::datastore::ord::{
+      collections::CollectionKind,
+      names::{Name, Namespace},
+      operation::{Action, InscriptionOp},
+    },
+    Result,
+  },
+  bitcoin::Txid,
+  std::collections::HashMap,
+};
+
+/// Registers the names inscribed in the block, the first inscription of a name wins.
+pub fn index_names<O: DataStoreReadWrite>(
+  ord_store: &O,
+  operations: &HashMap<Txid, Vec<InscriptionOp>>,
+  namespaces: &[Namespace],
+) -> Result<u64> {
+  let mut count = 0;
+
+  for op in new_positive_inscriptions(operations) {
+    let Action::New { inscription, .. } = op.action else {
+      unreachable!()
+    };
+
+    let Some(name) = inscription
+      .body()
+      .and_then(|content| Name::parse(content, namespaces).ok())
+    else {
+      continue;
+    };
+
+    let key = name.to_collection_key();
+    if ord_store
+      .get_collection_inscription_id(&key)
+      .map_err(|e| anyhow!("failed to get collection inscription! key: {key} error: {e}"))?
+      .is_some()
+    {
+      log::info!(
+        "duplicate name! name: {name} inscription_id {}",
+        op.inscription_id
+      );
+      continue;
+    }
+
+    log::info!(
+      "found valid name! name: {name} inscription_id {}",
+      op.inscription_id
+    );
+    ord_store
+      .set_inscription_by_collection_key(&key, op.inscription_id)
+      .map_err(|e| anyhow!("failed to store collection! key: {key}, error: {e}"))?;
+    add_inscription_collection(ord_store, op.inscription_id, CollectionKind::Name)?;
+    count += 1;
+  }
+
+  Ok(count)
+}
@@ -26,7 +26,7 @@ impl<'a, RW: StateRWriter> ProtocolManager<'a, RW> {
     Self {
       state_store,
       config,
-      call_man: CallManager::new(state_store),
+      call_man: CallManager::new(state_store, config),
       resolve_man: MsgResolveManager::new(client, state_store, config),
     }
   }
@@ -40,8 +40,26 @@ impl<'a, RW: StateRWriter> ProtocolManager<'a, RW> {
     let start = Instant::now();
     let mut inscriptions_size = 0;
     let mut messages_size = 0;
+    let mut children_size = 0;
+
+    // keep the inputs of recent transactions, so BRC20S commit transactions are resolved locally.
+    // without a txindex they are never pruned, since they can't be fetched from Bitcoin Core.
+    if let Some(first_brc20s_height) = self.config.first_brc20s_height {
+      if context.blockheight + self.config.transaction_cache_blocks >= first_brc20s_height {
+        ord_proto::cache_transaction_inputs(
+          self.state_store.ord(),
+          context,
+          block,
+          self
+            .config
+            .txindex
+            .then_some(self.config.transaction_cache_blocks),
+        )?;
+      }
+    }
+
     // skip the coinbase transaction.
-    for (tx, txid) in block.txdata.iter() {
+    for ((tx, txid), tx_inscriptions) in block.txdata.iter().zip(&block.inscriptions) {
       // skip coinbase transaction.
       if tx
         .input
@@ -54,6 +72,13 @@ impl<'a, RW: StateRWriter> ProtocolManager<'a, RW> {
 
       // index inscription operations.
       if let Some(tx_operations) = operations.get(txid) {
+        // record the provenance of every inscription.
+        if context.blockheight >= self.config.first_inscription_height {
+          ord_proto::save_inscription_history(self.state_store.ord(), context, tx_operations)?;
+          children_size +=
+            ord_proto::save_inscription_children(self.state_store.ord(), tx_operations)?;
+        }
+
         // save all transaction operations to ord database.
         if self.config.enable_ord_receipts
           && context.blockheight >= self.config.first_inscription_height
@@ -63,9 +88,10 @@ impl<'a, RW: StateRWriter> ProtocolManager<'a, RW> {
         }
 
         // Resolve and execute messages.
-        let messages = self
-          .resolve_man
-          .resolve_message(context, tx, tx_operations)?;
+        let messages =
+          self
+            .resolve_man
+            .resolve_message(context, tx, tx_inscriptions, tx_operations)?;
         for msg in messages.iter() {
           self.call_man.execute_message(context, msg)?;
         }
@@ -74,15 +100,26 @@ impl<'a, RW: StateRWriter> ProtocolManager<'a, RW> {
     }
     let mut bitmap_count = 0;
     if self.config.enable_index_bitmap {
-      bitmap_count = ord_proto::bitmap::index_bitmap(self.state_store.ord(), context, &operations)?;
+      bitmap_count =
+        ord_proto::bitmap::index_bitmap(self.state_store.ord(), context, block, &operations)?;
+    }
+    let mut names_count = 0;
+    if !self.config.name_namespaces.is_empty() {
+      names_count = ord_proto::names::index_names(
+        self.state_store.ord(),
+        &operations,
+        &self.config.name_namespaces,
+      )?;
     }
 
     log::info!(
-      "Protocol Manager indexed block {} with ord inscriptions {}, messages {}, bitmap {} in {} ms",
+      "Protocol Manager indexed block {} with ord inscriptions {}, children {}, messages {}, bitmap {}, names {} in {} ms",
       context.blockheight,
       inscriptions_size,
+      children_size,
       messages_size,
       bitmap_count,
+      names_count,
       (Instant::now() - start).as_millis(),
     );
     Ok(())
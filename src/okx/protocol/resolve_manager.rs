@@ -1,12 +1,13 @@
 use {
   super::*,
   crate::{
+    inscription::TransactionInscription,
     okx::{
       datastore::{
         ord::{operation::InscriptionOp, DataStoreReadWrite},
         StateRWriter,
       },
-      protocol::Message,
+      protocol::{ord as ord_proto, Message},
     },
     Inscription, Result,
   },
@@ -35,6 +36,7 @@ impl<'a, RW: StateRWriter> MsgResolveManager<'a, RW> {
     &self,
     context: BlockContext,
     tx: &Transaction,
+    new_inscriptions: &[TransactionInscription],
     operations: &[InscriptionOp],
   ) -> Result<Vec<Message>> {
     log::debug!(
@@ -45,9 +47,9 @@ impl<'a, RW: StateRWriter> MsgResolveManager<'a, RW> {
     );
     let mut messages = Vec::new();
     let mut operation_iter = operations.iter().peekable();
-    let new_inscriptions = Inscription::from_transaction(tx)
-      .into_iter()
-      .map(|v| v.inscription)
+    let new_inscriptions = new_inscriptions
+      .iter()
+      .map(|v| v.inscription.clone())
       .collect::<Vec<Inscription>>();
 
     let mut outpoint_to_txout_cache: HashMap<OutPoint, TxOut> = HashMap::new();
@@ -67,9 +69,12 @@ impl<'a, RW: StateRWriter> MsgResolveManager<'a, RW> {
           .map(|height| context.blockheight >= height)
           .unwrap_or(false)
         {
-          if let Some(msg) =
-            brc20::Message::resolve(self.state_store.brc20(), &new_inscriptions, operation)?
-          {
+          if let Some(msg) = brc20::Message::resolve(
+            self.state_store.brc20(),
+            &new_inscriptions,
+            operation,
+            ord_proto::verified_parent(operation, operations),
+          )? {
             log::debug!(
               "BRC20 resolved the message from {:?}, msg {:?}",
               operation,
@@ -88,7 +93,7 @@ impl<'a, RW: StateRWriter> MsgResolveManager<'a, RW> {
           .unwrap_or(false)
         {
           if let Some(msg) = brc20s::Message::resolve(
-            self.client,
+            self.config.txindex.then_some(self.client),
             self.state_store.ord(),
             self.state_store.brc20s(),
             &new_inscriptions,
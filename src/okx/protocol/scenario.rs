@@ -0,0 +1,702 @@
+//! Declarative protocol scenarios, run through the real indexer on `test_bitcoincore_rpc`.
+//!
+//! Each YAML or JSON file in `src/okx/protocol/scenarios` names some wallets, lists the steps
+//! they take, deploys, mints, transfers, deposits and withdrawals, and the balances, pool and
+//! user states expected at the end. A step expects its receipt to succeed unless it names the
+//! `error` it should fail with. Amounts are written with the decimals of their ticks.
+
+use {
+  crate::{
+    okx::{
+      datastore::{
+        brc20s::{Pid, PledgedTick, TickId},
+        ScriptKey,
+      },
+      protocol::brc20s::{hash::caculate_tick_id, params::NATIVE_TOKEN_DECIMAL},
+    },
+    test::{inscription, TransactionTemplate},
+    Index, Options,
+  },
+  bitcoin::{Address, Network, ScriptBuf, Txid},
+  clap::Parser,
+  serde::Deserialize,
+  std::{
+    collections::{HashMap, VecDeque},
+    ffi::OsString,
+    fs,
+    path::Path,
+    str::FromStr,
+  },
+  tempfile::TempDir,
+};
+
+/// The number of outputs a funding transaction splits a coinbase into.
+const FUNDING_OUTPUTS: usize = 10;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Scenario {
+  #[serde(default)]
+  #[allow(unused)]
+  description: String,
+  wallets: Vec<String>,
+  steps: Vec<Step>,
+  #[serde(default)]
+  expect: Expect,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum Step {
+  /// Mines empty blocks.
+  Mine(u64),
+  /// Inscribes an arbitrary body to the wallet.
+  Inscribe {
+    wallet: String,
+    body: serde_json::Value,
+    error: Option<String>,
+  },
+  /// Deploys a BRC20 tick.
+  Deploy {
+    wallet: String,
+    tick: String,
+    max: String,
+    lim: Option<String>,
+    dec: Option<String>,
+    error: Option<String>,
+  },
+  /// Mints a BRC20 tick.
+  Mint {
+    wallet: String,
+    tick: String,
+    amt: String,
+    error: Option<String>,
+  },
+  /// Inscribes a BRC20 transfer and sends it to another wallet.
+  Transfer {
+    from: String,
+    to: String,
+    tick: String,
+    amt: String,
+    error: Option<String>,
+  },
+  /// Deploys a BRC20-S pool, later steps refer to it by `name`.
+  DeployPool {
+    wallet: String,
+    name: String,
+    #[serde(rename = "type")]
+    pool_type: String,
+    number: String,
+    stake: String,
+    earn: String,
+    erate: String,
+    dmax: String,
+    total: String,
+    dec: Option<u8>,
+    #[serde(default)]
+    only: bool,
+    error: Option<String>,
+  },
+  /// Deposits into a BRC20-S pool.
+  Deposit {
+    wallet: String,
+    pool: String,
+    amt: String,
+    error: Option<String>,
+  },
+  /// Withdraws from a BRC20-S pool.
+  Withdraw {
+    wallet: String,
+    pool: String,
+    amt: String,
+    error: Option<String>,
+  },
+  /// Mints the earned tick of a BRC20-S pool.
+  MintPool {
+    wallet: String,
+    pool: String,
+    amt: String,
+    error: Option<String>,
+  },
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expect {
+  #[serde(default)]
+  balances: Vec<ExpectBalance>,
+  #[serde(default)]
+  pools: Vec<ExpectPool>,
+  #[serde(default)]
+  users: Vec<ExpectUser>,
+  #[serde(default)]
+  earned: Vec<ExpectEarned>,
+}
+
+/// The BRC20 balance of a wallet.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExpectBalance {
+  wallet: String,
+  tick: String,
+  overall: String,
+  #[serde(default = "zero")]
+  transferable: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExpectPool {
+  pool: String,
+  staked: String,
+  minted: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExpectUser {
+  pool: String,
+  wallet: String,
+  staked: String,
+  minted: String,
+}
+
+/// The balance of the earned tick of a pool.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExpectEarned {
+  pool: String,
+  wallet: String,
+  overall: String,
+}
+
+fn zero() -> String {
+  "0".into()
+}
+
+/// Which protocol a step expects a receipt of.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Protocol {
+  BRC20,
+  BRC20S,
+}
+
+/// A BRC20-S pool deployed by a step.
+struct DeployedPool {
+  pid: String,
+  tick_id: TickId,
+  earn: String,
+}
+
+struct Runner {
+  // the scenario file, which every failure names.
+  scenario: String,
+  rpc_server: test_bitcoincore_rpc::Handle,
+  #[allow(unused)]
+  tempdir: TempDir,
+  index: Index,
+  height: usize,
+  wallets: HashMap<String, Address>,
+  funding: HashMap<String, VecDeque<(usize, usize, usize)>>,
+  pools: HashMap<String, DeployedPool>,
+}
+
+impl Runner {
+  fn new(scenario: &Path, wallets: &[String]) -> Self {
+    let rpc_server = test_bitcoincore_rpc::builder()
+      .network(Network::Regtest)
+      .build();
+
+    let tempdir = TempDir::new().unwrap();
+    let cookie_file = tempdir.path().join("cookie");
+    fs::write(&cookie_file, "username:password").unwrap();
+
+    let options = Options::try_parse_from([
+      OsString::from("ord"),
+      "--rpc-url".into(),
+      rpc_server.url().into(),
+      "--data-dir".into(),
+      tempdir.path().into(),
+      "--cookie-file".into(),
+      cookie_file.into(),
+      "--regtest".into(),
+      "--enable-index-brc20".into(),
+      "--enable-index-brc20s".into(),
+    ])
+    .unwrap();
+
+    let index = Index::open(&options).unwrap();
+    index.update().unwrap();
+
+    Self {
+      scenario: scenario.display().to_string(),
+      rpc_server,
+      tempdir,
+      index,
+      height: 0,
+      wallets: wallets
+        .iter()
+        .map(|name| {
+          (
+            name.clone(),
+            Address::p2wsh(&ScriptBuf::from(name.as_bytes().to_vec()), Network::Regtest),
+          )
+        })
+        .collect(),
+      funding: HashMap::new(),
+      pools: HashMap::new(),
+    }
+  }
+
+  fn mine(&mut self, n: u64) {
+    self.rpc_server.mine_blocks(n);
+    self.index.update().unwrap();
+    self.height += usize::try_from(n).unwrap();
+  }
+
+  /// Broadcasts a transaction and mines it, returning its location and txid.
+  fn send(&mut self, template: TransactionTemplate) -> ((usize, usize, usize), Txid) {
+    let txid = self.rpc_server.broadcast_tx(template);
+    self.mine(1);
+    ((self.height, 1, 0), txid)
+  }
+
+  fn wallet(&self, name: &str) -> Address {
+    self
+      .wallets
+      .get(name)
+      .unwrap_or_else(|| panic!("{}: unknown wallet {name}", self.scenario))
+      .clone()
+  }
+
+  fn pool(&self, name: &str) -> &DeployedPool {
+    self
+      .pools
+      .get(name)
+      .unwrap_or_else(|| panic!("{}: unknown pool {name}", self.scenario))
+  }
+
+  /// Returns an output held by the wallet, splitting a new coinbase when it runs out.
+  fn funding(&mut self, wallet: &str) -> (usize, usize, usize) {
+    if self.funding.get(wallet).map_or(true, VecDeque::is_empty) {
+      self.mine(1);
+      let coinbase = self.height;
+      let ((height, tx, _), _) = self.send(TransactionTemplate {
+        inputs: &[(coinbase, 0, 0, Default::default())],
+        outputs: FUNDING_OUTPUTS,
+        recipient: Some(self.wallet(wallet)),
+        ..Default::default()
+      });
+      self.funding.insert(
+        wallet.into(),
+        (0..FUNDING_OUTPUTS)
+          .map(|vout| (height, tx, vout))
+          .collect(),
+      );
+    }
+
+    self.funding.get_mut(wallet).unwrap().pop_front().unwrap()
+  }
+
+  /// Commits from and reveals to the wallet, so BRC20-S sees the wallet on both sides.
+  fn inscribe(&mut self, wallet: &str, body: &str) -> ((usize, usize, usize), Txid) {
+    let funding = self.funding(wallet);
+    let (commit, _) = self.send(TransactionTemplate {
+      inputs: &[(funding.0, funding.1, funding.2, Default::default())],
+      recipient: Some(self.wallet(wallet)),
+      ..Default::default()
+    });
+    self.send(TransactionTemplate {
+      inputs: &[(
+        commit.0,
+        commit.1,
+        commit.2,
+        inscription("text/plain;charset=utf-8", body).to_witness(),
+      )],
+      recipient: Some(self.wallet(wallet)),
+      ..Default::default()
+    })
+  }
+
+  /// Asserts the receipt of the transaction succeeded, or failed with `error`.
+  fn check_receipt(&self, step: usize, protocol: Protocol, txid: Txid, error: Option<&str>) {
+    let results = match protocol {
+      Protocol::BRC20 => self
+        .index
+        .brc20_get_tx_events_by_txid(&txid)
+        .unwrap()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|receipt| receipt.result.map(|_| ()).map_err(|err| err.to_string()))
+        .collect::<Vec<_>>(),
+      Protocol::BRC20S => self
+        .index
+        .brc20s_txid_receipts(&txid)
+        .unwrap()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|receipt| receipt.result.map(|_| ()).map_err(|err| err.to_string()))
+        .collect::<Vec<_>>(),
+    };
+
+    assert_eq!(
+      results,
+      [error.map_or(Ok(()), |error| Err(error.to_string()))],
+      "{}: step {step}: unexpected {protocol:?} receipts of {txid}",
+      self.scenario
+    );
+  }
+
+  fn run_step(&mut self, i: usize, step: &Step) {
+    match step {
+      Step::Mine(n) => self.mine(*n),
+      Step::Inscribe {
+        wallet,
+        body,
+        error,
+      } => {
+        let protocol = match body["p"].as_str() {
+          Some("brc20-s") => Protocol::BRC20S,
+          _ => Protocol::BRC20,
+        };
+        let (_, txid) = self.inscribe(wallet, &body.to_string());
+        self.check_receipt(i, protocol, txid, error.as_deref());
+      }
+      Step::Deploy {
+        wallet,
+        tick,
+        max,
+        lim,
+        dec,
+        error,
+      } => {
+        let mut body = serde_json::json!({"p": "brc-20", "op": "deploy", "tick": tick, "max": max});
+        if let Some(lim) = lim {
+          body["lim"] = lim.as_str().into();
+        }
+        if let Some(dec) = dec {
+          body["dec"] = dec.as_str().into();
+        }
+        let (_, txid) = self.inscribe(wallet, &body.to_string());
+        self.check_receipt(i, Protocol::BRC20, txid, error.as_deref());
+      }
+      Step::Mint {
+        wallet,
+        tick,
+        amt,
+        error,
+      } => {
+        let body = serde_json::json!({"p": "brc-20", "op": "mint", "tick": tick, "amt": amt});
+        let (_, txid) = self.inscribe(wallet, &body.to_string());
+        self.check_receipt(i, Protocol::BRC20, txid, error.as_deref());
+      }
+      Step::Transfer {
+        from,
+        to,
+        tick,
+        amt,
+        error,
+      } => {
+        let body = serde_json::json!({"p": "brc-20", "op": "transfer", "tick": tick, "amt": amt});
+        let (transfer, txid) = self.inscribe(from, &body.to_string());
+        self.check_receipt(i, Protocol::BRC20, txid, error.as_deref());
+        if error.is_some() {
+          return;
+        }
+
+        let (_, txid) = self.send(TransactionTemplate {
+          inputs: &[(transfer.0, transfer.1, transfer.2, Default::default())],
+          recipient: Some(self.wallet(to)),
+          ..Default::default()
+        });
+        self.check_receipt(i, Protocol::BRC20, txid, None);
+      }
+      Step::DeployPool {
+        wallet,
+        name,
+        pool_type,
+        number,
+        stake,
+        earn,
+        erate,
+        dmax,
+        total,
+        dec,
+        only,
+        error,
+      } => {
+        let dec = dec.unwrap_or(18);
+        let script_key = ScriptKey::from_address(self.wallet(wallet));
+        let tick_id = caculate_tick_id(earn, total.parse().unwrap(), dec, &script_key, &script_key);
+        let pid = format!("{}#{number}", tick_id.hex());
+
+        let mut body = serde_json::json!({
+          "p": "brc20-s",
+          "op": "deploy",
+          "t": pool_type,
+          "pid": pid,
+          "stake": stake,
+          "earn": earn,
+          "erate": erate,
+          "dmax": dmax,
+          "total": total,
+          "dec": dec.to_string(),
+        });
+        if *only {
+          body["only"] = "1".into();
+        }
+        let (_, txid) = self.inscribe(wallet, &body.to_string());
+        self.check_receipt(i, Protocol::BRC20S, txid, error.as_deref());
+
+        self.pools.insert(
+          name.clone(),
+          DeployedPool {
+            pid,
+            tick_id,
+            earn: earn.clone(),
+          },
+        );
+      }
+      Step::Deposit {
+        wallet,
+        pool,
+        amt,
+        error,
+      } => {
+        let body = serde_json::json!({
+          "p": "brc20-s",
+          "op": "deposit",
+          "pid": self.pool(pool).pid,
+          "amt": amt,
+        });
+        let (_, txid) = self.inscribe(wallet, &body.to_string());
+        self.check_receipt(i, Protocol::BRC20S, txid, error.as_deref());
+      }
+      Step::Withdraw {
+        wallet,
+        pool,
+        amt,
+        error,
+      } => {
+        let body = serde_json::json!({
+          "p": "brc20-s",
+          "op": "withdraw",
+          "pid": self.pool(pool).pid,
+          "amt": amt,
+        });
+        let (_, txid) = self.inscribe(wallet, &body.to_string());
+        self.check_receipt(i, Protocol::BRC20S, txid, error.as_deref());
+      }
+      Step::MintPool {
+        wallet,
+        pool,
+        amt,
+        error,
+      } => {
+        let pool = self.pool(pool);
+        let body = serde_json::json!({
+          "p": "brc20-s",
+          "op": "mint",
+          "tick": pool.earn,
+          "pid": pool.pid,
+          "amt": amt,
+        });
+        let (_, txid) = self.inscribe(wallet, &body.to_string());
+        self.check_receipt(i, Protocol::BRC20S, txid, error.as_deref());
+      }
+    }
+  }
+
+  fn pid(&self, pool: &str) -> Pid {
+    Pid::from_str(&self.pool(pool).pid).unwrap()
+  }
+
+  fn earn_decimal(&self, pool: &str) -> u8 {
+    self
+      .index
+      .brc20s_tick_info(&self.pool(pool).tick_id)
+      .unwrap()
+      .unwrap()
+      .decimal
+  }
+
+  fn stake_decimal(&self, stake: &PledgedTick) -> u8 {
+    match stake {
+      PledgedTick::Native => NATIVE_TOKEN_DECIMAL,
+      PledgedTick::BRC20Tick(tick) => {
+        self
+          .index
+          .brc20_get_tick_info(tick)
+          .unwrap()
+          .unwrap()
+          .decimal
+      }
+      PledgedTick::BRC20STick(tick_id) => {
+        self
+          .index
+          .brc20s_tick_info(tick_id)
+          .unwrap()
+          .unwrap()
+          .decimal
+      }
+      PledgedTick::Unknown => 0,
+    }
+  }
+
+  fn check(&self, expect: &Expect) {
+    assert_eq!(
+      self.index.check().unwrap().violations,
+      [],
+      "{}: invariant violations",
+      self.scenario
+    );
+
+    for expected in &expect.balances {
+      let tick = expected.tick.parse().unwrap();
+      let decimal = self
+        .index
+        .brc20_get_tick_info(&tick)
+        .unwrap()
+        .map_or(0, |info| info.decimal);
+      let balance = self
+        .index
+        .brc20_get_balance_by_address(&tick, &self.wallet(&expected.wallet))
+        .unwrap();
+      assert_eq!(
+        (
+          balance
+            .as_ref()
+            .map_or(0, |balance| balance.overall_balance),
+          balance.map_or(0, |balance| balance.transferable_balance),
+        ),
+        (
+          amount(&expected.overall, decimal),
+          amount(&expected.transferable, decimal)
+        ),
+        "{}: balance of {} for {}",
+        self.scenario,
+        expected.tick,
+        expected.wallet
+      );
+    }
+
+    for expected in &expect.pools {
+      let pid = self.pid(&expected.pool);
+      let pool = self
+        .index
+        .brc20s_pool_info(&pid)
+        .unwrap()
+        .unwrap_or_else(|| panic!("{}: pool {} not deployed", self.scenario, expected.pool));
+      assert_eq!(
+        (pool.staked, pool.minted),
+        (
+          amount(&expected.staked, self.stake_decimal(&pool.stake)),
+          amount(&expected.minted, self.earn_decimal(&expected.pool))
+        ),
+        "{}: pool {}",
+        self.scenario,
+        expected.pool
+      );
+    }
+
+    for expected in &expect.users {
+      let pid = self.pid(&expected.pool);
+      let pool = self.index.brc20s_pool_info(&pid).unwrap().unwrap();
+      let user = self
+        .index
+        .brc20s_user_info(&pid, &self.wallet(&expected.wallet))
+        .unwrap();
+      assert_eq!(
+        (
+          user.as_ref().map_or(0, |user| user.staked),
+          user.map_or(0, |user| user.minted),
+        ),
+        (
+          amount(&expected.staked, self.stake_decimal(&pool.stake)),
+          amount(&expected.minted, self.earn_decimal(&expected.pool))
+        ),
+        "{}: user {} of pool {}",
+        self.scenario,
+        expected.wallet,
+        expected.pool
+      );
+    }
+
+    for expected in &expect.earned {
+      let balance = self
+        .index
+        .brc20s_balance(
+          &self.pool(&expected.pool).tick_id,
+          &self.wallet(&expected.wallet),
+        )
+        .unwrap();
+      assert_eq!(
+        balance.map_or(0, |balance| balance.overall_balance),
+        amount(&expected.overall, self.earn_decimal(&expected.pool)),
+        "{}: earned of pool {} for {}",
+        self.scenario,
+        expected.pool,
+        expected.wallet
+      );
+    }
+  }
+}
+
+/// Converts a decimal amount like `1.5` into its integer representation with `decimal` places.
+fn amount(value: &str, decimal: u8) -> u128 {
+  let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+  let decimal = usize::from(decimal);
+  assert!(
+    fraction.len() <= decimal,
+    "{value} has more than {decimal} decimals"
+  );
+  format!("{integer}{fraction:0<decimal$}").parse().unwrap()
+}
+
+fn run(path: &Path) {
+  let content = fs::read_to_string(path).unwrap();
+  let scenario: Scenario = match path.extension().and_then(|extension| extension.to_str()) {
+    Some("json") => serde_json::from_str(&content).map_err(anyhow::Error::from),
+    // serde_yaml expects enum variants as tags, so go through JSON values for `- mint: {..}`.
+    _ => serde_yaml::from_str::<serde_json::Value>(&content)
+      .map_err(anyhow::Error::from)
+      .and_then(|value| serde_json::from_value(value).map_err(anyhow::Error::from)),
+  }
+  .unwrap_or_else(|err| panic!("{}: invalid scenario: {err}", path.display()));
+
+  let mut runner = Runner::new(path, &scenario.wallets);
+  for (i, step) in scenario.steps.iter().enumerate() {
+    runner.run_step(i, step);
+  }
+  runner.check(&scenario.expect);
+}
+
+#[test]
+fn scenarios() {
+  let mut paths =
+    fs::read_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/okx/protocol/scenarios"))
+      .unwrap()
+      .map(|entry| entry.unwrap().path())
+      .filter(|path| {
+        matches!(
+          path.extension().and_then(|extension| extension.to_str()),
+          Some("yaml" | "yml" | "json")
+        )
+      })
+      .collect::<Vec<_>>();
+  paths.sort();
+
+  assert!(!paths.is_empty());
+
+  for path in paths {
+    run(&path);
+  }
+}
+
+#[test]
+fn amounts() {
+  assert_eq!(amount("1", 0), 1);
+  assert_eq!(amount("1.5", 2), 150);
+  assert_eq!(amount("0.000000000000000001", 18), 1);
+  assert_eq!(amount("1000", 18), 1000 * 10u128.pow(18));
+}
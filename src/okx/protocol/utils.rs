@@ -3,7 +3,7 @@ use crate::{
   InscriptionId, Result, SatPoint,
 };
 use anyhow::anyhow;
-use bitcoin::Network;
+use bitcoin::{Network, TxOut};
 
 pub(super) fn get_script_key_on_satpoint<O: DataStoreReadOnly>(
   satpoint: SatPoint,
@@ -11,18 +11,24 @@ pub(super) fn get_script_key_on_satpoint<O: DataStoreReadOnly>(
   network: Network,
 ) -> Result<ScriptKey> {
   Ok(ScriptKey::from_script(
-    &ord_store
-      .get_outpoint_to_txout(satpoint.outpoint)
-      .map_err(|e| anyhow!("failed to get tx out from state! error: {e}",))?
-      .ok_or(anyhow!(
-        "failed to get tx out! error: outpoint {} not found",
-        satpoint.outpoint
-      ))?
-      .script_pubkey,
+    &get_tx_out_on_satpoint(satpoint, ord_store)?.script_pubkey,
     network,
   ))
 }
 
+pub(super) fn get_tx_out_on_satpoint<O: DataStoreReadOnly>(
+  satpoint: SatPoint,
+  ord_store: &O,
+) -> Result<TxOut> {
+  ord_store
+    .get_outpoint_to_txout(satpoint.outpoint)
+    .map_err(|e| anyhow!("failed to get tx out from state! error: {e}",))?
+    .ok_or(anyhow!(
+      "failed to get tx out! error: outpoint {} not found",
+      satpoint.outpoint
+    ))
+}
+
 pub(super) fn get_inscription_number_by_id<O: DataStoreReadOnly>(
   inscription_id: InscriptionId,
   ord_store: &O,
@@ -213,6 +213,107 @@ pub fn update_user_stake(
   Ok(())
 }
 
+/// The rewards of a user after a hypothetical deposit, under the pool's staked total.
+#[derive(Debug, PartialEq)]
+pub struct RewardProjection {
+  /// The reward of the user in each block after the deposit block.
+  pub rewards: Vec<u128>,
+  /// The number of blocks after the deposit block until the pool has minted `dmax`, if it ever does.
+  pub blocks_until_dmax: Option<u64>,
+  /// The average reward per block for each whole staked token, which is the erate for fixed pools.
+  pub effective_rate: u128,
+}
+
+// simulates a deposit at block_num and the following blocks, do not save pool and user info
+pub fn project_reward(
+  user: UserInfo,
+  pool: PoolInfo,
+  block_num: u64,
+  deposit: u128,
+  blocks: u64,
+  staked_decimal: u8,
+) -> Result<RewardProjection, BRC20SError> {
+  let mut user_temp = user;
+  let mut pool_temp = pool;
+
+  // deposit the same way as the stake execution.
+  update_pool(&mut pool_temp, block_num, staked_decimal)?;
+  if user_temp.staked > 0 {
+    withdraw_user_reward(&mut user_temp, &pool_temp, staked_decimal)?;
+  }
+  user_temp.staked = Into::<Num>::into(user_temp.staked)
+    .checked_add(&deposit.into())?
+    .checked_to_u128()?;
+  update_user_stake(&mut user_temp, &pool_temp, staked_decimal)?;
+  pool_temp.staked = Into::<Num>::into(pool_temp.staked)
+    .checked_add(&deposit.into())?
+    .checked_to_u128()?;
+
+  let blocks_until_dmax = blocks_until_dmax(&pool_temp, staked_decimal)?;
+
+  let mut rewards = Vec::new();
+  let mut total_reward = 0;
+  for block in 1..=blocks {
+    update_pool(&mut pool_temp, block_num + block, staked_decimal)?;
+    let reward = if user_temp.staked > 0 {
+      withdraw_user_reward(&mut user_temp.clone(), &pool_temp, staked_decimal)?
+    } else {
+      0
+    };
+    rewards.push(reward.saturating_sub(total_reward));
+    total_reward = reward;
+  }
+
+  let effective_rate = if user_temp.staked > 0 && blocks > 0 {
+    Into::<Num>::into(total_reward)
+      .checked_mul(&get_num_by_decimal(staked_decimal)?)?
+      .checked_div(&user_temp.staked.into())?
+      .checked_div(&blocks.into())?
+      .truncate_to_u128()?
+  } else {
+    0
+  };
+
+  Ok(RewardProjection {
+    rewards,
+    blocks_until_dmax,
+    effective_rate,
+  })
+}
+
+// the pool mints the same amount each block, so search the first block that mints up to dmax
+fn blocks_until_dmax(pool: &PoolInfo, staked_decimal: u8) -> Result<Option<u64>, BRC20SError> {
+  let exhausted = |blocks: u64| -> Result<bool, BRC20SError> {
+    let mut pool_temp = pool.clone();
+    update_pool(
+      &mut pool_temp,
+      pool.last_update_block + blocks,
+      staked_decimal,
+    )?;
+    Ok(pool_temp.minted >= pool_temp.dmax)
+  };
+
+  if pool.minted >= pool.dmax {
+    return Ok(Some(0));
+  }
+
+  let (mut low, mut high) = (0, u64::from(u32::MAX));
+  if pool.last_update_block.checked_add(high).is_none() || !exhausted(high)? {
+    return Ok(None);
+  }
+
+  while high - low > 1 {
+    let middle = low + (high - low) / 2;
+    if exhausted(middle)? {
+      high = middle;
+    } else {
+      low = middle;
+    }
+  }
+
+  Ok(Some(high))
+}
+
 fn get_per_share_multiplier() -> Num {
   get_num_by_decimal(PER_SHARE_MULTIPLIER).unwrap()
 }
@@ -3609,6 +3710,70 @@ mod tests {
     assert_eq!(pool.last_update_block, block_mum);
   }
 
+  #[test]
+  fn test_project_reward_fixed() {
+    const STAKED_DECIMAL: u8 = 3;
+    let stake_base = get_base_decimal(STAKED_DECIMAL);
+
+    let pid = Pid::from_str("Bca1DaBca1D#1").unwrap();
+    let mut pool = new_pool(&pid, PoolType::Fixed, 10, 1000);
+    pool.staked = 2 * stake_base;
+    pool.last_update_block = 5;
+    let mut user = new_user(&pid);
+    user.staked = 2 * stake_base;
+    update_user_stake(&mut user, &pool, STAKED_DECIMAL).unwrap();
+
+    // 2 staked tokens mint 200 up to the deposit block, then 3 staked tokens mint 30 each block.
+    let projection = project_reward(
+      user.clone(),
+      pool.clone(),
+      15,
+      stake_base,
+      3,
+      STAKED_DECIMAL,
+    )
+    .unwrap();
+    assert_eq!(projection.rewards, [30, 30, 30]);
+    assert_eq!(projection.blocks_until_dmax, Some(27));
+    assert_eq!(projection.effective_rate, 10);
+
+    // the last block mints what is left of dmax, truncated to the reward of the staked tokens.
+    let projection = project_reward(user, pool, 15, stake_base, 29, STAKED_DECIMAL).unwrap();
+    assert_eq!(projection.rewards[25..], [30, 19, 0, 0]);
+    assert_eq!(projection.rewards.iter().sum::<u128>(), 799);
+  }
+
+  #[test]
+  fn test_project_reward_pool() {
+    const STAKED_DECIMAL: u8 = 3;
+
+    let pid = Pid::from_str("Bca1DaBca1D#1").unwrap();
+    let mut pool = new_pool(&pid, PoolType::Pool, 100, 2000);
+    pool.staked = 300;
+    pool.last_update_block = 5;
+    let mut user = new_user(&pid);
+    user.staked = 100;
+    update_user_stake(&mut user, &pool, STAKED_DECIMAL).unwrap();
+
+    // 1000 is minted up to the deposit block, then doubling the stake earns half of the 100 minted each block.
+    let projection =
+      project_reward(user.clone(), pool.clone(), 15, 100, 2, STAKED_DECIMAL).unwrap();
+    assert_eq!(projection.rewards, [50, 50]);
+    assert_eq!(projection.blocks_until_dmax, Some(10));
+    assert_eq!(projection.effective_rate, 250);
+
+    // a new user without a deposit earns nothing.
+    let projection =
+      project_reward(new_user(&pid), pool.clone(), 15, 0, 2, STAKED_DECIMAL).unwrap();
+    assert_eq!(projection.rewards, [0, 0]);
+    assert_eq!(projection.effective_rate, 0);
+
+    // nothing is minted without stakes.
+    pool.staked = 0;
+    let projection = project_reward(new_user(&pid), pool, 15, 0, 2, STAKED_DECIMAL).unwrap();
+    assert_eq!(projection.blocks_until_dmax, None);
+  }
+
   fn new_pool(pid: &Pid, pool_type: PoolType, erate: u128, dmax: u128) -> PoolInfo {
     PoolInfo {
       pid: pid.clone(),
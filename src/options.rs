@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::str::FromStr;
-use {super::*, bitcoincore_rpc::Auth};
+use {super::*, crate::okx::datastore::ord::names::Namespace, bitcoincore_rpc::Auth};
 
 #[derive(Clone, Default, Debug, Parser)]
 #[command(group(
@@ -72,6 +72,16 @@ pub(crate) struct Options {
     help = "Don't look for BRC20 messages below <FIRST_BRC20_HEIGHT>."
   )]
   pub(crate) first_brc20_height: Option<u64>,
+  #[arg(
+    long,
+    help = "Don't accept five-byte BRC20 ticks deployed with `self_mint` below <FIRST_BRC20_SELF_MINT_HEIGHT>."
+  )]
+  pub(crate) first_brc20_self_mint_height: Option<u64>,
+  #[arg(
+    long,
+    help = "Don't burn BRC20 transfers sent to OP_RETURN outputs below <FIRST_BRC20_BURN_HEIGHT>."
+  )]
+  pub(crate) first_brc20_burn_height: Option<u64>,
   #[arg(long, help = "Enable Index all of BRC20S Protocol")]
   pub(crate) enable_index_brc20s: bool,
   #[arg(
@@ -81,8 +91,62 @@ pub(crate) struct Options {
   pub(crate) first_brc20s_height: Option<u64>,
   #[arg(long, help = "Enable Save Ord Receipts.")]
   pub(crate) enable_save_ord_receipts: bool,
-  #[arg(long, help = "Enable Index Bitmap Collection.")]
+  #[arg(
+    long,
+    help = "Enable Index Bitmap Collection. Parcels are checked against the transaction count of their district's block, so blocks below the first inscription height are fetched in full, and it can only be set when the index is created."
+  )]
   pub(crate) enable_index_bitmap: bool,
+  #[arg(
+    long,
+    value_delimiter = ',',
+    help = "Index first-is-first names in the comma separated <INDEX_NAMES> namespaces, each written `<NAME>:<CHARSET>:<MAX_LENGTH>` with the charset `any` or `hostname`, like `sats:any:64,btc:hostname:63`. The namespaces can't be changed without rebuilding the index."
+  )]
+  pub(crate) index_names: Vec<Namespace>,
+  #[arg(
+    long,
+    help = "Read blocks from the blk*.dat files in <BITCOIN_DATA_DIR> instead of Bitcoin Core RPC."
+  )]
+  pub(crate) index_from_block_files: bool,
+  #[arg(
+    long,
+    help = "Fetch the last <BLOCK_FILES_TIP_DISTANCE> blocks over RPC when reading block files. [default: 100]"
+  )]
+  pub(crate) block_files_tip_distance: Option<u64>,
+  #[arg(
+    long,
+    help = "Fetch and decode blocks with <BLOCK_FETCH_WORKERS> threads. [default: 4]"
+  )]
+  pub(crate) block_fetch_workers: Option<usize>,
+  #[arg(
+    long,
+    help = "Keep the inputs of the transactions of the last <TRANSACTION_CACHE_BLOCKS> blocks to resolve BRC20S commit transactions. [default: 144]"
+  )]
+  pub(crate) transaction_cache_blocks: Option<u64>,
+  #[arg(
+    long,
+    help = "Don't fetch previous outputs or BRC20S commit transactions from Bitcoin Core, so it doesn't need -txindex. Every block is fetched in full and its outputs are indexed from genesis, and transaction inputs are kept from <TRANSACTION_CACHE_BLOCKS> blocks before the first BRC20S height. Indexing stops with an error if a BRC20S commit transaction is older than that. Can only be set when the index is created."
+  )]
+  pub(crate) no_txindex: bool,
+  #[arg(
+    long,
+    help = "Commit the index after <COMMIT_INTERVAL> blocks. [default: 200]"
+  )]
+  pub(crate) commit_interval: Option<u64>,
+  #[arg(
+    long,
+    help = "Commit the index after indexing for <COMMIT_INTERVAL_SECS> seconds without a commit."
+  )]
+  pub(crate) commit_interval_secs: Option<u64>,
+  #[arg(
+    long,
+    help = "Commit the index once an estimated <COMMIT_DIRTY_BYTES> bytes are uncommitted. Pass 0 to disable. [default: 1073741824]"
+  )]
+  pub(crate) commit_dirty_bytes: Option<u64>,
+  #[arg(
+    long,
+    help = "Commit the index after every block within <COMMIT_TIP_DISTANCE> blocks of the chain tip. [default: 6]"
+  )]
+  pub(crate) commit_tip_distance: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -144,6 +208,30 @@ impl Options {
     }
   }
 
+  pub(crate) fn first_brc20_self_mint_height(&self) -> u64 {
+    if self.chain() == Chain::Regtest {
+      self.first_brc20_self_mint_height.unwrap_or(0)
+    } else if integration_test() {
+      0
+    } else {
+      self
+        .first_brc20_self_mint_height
+        .unwrap_or_else(|| self.chain().first_brc20_self_mint_height())
+    }
+  }
+
+  pub(crate) fn first_brc20_burn_height(&self) -> u64 {
+    if self.chain() == Chain::Regtest {
+      self.first_brc20_burn_height.unwrap_or(0)
+    } else if integration_test() {
+      0
+    } else {
+      self
+        .first_brc20_burn_height
+        .unwrap_or_else(|| self.chain().first_brc20_burn_height())
+    }
+  }
+
   pub(crate) fn first_brc20s_height(&self) -> u64 {
     if self.chain() == Chain::Regtest {
       self.first_brc20s_height.unwrap_or(0)
@@ -173,6 +261,41 @@ impl Options {
       return Ok(cookie_file.clone());
     }
 
+    Ok(self.bitcoin_data_dir()?.join(".cookie"))
+  }
+
+  pub(crate) fn bitcoin_blocks_dir(&self) -> Result<PathBuf> {
+    Ok(self.bitcoin_data_dir()?.join("blocks"))
+  }
+
+  pub(crate) fn block_files_tip_distance(&self) -> u64 {
+    self.block_files_tip_distance.unwrap_or(100)
+  }
+
+  pub(crate) fn block_fetch_workers(&self) -> usize {
+    self.block_fetch_workers.unwrap_or(4).max(1)
+  }
+
+  pub(crate) fn transaction_cache_blocks(&self) -> u64 {
+    self.transaction_cache_blocks.unwrap_or(144)
+  }
+
+  pub(crate) fn commit_interval(&self) -> u64 {
+    self.commit_interval.unwrap_or(200).max(1)
+  }
+
+  pub(crate) fn commit_dirty_bytes(&self) -> Option<u64> {
+    match self.commit_dirty_bytes.unwrap_or(1 << 30) {
+      0 => None,
+      bytes => Some(bytes),
+    }
+  }
+
+  pub(crate) fn commit_tip_distance(&self) -> u64 {
+    self.commit_tip_distance.unwrap_or(6)
+  }
+
+  fn bitcoin_data_dir(&self) -> Result<PathBuf> {
     let path = if let Some(bitcoin_data_dir) = &self.bitcoin_data_dir {
       bitcoin_data_dir.clone()
     } else if cfg!(target_os = "linux") {
@@ -185,9 +308,7 @@ impl Options {
         .join("Bitcoin")
     };
 
-    let path = self.chain().join_with_data_dir(&path);
-
-    Ok(path.join(".cookie"))
+    Ok(self.chain().join_with_data_dir(&path))
   }
 
   pub(crate) fn data_dir(&self) -> Result<PathBuf> {
@@ -467,6 +588,35 @@ mod tests {
     }));
   }
 
+  #[test]
+  fn block_files_are_read_from_bitcoin_data_dir() {
+    let arguments = Arguments::try_parse_from([
+      "ord",
+      "--bitcoin-data-dir=foo",
+      "--chain=signet",
+      "--index-from-block-files",
+      "index",
+      "run",
+    ])
+    .unwrap();
+
+    assert!(arguments.options.index_from_block_files);
+    assert_eq!(arguments.options.block_files_tip_distance(), 100);
+
+    let blocks_dir = arguments
+      .options
+      .bitcoin_blocks_dir()
+      .unwrap()
+      .display()
+      .to_string();
+
+    assert!(blocks_dir.ends_with(if cfg!(windows) {
+      r"foo\signet\blocks"
+    } else {
+      "foo/signet/blocks"
+    }));
+  }
+
   #[test]
   fn cookie_file_defaults_to_bitcoin_data_dir() {
     let arguments = Arguments::try_parse_from([
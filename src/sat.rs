@@ -52,6 +52,13 @@ impl Sat {
     self.into()
   }
 
+  pub(crate) fn satributes(self) -> Vec<Satribute> {
+    Satribute::ALL
+      .into_iter()
+      .filter(|satribute| satribute.applies(self))
+      .collect()
+  }
+
   /// `Sat::rarity` is expensive and is called frequently when indexing.
   /// Sat::is_common only checks if self is `Rarity::Common` but is
   /// much faster.
@@ -0,0 +1,198 @@
+use super::*;
+
+/// The txid of Laszlo's 10,000 bitcoin pizza transaction, whose input sats are pizza sats.
+pub(crate) const PIZZA_TXID: &str =
+  "a1075db55d416d3ca199f55b6084e2115b9345e16c5cf302fc80e9d5fbf5d48d";
+
+/// The last block whose nonce follows the pattern of Satoshi's mining software.
+const NAKAMOTO_LAST_HEIGHT: u64 = 54_316;
+
+/// Whether the mainnet block was mined by Satoshi, going by the "Patoshi pattern": the least significant
+/// byte of the nonce of the blocks they mined lies in 0-9 or 19-58. A few blocks of other miners of
+/// the period match it by chance.
+pub(crate) fn is_nakamoto_block(height: Height, nonce: u32) -> bool {
+  height.n() <= NAKAMOTO_LAST_HEIGHT && matches!(nonce & 0xff, 0..=9 | 19..=58)
+}
+
+/// Collectible sat attributes beyond `Rarity`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Satribute {
+  /// The first sat of a bitcoin.
+  Alpha,
+  /// The last sat of a bitcoin.
+  Omega,
+  /// The last sat of a block.
+  Black,
+  /// A sat whose number reads the same backwards.
+  Palindrome,
+  /// A sat mined in block 9.
+  Block9,
+  /// A sat mined in the first 1000 blocks.
+  Vintage,
+  /// A sat mined by Satoshi, only known to an index with `--index-sats`.
+  Nakamoto,
+  /// A sat spent in the pizza transaction, only known to an index with `--index-sats`.
+  Pizza,
+}
+
+impl Satribute {
+  pub const ALL: [Self; 8] = [
+    Self::Alpha,
+    Self::Omega,
+    Self::Black,
+    Self::Palindrome,
+    Self::Block9,
+    Self::Vintage,
+    Self::Nakamoto,
+    Self::Pizza,
+  ];
+
+  /// Whether the sat has the satribute, `Nakamoto` and `Pizza` need the index and are never
+  /// reported here.
+  pub(crate) fn applies(self, sat: Sat) -> bool {
+    match self {
+      Self::Alpha => sat.n() % COIN_VALUE == 0,
+      Self::Omega => sat.n() % COIN_VALUE == COIN_VALUE - 1,
+      Self::Black => sat.third() == sat.epoch().subsidy() - 1,
+      Self::Palindrome => {
+        let digits = sat.n().to_string();
+        digits.bytes().eq(digits.bytes().rev())
+      }
+      Self::Block9 => sat.height() == 9,
+      Self::Vintage => sat.height().n() < 1000,
+      Self::Nakamoto | Self::Pizza => false,
+    }
+  }
+}
+
+impl Display for Satribute {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Self::Alpha => "alpha",
+        Self::Omega => "omega",
+        Self::Black => "black",
+        Self::Palindrome => "palindrome",
+        Self::Block9 => "block_9",
+        Self::Vintage => "vintage",
+        Self::Nakamoto => "nakamoto",
+        Self::Pizza => "pizza",
+      }
+    )
+  }
+}
+
+impl FromStr for Satribute {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "alpha" => Ok(Self::Alpha),
+      "omega" => Ok(Self::Omega),
+      "black" => Ok(Self::Black),
+      "palindrome" => Ok(Self::Palindrome),
+      "block_9" => Ok(Self::Block9),
+      "vintage" => Ok(Self::Vintage),
+      "nakamoto" => Ok(Self::Nakamoto),
+      "pizza" => Ok(Self::Pizza),
+      _ => Err(anyhow!("invalid satribute: {s}")),
+    }
+  }
+}
+
+impl Serialize for Satribute {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de> Deserialize<'de> for Satribute {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    Ok(DeserializeFromStr::deserialize(deserializer)?.0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn satributes() {
+    assert_eq!(
+      Sat(0).satributes(),
+      [Satribute::Alpha, Satribute::Palindrome, Satribute::Vintage]
+    );
+    assert_eq!(
+      Sat(COIN_VALUE - 1).satributes(),
+      [Satribute::Omega, Satribute::Palindrome, Satribute::Vintage]
+    );
+    assert_eq!(Sat(12345).satributes(), [Satribute::Vintage]);
+    assert_eq!(
+      Sat(50 * COIN_VALUE - 1).satributes(),
+      [Satribute::Omega, Satribute::Black, Satribute::Vintage]
+    );
+    assert_eq!(
+      Sat(9 * 50 * COIN_VALUE + 1).satributes(),
+      [Satribute::Block9, Satribute::Vintage]
+    );
+    assert_eq!(
+      Sat(1000 * 50 * COIN_VALUE + 1).satributes(),
+      Vec::<Satribute>::new()
+    );
+    assert_eq!(Sat(5000000000005).satributes(), [Satribute::Palindrome]);
+  }
+
+  #[test]
+  fn black_sats_follow_the_subsidy() {
+    let height = Height(SUBSIDY_HALVING_INTERVAL);
+    let last = height.starting_sat() + (height.subsidy() - 1);
+    assert!(last.satributes().contains(&Satribute::Black));
+    assert!(!(last + 1).satributes().contains(&Satribute::Black));
+    assert!(Sat::LAST.satributes().contains(&Satribute::Black));
+  }
+
+  #[test]
+  fn from_str_and_deserialize_ok() {
+    for satribute in Satribute::ALL {
+      assert_eq!(
+        satribute.to_string().parse::<Satribute>().unwrap(),
+        satribute
+      );
+      assert_eq!(
+        serde_json::from_str::<Satribute>(&serde_json::to_string(&satribute).unwrap()).unwrap(),
+        satribute
+      );
+    }
+    assert_eq!(
+      serde_json::to_string(&Satribute::Block9).unwrap(),
+      "\"block_9\""
+    );
+  }
+
+  #[test]
+  fn from_str_err() {
+    "block9".parse::<Satribute>().unwrap_err();
+    "satoshi".parse::<Satribute>().unwrap_err();
+  }
+
+  #[test]
+  fn nakamoto_blocks_follow_the_nonce_pattern() {
+    assert!(is_nakamoto_block(Height(0), 2083236893));
+    assert!(is_nakamoto_block(Height(1), 0x1234_5600));
+    assert!(is_nakamoto_block(Height(1), 0x1234_5609));
+    assert!(!is_nakamoto_block(Height(1), 0x1234_560a));
+    assert!(!is_nakamoto_block(Height(1), 0x1234_5612));
+    assert!(is_nakamoto_block(Height(1), 0x1234_5613));
+    assert!(is_nakamoto_block(Height(NAKAMOTO_LAST_HEIGHT), 0x1234_563a));
+    assert!(!is_nakamoto_block(Height(1), 0x1234_563b));
+    assert!(!is_nakamoto_block(Height(NAKAMOTO_LAST_HEIGHT + 1), 0));
+  }
+}
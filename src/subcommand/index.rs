@@ -1,9 +1,30 @@
-use super::*;
+use {
+  super::*,
+  crate::index::{BRC20ExportKind, BRC20SExportKind, ExportFormat},
+  clap::ValueEnum,
+};
 
 #[derive(Debug, Parser)]
 pub(crate) enum IndexSubcommand {
   #[command(about = "Write inscription numbers and ids to a tab-separated file")]
   Export(Export),
+  #[command(
+    name = "export-brc20",
+    about = "Write BRC20 ledger tables to a CSV or JSON lines file"
+  )]
+  ExportBRC20(ExportBRC20),
+  #[command(
+    name = "export-brc20s",
+    about = "Write BRC20S ledger tables to a CSV or JSON lines file"
+  )]
+  ExportBRC20S(ExportBRC20S),
+  #[command(about = "Check the BRC20 and BRC20S tables for inconsistencies")]
+  Check,
+  #[command(
+    name = "diff-brc20",
+    about = "Compare BRC20 balances and events with a reference JSON lines file"
+  )]
+  DiffBRC20(DiffBRC20),
   #[command(about = "Update the index")]
   Run,
 }
@@ -12,6 +33,10 @@ impl IndexSubcommand {
   pub(crate) fn run(self, options: Options) -> SubcommandResult {
     match self {
       Self::Export(export) => export.run(options),
+      Self::ExportBRC20(export) => export.run(options),
+      Self::ExportBRC20S(export) => export.run(options),
+      Self::Check => check(options),
+      Self::DiffBRC20(diff) => diff.run(options),
       Self::Run => index::run(options),
     }
   }
@@ -40,6 +65,111 @@ impl Export {
   }
 }
 
+#[derive(Debug, Parser)]
+pub(crate) struct ExportBRC20 {
+  #[arg(long, value_enum, help = "Export <WHAT> table")]
+  what: BRC20ExportKind,
+  #[arg(
+    long,
+    value_enum,
+    default_value = "csv",
+    help = "Write rows in <FORMAT>"
+  )]
+  format: ExportFormat,
+  #[arg(
+    long,
+    help = "<OUTPUT> file to write to [default: brc20_<WHAT>.<FORMAT>]"
+  )]
+  output: Option<String>,
+}
+
+impl ExportBRC20 {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let index = Index::open(&options)?;
+
+    index.update()?;
+
+    let output = self.output.unwrap_or_else(|| {
+      default_export_output("brc20", self.what.to_possible_value(), self.format)
+    });
+    index.export_brc20(&output, self.what, self.format)?;
+
+    Ok(Box::new(Empty {}))
+  }
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct ExportBRC20S {
+  #[arg(long, value_enum, help = "Export <WHAT> table")]
+  what: BRC20SExportKind,
+  #[arg(
+    long,
+    value_enum,
+    default_value = "csv",
+    help = "Write rows in <FORMAT>"
+  )]
+  format: ExportFormat,
+  #[arg(
+    long,
+    help = "<OUTPUT> file to write to [default: brc20s_<WHAT>.<FORMAT>]"
+  )]
+  output: Option<String>,
+}
+
+impl ExportBRC20S {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let index = Index::open(&options)?;
+
+    index.update()?;
+
+    let output = self.output.unwrap_or_else(|| {
+      default_export_output("brc20s", self.what.to_possible_value(), self.format)
+    });
+    index.export_brc20s(&output, self.what, self.format)?;
+
+    Ok(Box::new(Empty {}))
+  }
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct DiffBRC20 {
+  #[arg(
+    long,
+    help = "Compare with balance and receipt rows in <REFERENCE>, in the layout written by `export-brc20 --format jsonl`"
+  )]
+  reference: PathBuf,
+}
+
+impl DiffBRC20 {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let index = Index::open(&options)?;
+
+    index.update()?;
+
+    Ok(Box::new(index.diff_brc20(&self.reference)?))
+  }
+}
+
+fn default_export_output(
+  protocol: &str,
+  what: Option<clap::builder::PossibleValue>,
+  format: ExportFormat,
+) -> String {
+  format!(
+    "{protocol}_{}.{}",
+    what.unwrap().get_name(),
+    format.to_possible_value().unwrap().get_name()
+  )
+}
+
+fn check(options: Options) -> SubcommandResult {
+  let index = Index::open(&options)?;
+
+  index.update()?;
+
+  Ok(Box::new(index.check()?))
+}
+
 pub(crate) fn run(options: Options) -> SubcommandResult {
   let index = Index::open(&options)?;
 
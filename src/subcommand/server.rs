@@ -45,6 +45,8 @@ mod brc20;
 mod brc20s;
 mod info;
 mod ord;
+mod recursive;
+mod sat;
 mod types;
 
 mod accept_json;
@@ -171,6 +173,7 @@ impl Server {
           brc20::brc20_block_events,
           brc20::brc20_transferable,
           brc20::brc20_all_transferable,
+          brc20::brc20_tick_search,
 
           brc20s::brc20s_tick_info,
           brc20s::brc20s_all_tick_info,
@@ -179,18 +182,32 @@ impl Server {
           brc20s::brc20s_pool_info,
           brc20s::brc20s_all_pool_info,
           brc20s::brc20s_all_pools_by_tid,
+          brc20s::brc20s_pool_users,
+          brc20s::brc20s_pool_history,
           brc20s::brc20s_txid_receipts,
           brc20s::brc20s_block_receipts,
           brc20s::brc20s_transferable,
           brc20s::brc20s_all_transferable,
           brc20s::brc20s_userinfo,
+          brc20s::brc20s_reward_projection,
           brc20s::brc20s_stake_info,
 
           ord::ord_inscription_id,
           ord::ord_inscription_number,
+          ord::ord_inscription_children,
           ord::ord_outpoint,
           ord::ord_txid_inscriptions,
           ord::ord_block_inscriptions,
+          ord::ord_stats,
+          ord::ord_block_stats,
+          ord::ord_address_inscriptions,
+          ord::ord_inscription_history,
+          ord::ord_collection_name,
+          ord::ord_address_names,
+          ord::ord_bitmap_district,
+          ord::ord_address_bitmap,
+
+          sat::sat_address_rare,
 
           info::node_info,
         ),
@@ -206,11 +223,14 @@ impl Server {
           brc20::MintEvent,
           brc20::InscribeTransferEvent,
           brc20::TransferEvent,
+          brc20::BurnEvent,
           brc20::ErrorEvent,
           brc20::TxEvents,
           brc20::BlockEvents,
           brc20::TransferableInscription,
           brc20::TransferableInscriptions,
+          brc20::TickSearchResult,
+          brc20::TickSearchResults,
 
           // BRC20 responses schemas
           response::BRC20Tick,
@@ -220,6 +240,7 @@ impl Server {
           response::BRC20TxEvents,
           response::BRC20BlockEvents,
           response::BRC20Transferable,
+          response::BRC20TickSearch,
 
           // BRC20S schemas
           brc20s::Tick,
@@ -232,6 +253,10 @@ impl Server {
           brc20s::AllBalance,
           brc20s::Pool,
           brc20s::AllPoolInfo,
+          brc20s::PoolUser,
+          brc20s::PoolUsers,
+          brc20s::PoolSnapshot,
+          brc20s::PoolHistory,
           brc20s::OperationType,
           brc20s::Event,
           brc20s::DeployTickEvent,
@@ -248,6 +273,8 @@ impl Server {
           brc20s::TransferableInscription,
           brc20s::Transferable,
           brc20s::UserInfo,
+          brc20s::BlockReward,
+          brc20s::RewardProjection,
           brc20s::StakedInfo,
           brc20s::StakedPid,
 
@@ -261,6 +288,7 @@ impl Server {
           response::BRC20STxReceipts,
           response::BRC20SBlockReceipts,
           response::BRC20Transferable,
+          response::BRC20SRewardProjection,
 
           // Ord schemas
           ord::OrdInscription,
@@ -271,13 +299,31 @@ impl Server {
           ord::TxInscription,
           ord::TxInscriptions,
           ord::BlockInscriptions,
+          ord::ContentStats,
+          ord::OrdStats,
+          ord::BlockStats,
+          ord::AddressInscriptions,
+          ord::InscriptionTransferEvent,
+          ord::InscriptionHistory,
+          ord::OrdName,
+          ord::AddressNames,
+          ord::BitmapDistrict,
+          ord::BitmapParcel,
+          ord::AddressBitmap,
+          ord::InscriptionChildren,
 
           // Ord responses schemas
           response::OrdOrdInscription,
           response::OrdTxInscriptions,
           response::OrdBlockInscriptions,
           response::OrdOutPointResult,
+          response::OrdOrdStats,
+          response::OrdBlockStats,
 
+          // Sat schemas
+          sat::RareSat,
+          sat::AddressRareSats,
+          response::SatAddressRareSats,
 
           // Node Info schemas
           info::NodeInfo,
@@ -305,7 +351,10 @@ impl Server {
           get(|| async { ApiDoc::openapi().to_pretty_json().unwrap() }),
         )
         .route("/node/info", get(info::node_info))
+        .route("/sat/address/:address/rare", get(sat::sat_address_rare))
         .route("/ord/id/:id/inscription", get(ord::ord_inscription_id))
+        .route("/ord/id/:id/history", get(ord::ord_inscription_history))
+        .route("/ord/id/:id/children", get(ord::ord_inscription_children))
         .route(
           "/ord/number/:number/inscription",
           get(ord::ord_inscription_number),
@@ -315,16 +364,34 @@ impl Server {
           "/ord/tx/:txid/inscriptions",
           get(ord::ord_txid_inscriptions),
         )
+        // both block routes share the `:block` segment name, so that they don't conflict.
         .route(
-          "/ord/block/:blockhash/inscriptions",
+          "/ord/block/:block/inscriptions",
           get(ord::ord_block_inscriptions),
         )
+        .route("/ord/stats", get(ord::ord_stats))
+        .route("/ord/block/:block/stats", get(ord::ord_block_stats))
+        .route(
+          "/ord/address/:address/inscriptions",
+          get(ord::ord_address_inscriptions),
+        )
+        .route("/ord/address/:address/names", get(ord::ord_address_names))
+        .route(
+          "/ord/collection/:namespace/:name",
+          get(ord::ord_collection_name),
+        )
+        .route("/ord/address/:address/bitmap", get(ord::ord_address_bitmap))
+        .route(
+          "/ord/bitmap/district/:number",
+          get(ord::ord_bitmap_district),
+        )
         .route(
           "/ord/debug/bitmap/district/:number",
           get(ord::ord_debug_bitmap_district),
         )
         .route("/brc20/tick/:tick", get(brc20::brc20_tick_info))
         .route("/brc20/tick", get(brc20::brc20_all_tick_info))
+        .route("/brc20/search", get(brc20::brc20_tick_search))
         .route(
           "/brc20/tick/:tick/address/:address/balance",
           get(brc20::brc20_balance),
@@ -355,6 +422,8 @@ impl Server {
         )
         .route("/brc20s/pool", get(brc20s::brc20s_all_pool_info))
         .route("/brc20s/pool/:pid", get(brc20s::brc20s_pool_info))
+        .route("/brc20s/pool/:pid/users", get(brc20s::brc20s_pool_users))
+        .route("/brc20s/pool/:pid/history", get(brc20s::brc20s_pool_history))
         .route(
           "/brc20s/pool/tid/:tick_id",
           get(brc20s::brc20s_all_pools_by_tid),
@@ -375,6 +444,10 @@ impl Server {
           "/brc20s/debug/pool/:pid/address/:address/reward",
           get(brc20s::brc20s_user_pending_reward),
         )
+        .route(
+          "/brc20s/pool/:pid/address/:address/projection",
+          get(brc20s::brc20s_reward_projection),
+        )
         .route(
           "/brc20s/tick/:tick_id/address/:address/balance",
           get(brc20s::brc20s_balance),
@@ -449,6 +522,23 @@ impl Server {
         .route("/ordinal/:sat", get(Self::ordinal))
         .route("/output/:output", get(Self::output))
         .route("/preview/:inscription_id", get(Self::preview))
+        .route("/r/blockhash", get(recursive::blockhash))
+        .route("/r/blockhash/:height", get(recursive::blockhash_at_height))
+        .route("/r/blockheight", get(recursive::blockheight))
+        .route("/r/blocktime", get(recursive::blocktime))
+        .route("/r/brc20/tick/:tick", get(recursive::brc20_tick))
+        .route(
+          "/r/brc20/tick/:tick/address/:address/balance",
+          get(recursive::brc20_balance),
+        )
+        .route("/r/children/:inscription_id", get(recursive::children))
+        .route(
+          "/r/children/:inscription_id/:page",
+          get(recursive::children_page),
+        )
+        .route("/r/metadata/:inscription_id", get(recursive::metadata))
+        .route("/r/sat/:sat", get(recursive::sat))
+        .route("/r/sat/:sat/:page", get(recursive::sat_page))
         .route("/range/:start/:end", get(Self::range))
         .route("/rare.txt", get(Self::rare_txt))
         .route("/sat/:sat", get(Self::sat))
@@ -677,6 +767,7 @@ impl Server {
       })
     });
     let blocktime = index.block_time(sat.height())?;
+    let satributes = index.satributes(sat)?;
     Ok(if accept_json.0 {
       Json(SatJson {
         number: sat.0,
@@ -689,6 +780,7 @@ impl Server {
         period: sat.period(),
         offset: sat.third(),
         rarity: sat.rarity(),
+        satributes,
         percentile: sat.percentile(),
         satpoint,
         timestamp: blocktime.timestamp().timestamp(),
@@ -701,6 +793,7 @@ impl Server {
         satpoint,
         blocktime,
         inscriptions,
+        satributes,
       }
       .page(page_config, index.has_sat_index()?)
       .into_response()
@@ -929,11 +1022,26 @@ impl Server {
       Ok(Redirect::to(&format!("/output/{query}")))
     } else if INSCRIPTION_ID.is_match(query) {
       Ok(Redirect::to(&format!("/inscription/{query}")))
+    } else if query.parse::<Sat>().is_ok() {
+      Ok(Redirect::to(&format!("/sat/{query}")))
+    } else if let Some(page) = brc20::tick_page(index, query)? {
+      Ok(Redirect::to(&page))
     } else {
       Ok(Redirect::to(&format!("/sat/{query}")))
     }
   }
 
+  fn percent_encode(s: &str) -> String {
+    s.bytes()
+      .map(|b| match b {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+          char::from(b).to_string()
+        }
+        _ => format!("%{b:02X}"),
+      })
+      .collect()
+  }
+
   async fn favicon(user_agent: Option<TypedHeader<UserAgent>>) -> ServerResult<Response> {
     if user_agent
       .map(|user_agent| {
@@ -1128,14 +1236,7 @@ impl Server {
         .and_then(|content_type| content_type.parse().ok())
         .unwrap_or(HeaderValue::from_static("application/octet-stream")),
     );
-    headers.insert(
-      header::CONTENT_SECURITY_POLICY,
-      HeaderValue::from_static("default-src 'self' 'unsafe-eval' 'unsafe-inline' data: blob:"),
-    );
-    headers.append(
-      header::CONTENT_SECURITY_POLICY,
-      HeaderValue::from_static("default-src *:*/content/ *:*/blockheight *:*/blockhash *:*/blockhash/ *:*/blocktime 'unsafe-eval' 'unsafe-inline' data: blob:"),
-    );
+    recursive::content_security_policy(&mut headers);
 
     let body = inscription.into_body();
     let cache_control = match body {
@@ -1279,6 +1380,7 @@ impl Server {
         genesis_fee: entry.fee,
         genesis_height: entry.height,
         children,
+        history: index.ord_get_inscription_history(inscription_id, 0, usize::MAX)?,
         inscription,
         inscription_id,
         next,
@@ -1402,7 +1504,12 @@ impl Server {
 #[cfg(test)]
 mod tests {
   use {
-    super::*, reqwest::Url, serde::de::DeserializeOwned, std::net::TcpListener, tempfile::TempDir,
+    super::*,
+    crate::okx::{datastore::ScriptKey, protocol::brc20s::hash::caculate_tick_id},
+    reqwest::Url,
+    serde::de::DeserializeOwned,
+    std::net::TcpListener,
+    tempfile::TempDir,
   };
 
   struct TestServer {
@@ -3158,6 +3265,1283 @@ mod tests {
     );
   }
 
+  #[test]
+  fn ord_stats_endpoints() {
+    let server = TestServer::new_with_regtest();
+    server.mine_blocks(1);
+
+    server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    let stats = server.get_json::<serde_json::Value>("/api/v1/ord/stats");
+    assert_eq!(stats["data"]["count"], 1);
+    assert_eq!(stats["data"]["bytes"], 5);
+    assert_eq!(stats["data"]["contentTypes"][0]["name"], "text/plain");
+    assert_eq!(stats["data"]["media"][0]["name"], "text");
+
+    let stats = server.get_json::<serde_json::Value>("/api/v1/ord/block/2/stats");
+    assert_eq!(stats["data"]["height"], 2);
+    assert_eq!(stats["data"]["count"], 1);
+
+    let stats = server.get_json::<serde_json::Value>("/api/v1/ord/block/1/stats");
+    assert_eq!(stats["data"]["count"], 0);
+
+    assert_eq!(
+      server.get("/api/v1/ord/block/3/stats").status(),
+      StatusCode::NOT_FOUND
+    );
+  }
+
+  #[test]
+  fn ord_children_endpoint() {
+    let server = TestServer::new_server(
+      test_bitcoincore_rpc::builder()
+        .network(bitcoin::Network::Regtest)
+        .build(),
+      None,
+      &["--chain", "regtest"],
+      &[],
+    );
+    server.mine_blocks(1);
+
+    let parent_txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "parent").to_witness())],
+      ..Default::default()
+    });
+    server.mine_blocks(2);
+
+    let parent = InscriptionId {
+      txid: parent_txid,
+      index: 0,
+    };
+    let child = |index| Inscription {
+      content_type: Some("text/plain".into()),
+      body: Some(format!("child {index}").into_bytes()),
+      parent: Some(parent.parent_value()),
+      unrecognized_even_field: false,
+      metadata: None,
+    };
+
+    let first_txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[
+        (2, 1, 0, Default::default()),
+        (3, 0, 0, child(0).to_witness()),
+      ],
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    let second_txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[
+        (4, 1, 0, Default::default()),
+        (4, 0, 0, child(1).to_witness()),
+      ],
+      ..Default::default()
+    });
+    // the parent is not spent, so this is not a child.
+    server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, child(2).to_witness())],
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    let first = InscriptionId {
+      txid: first_txid,
+      index: 0,
+    };
+    let second = InscriptionId {
+      txid: second_txid,
+      index: 0,
+    };
+
+    let response =
+      server.get_json::<serde_json::Value>(&format!("/api/v1/ord/id/{parent}/inscription"));
+    assert_eq!(response["data"]["childrenCount"], 2);
+    assert_eq!(response["data"]["parent"], serde_json::Value::Null);
+    assert_eq!(
+      response["data"]["collections"],
+      serde_json::json!(["parent"])
+    );
+
+    let response =
+      server.get_json::<serde_json::Value>(&format!("/api/v1/ord/id/{first}/inscription"));
+    assert_eq!(response["data"]["parent"], parent.to_string());
+    assert_eq!(response["data"]["childrenCount"], 0);
+
+    let response =
+      server.get_json::<serde_json::Value>(&format!("/api/v1/ord/id/{parent}/children"));
+    assert_eq!(response["data"]["total"], 2);
+    assert_eq!(response["data"]["children"][0]["id"], first.to_string());
+    assert_eq!(response["data"]["children"][1]["id"], second.to_string());
+
+    let response = server
+      .get_json::<serde_json::Value>(&format!("/api/v1/ord/id/{parent}/children?start=1&limit=1"));
+    assert_eq!(response["data"]["total"], 2);
+    assert_eq!(response["data"]["children"].as_array().unwrap().len(), 1);
+    assert_eq!(response["data"]["children"][0]["id"], second.to_string());
+    assert_eq!(
+      server
+        .get(format!("/api/v1/ord/id/{parent}/children?limit=101"))
+        .status(),
+      StatusCode::BAD_REQUEST
+    );
+
+    assert_eq!(
+      server
+        .get(format!(
+          "/api/v1/ord/id/{}/children",
+          InscriptionId {
+            txid: txid(1),
+            index: 0
+          }
+        ))
+        .status(),
+      StatusCode::NOT_FOUND
+    );
+  }
+
+  #[test]
+  fn recursive_endpoints() {
+    let server = TestServer::new_with_regtest_with_index_sats();
+    let blocks = server.mine_blocks(1);
+
+    let parent_txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        Inscription {
+          content_type: Some("text/plain".into()),
+          body: Some("parent".into()),
+          parent: None,
+          unrecognized_even_field: false,
+          metadata: Some(vec![0xa1, 0x61, 0x61, 0x01]),
+        }
+        .to_witness(),
+      )],
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    let parent = InscriptionId {
+      txid: parent_txid,
+      index: 0,
+    };
+
+    let child_txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[
+        (2, 1, 0, Default::default()),
+        (
+          2,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("child".into()),
+            parent: Some(parent.parent_value()),
+            unrecognized_even_field: false,
+            metadata: None,
+          }
+          .to_witness(),
+        ),
+      ],
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    let child = InscriptionId {
+      txid: child_txid,
+      index: 0,
+    };
+
+    let response = server.get("/r/blockheight");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response
+        .headers()
+        .get_all(header::CONTENT_SECURITY_POLICY)
+        .iter()
+        .count(),
+      2
+    );
+    assert_eq!(response.text().unwrap(), "3");
+
+    assert_eq!(
+      server.get_json::<BlockHash>("/r/blockhash/1"),
+      blocks[0].block_hash()
+    );
+    assert_eq!(
+      server.get("/r/blockhash/10").status(),
+      StatusCode::NOT_FOUND
+    );
+
+    assert_eq!(
+      server.get_json::<String>(format!("/r/metadata/{parent}")),
+      "a1616101"
+    );
+    assert_eq!(
+      server.get(format!("/r/metadata/{child}")).status(),
+      StatusCode::NOT_FOUND
+    );
+
+    assert_eq!(
+      server.get_json::<recursive::InscriptionIdsJson>(format!("/r/children/{parent}")),
+      recursive::InscriptionIdsJson {
+        ids: vec![child],
+        more: false,
+        page: 0,
+      }
+    );
+    assert!(server
+      .get_json::<recursive::InscriptionIdsJson>(format!("/r/children/{parent}/1"))
+      .ids
+      .is_empty());
+
+    let sat = server.get_json::<serde_json::Value>(format!("/api/v1/ord/id/{parent}/inscription"))
+      ["data"]["sat"]
+      .as_u64()
+      .unwrap();
+    assert_eq!(
+      server.get_json::<recursive::InscriptionIdsJson>(format!("/r/sat/{sat}")),
+      recursive::InscriptionIdsJson {
+        ids: vec![parent],
+        more: false,
+        page: 0,
+      }
+    );
+
+    assert_eq!(
+      server.get("/r/brc20/tick/ordi").status(),
+      StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+      server.get("/r/brc20/tick/ord").status(),
+      StatusCode::BAD_REQUEST
+    );
+  }
+
+  #[test]
+  fn ord_bitmap_endpoints() {
+    let server = TestServer::new_server(
+      test_bitcoincore_rpc::builder()
+        .network(bitcoin::Network::Regtest)
+        .build(),
+      None,
+      &["--chain", "regtest", "--enable-index-bitmap"],
+      &[],
+    );
+    let blocks = server.mine_blocks(6);
+
+    let alice = Address::p2wsh(&ScriptBuf::from(vec![0x51]), Network::Regtest);
+    let bob = Address::p2wsh(&ScriptBuf::from(vec![0x52]), Network::Regtest);
+
+    let inscribe = |input: usize, content: &str, recipient: &Address| {
+      server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(input, 0, 0, inscription("text/plain", content).to_witness())],
+        recipient: Some(recipient.clone()),
+        ..Default::default()
+      })
+    };
+
+    let district = inscribe(1, "1.bitmap", &alice);
+    inscribe(2, "2.bitmap", &alice);
+    server.mine_blocks(1);
+
+    let parcel = inscribe(3, "0.1.bitmap", &alice);
+    // block 1 only has the coinbase transaction.
+    inscribe(4, "1.1.bitmap", &alice);
+    // district 2 is held by alice.
+    inscribe(5, "0.2.bitmap", &bob);
+    inscribe(6, "0.1.bitmap", &alice);
+    server.mine_blocks(1);
+
+    let response = server.get_json::<serde_json::Value>("/api/v1/ord/bitmap/district/1");
+    assert_eq!(response["data"]["number"], 1);
+    assert_eq!(
+      response["data"]["inscriptionId"],
+      InscriptionId {
+        txid: district,
+        index: 0
+      }
+      .to_string()
+    );
+    assert_eq!(
+      response["data"]["blockHash"],
+      blocks[0].block_hash().to_string()
+    );
+    assert_eq!(response["data"]["transactions"], 1);
+
+    assert_eq!(
+      server.get("/api/v1/ord/bitmap/district/3").status(),
+      StatusCode::NOT_FOUND
+    );
+
+    let bitmap =
+      server.get_json::<serde_json::Value>(&format!("/api/v1/ord/address/{alice}/bitmap"));
+    assert_eq!(bitmap["data"]["districts"].as_array().unwrap().len(), 2);
+    assert_eq!(bitmap["data"]["parcels"].as_array().unwrap().len(), 1);
+    assert_eq!(bitmap["data"]["parcels"][0]["parcel"], "0.1.bitmap");
+    assert_eq!(bitmap["data"]["parcels"][0]["district"], 1);
+    assert_eq!(
+      bitmap["data"]["parcels"][0]["inscriptionId"],
+      InscriptionId {
+        txid: parcel,
+        index: 0
+      }
+      .to_string()
+    );
+
+    let bitmap = server.get_json::<serde_json::Value>(&format!("/api/v1/ord/address/{bob}/bitmap"));
+    assert!(bitmap["data"]["districts"].as_array().unwrap().is_empty());
+    assert!(bitmap["data"]["parcels"].as_array().unwrap().is_empty());
+  }
+
+  #[test]
+  fn sat_address_rare_endpoint() {
+    let server = TestServer::new_with_regtest_with_index_sats();
+    server.mine_blocks(2);
+
+    let alice = Address::p2wsh(&ScriptBuf::from(vec![0x51]), Network::Regtest);
+    let bob = Address::p2wsh(&ScriptBuf::from(vec![0x52]), Network::Regtest);
+
+    let inscribed = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      recipient: Some(alice.clone()),
+      ..Default::default()
+    });
+    let plain = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, Default::default())],
+      recipient: Some(alice.clone()),
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    let rare = server.get_json::<serde_json::Value>(&format!("/api/v1/sat/address/{alice}/rare"));
+    assert_eq!(
+      rare["data"]["sats"],
+      serde_json::json!([
+        {
+          "sat": 50 * COIN_VALUE,
+          "rarity": "uncommon",
+          "satpoint": format!("{inscribed}:0:0"),
+          "inscribed": true,
+          "satributes": ["alpha", "vintage"],
+        },
+        {
+          "sat": 100 * COIN_VALUE,
+          "rarity": "uncommon",
+          "satpoint": format!("{plain}:0:0"),
+          "inscribed": false,
+          "satributes": ["alpha", "vintage"],
+        },
+      ])
+    );
+
+    let rare = server.get_json::<serde_json::Value>(&format!(
+      "/api/v1/sat/address/{alice}/rare?satribute=vintage"
+    ));
+    assert_eq!(rare["data"]["sats"].as_array().unwrap().len(), 2);
+
+    let rare = server.get_json::<serde_json::Value>(&format!(
+      "/api/v1/sat/address/{alice}/rare?satribute=palindrome"
+    ));
+    assert!(rare["data"]["sats"].as_array().unwrap().is_empty());
+
+    for satribute in ["shiny", "omega", "black"] {
+      assert_eq!(
+        server
+          .get(format!(
+            "/api/v1/sat/address/{alice}/rare?satribute={satribute}"
+          ))
+          .status(),
+        StatusCode::BAD_REQUEST
+      );
+    }
+
+    let sent = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(3, 2, 0, Default::default())],
+      recipient: Some(bob.clone()),
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    let rare = server.get_json::<serde_json::Value>(&format!("/api/v1/sat/address/{alice}/rare"));
+    assert_eq!(rare["data"]["sats"].as_array().unwrap().len(), 1);
+    assert_eq!(rare["data"]["sats"][0]["sat"], 50 * COIN_VALUE);
+
+    let rare = server.get_json::<serde_json::Value>(&format!("/api/v1/sat/address/{bob}/rare"));
+    assert_eq!(rare["data"]["sats"][0]["sat"], 100 * COIN_VALUE);
+    assert_eq!(rare["data"]["sats"][0]["satpoint"], format!("{sent}:0:0"));
+  }
+
+  #[test]
+  fn sat_address_rare_endpoint_requires_sat_index() {
+    let server = TestServer::new_with_regtest();
+    let alice = Address::p2wsh(&ScriptBuf::from(vec![0x51]), Network::Regtest);
+
+    assert_eq!(
+      server
+        .get(format!("/api/v1/sat/address/{alice}/rare"))
+        .status(),
+      StatusCode::NOT_FOUND
+    );
+  }
+
+  fn brc20_inscription(body: &str, parent: Option<InscriptionId>) -> Inscription {
+    Inscription {
+      content_type: Some("text/plain".into()),
+      body: Some(body.into()),
+      parent: parent.map(|parent| parent.parent_value()),
+      unrecognized_even_field: false,
+      metadata: None,
+    }
+  }
+
+  #[test]
+  fn search_for_tick_returns_tick() {
+    let server = TestServer::new_server(
+      test_bitcoincore_rpc::builder()
+        .network(bitcoin::Network::Regtest)
+        .build(),
+      None,
+      &["--chain", "regtest", "--enable-index-brc20"],
+      &[],
+    );
+    server.mine_blocks(1);
+
+    server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        brc20_inscription(
+          r#"{"p":"brc-20","op":"deploy","tick":"abcd","max":"21000","lim":"1000"}"#,
+          None,
+        )
+        .to_witness(),
+      )],
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    assert_eq!(
+      server.get_json::<serde_json::Value>("/api/v1/brc20/search?q=a")["data"]["ticks"][0]["tick"],
+      "abcd"
+    );
+    server.assert_redirect("/search?query=a", "/sat/a");
+    server.assert_redirect("/search?query=abcd", "/sat/abcd");
+    server.assert_redirect("/search?query=ABCD", "/api/v1/brc20/tick/ABCD");
+    server.assert_redirect("/search?query=ABCE", "/sat/ABCE");
+  }
+
+  #[test]
+  fn brc20_self_mint_ticks() {
+    let server = TestServer::new_server(
+      test_bitcoincore_rpc::builder()
+        .network(bitcoin::Network::Regtest)
+        .build(),
+      None,
+      &["--chain", "regtest", "--enable-index-brc20"],
+      &[],
+    );
+    server.mine_blocks(4);
+
+    let alice = Address::p2wsh(&ScriptBuf::from(vec![0x51]), Network::Regtest);
+    let inscribe = |inputs: &[(usize, usize, usize, Witness)]| {
+      server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+        inputs,
+        recipient: Some(alice.clone()),
+        ..Default::default()
+      })
+    };
+
+    let deploy = inscribe(&[(
+      1,
+      0,
+      0,
+      brc20_inscription(
+        r#"{"p":"brc-20","op":"deploy","tick":"sfmnt","max":"0","lim":"1000","self_mint":"true"}"#,
+        None,
+      )
+      .to_witness(),
+    )]);
+    inscribe(&[(
+      2,
+      0,
+      0,
+      brc20_inscription(
+        r#"{"p":"brc-20","op":"deploy","tick":"nosfm","max":"21000","lim":"1000"}"#,
+        None,
+      )
+      .to_witness(),
+    )]);
+    server.mine_blocks(1);
+
+    let mint = r#"{"p":"brc-20","op":"mint","tick":"sfmnt","amt":"1000"}"#;
+    let parent = InscriptionId {
+      txid: deploy,
+      index: 0,
+    };
+    // the deploy inscription is spent alongside the child, which verifies the parent.
+    inscribe(&[
+      (3, 0, 0, brc20_inscription(mint, Some(parent)).to_witness()),
+      (5, 1, 0, Default::default()),
+    ]);
+    inscribe(&[(4, 0, 0, brc20_inscription(mint, None).to_witness())]);
+    server.mine_blocks(1);
+
+    let tick = server.get_json::<serde_json::Value>("/api/v1/brc20/tick/sfmnt");
+    assert_eq!(
+      tick["data"]["supply"],
+      (u128::from(u64::MAX) * 10u128.pow(18)).to_string()
+    );
+    assert_eq!(tick["data"]["minted"], (1000 * 10u128.pow(18)).to_string());
+
+    assert_eq!(
+      server.get("/api/v1/brc20/tick/nosfm").status(),
+      StatusCode::NOT_FOUND
+    );
+  }
+
+  #[test]
+  fn brc20_self_mint_ticks_are_height_gated() {
+    let server = TestServer::new_server(
+      test_bitcoincore_rpc::builder()
+        .network(bitcoin::Network::Regtest)
+        .build(),
+      None,
+      &[
+        "--chain",
+        "regtest",
+        "--enable-index-brc20",
+        "--first-brc20-self-mint-height",
+        "100",
+        "--first-brc20-burn-height",
+        "100",
+      ],
+      &[],
+    );
+    server.mine_blocks(1);
+
+    server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        brc20_inscription(
+          r#"{"p":"brc-20","op":"deploy","tick":"sfmnt","max":"21000","self_mint":"true"}"#,
+          None,
+        )
+        .to_witness(),
+      )],
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    assert_eq!(
+      server.get("/api/v1/brc20/tick/sfmnt").status(),
+      StatusCode::NOT_FOUND
+    );
+
+    // five-byte mints fail on their length before activation, as they did before self mint.
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        2,
+        0,
+        0,
+        brc20_inscription(
+          r#"{"p":"brc-20","op":"mint","tick":"sfmnt","amt":"1000"}"#,
+          None,
+        )
+        .to_witness(),
+      )],
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    let events = server.get_json::<serde_json::Value>(&format!("/api/v1/brc20/tx/{txid}/events"));
+    assert_eq!(events["data"]["events"][0]["valid"], false);
+    assert_eq!(
+      events["data"]["events"][0]["msg"],
+      "illegal tick length 'sfmnt'"
+    );
+  }
+
+  fn brc20_transfer_to_op_return(burn_height: &str) -> (TestServer, Txid) {
+    let server = TestServer::new_server(
+      test_bitcoincore_rpc::builder()
+        .network(bitcoin::Network::Regtest)
+        .build(),
+      None,
+      &[
+        "--chain",
+        "regtest",
+        "--enable-index-brc20",
+        "--first-brc20-burn-height",
+        burn_height,
+      ],
+      &[],
+    );
+    server.mine_blocks(1);
+
+    for (height, body) in [
+      (
+        1,
+        r#"{"p":"brc-20","op":"deploy","tick":"brnt","max":"21000","dec":"0"}"#,
+      ),
+      (
+        2,
+        r#"{"p":"brc-20","op":"mint","tick":"brnt","amt":"1000"}"#,
+      ),
+      (
+        3,
+        r#"{"p":"brc-20","op":"transfer","tick":"brnt","amt":"100"}"#,
+      ),
+    ] {
+      server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(height, 0, 0, brc20_inscription(body, None).to_witness())],
+        ..Default::default()
+      });
+      server.mine_blocks(1);
+    }
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(4, 1, 0, Default::default())],
+      op_return: Some(
+        script::Builder::new()
+          .push_opcode(opcodes::all::OP_RETURN)
+          .into_script(),
+      ),
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    (server, txid)
+  }
+
+  #[test]
+  fn brc20_transfer_to_op_return_burns() {
+    let (server, txid) = brc20_transfer_to_op_return("0");
+
+    let tick = server.get_json::<serde_json::Value>("/api/v1/brc20/tick/brnt");
+    assert_eq!(tick["data"]["minted"], "1000");
+    assert_eq!(tick["data"]["burned"], "100");
+
+    let events = server.get_json::<serde_json::Value>(&format!("/api/v1/brc20/tx/{txid}/events"));
+    assert_eq!(events["data"]["events"][0]["type"], "burn");
+    assert_eq!(events["data"]["events"][0]["amount"], "100");
+    assert_eq!(events["data"]["events"][0]["valid"], true);
+  }
+
+  #[test]
+  fn brc20_transfer_to_op_return_burns_are_height_gated() {
+    let (server, txid) = brc20_transfer_to_op_return("100");
+
+    let tick = server.get_json::<serde_json::Value>("/api/v1/brc20/tick/brnt");
+    assert_eq!(tick["data"]["burned"], "0");
+
+    let events = server.get_json::<serde_json::Value>(&format!("/api/v1/brc20/tx/{txid}/events"));
+    assert_eq!(events["data"]["events"][0]["type"], "transfer");
+  }
+
+  #[test]
+  fn brc20s_reward_projection_validates_queries() {
+    let server = TestServer::new_server(
+      test_bitcoincore_rpc::builder()
+        .network(bitcoin::Network::Regtest)
+        .build(),
+      None,
+      &[
+        "--chain",
+        "regtest",
+        "--enable-index-brc20",
+        "--enable-index-brc20s",
+      ],
+      &[],
+    );
+    server.mine_blocks(1);
+
+    let alice = Address::p2wsh(&ScriptBuf::from(vec![0x51]), Network::Regtest);
+    let projection = |pid: &str, query: &str| {
+      server
+        .get(format!(
+          "/api/v1/brc20s/pool/{pid}/address/{alice}/projection{query}"
+        ))
+        .status()
+    };
+
+    assert_eq!(projection("0123456789%2301", ""), StatusCode::NOT_FOUND);
+    assert_eq!(
+      projection("0123456789%2301", "?amount=1000&blocks=10000"),
+      StatusCode::NOT_FOUND
+    );
+    assert_eq!(projection("01234567", ""), StatusCode::BAD_REQUEST);
+    assert_eq!(
+      projection("0123456789%2301", "?amount=1.5"),
+      StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+      projection("0123456789%2301", "?blocks=10001"),
+      StatusCode::BAD_REQUEST
+    );
+  }
+
+  #[test]
+  fn brc20s_pool_users_validates_queries() {
+    let server = TestServer::new_server(
+      test_bitcoincore_rpc::builder()
+        .network(bitcoin::Network::Regtest)
+        .build(),
+      None,
+      &[
+        "--chain",
+        "regtest",
+        "--enable-index-brc20",
+        "--enable-index-brc20s",
+      ],
+      &[],
+    );
+    server.mine_blocks(1);
+
+    let users = |pid: &str, query: &str| {
+      server
+        .get(format!("/api/v1/brc20s/pool/{pid}/users{query}"))
+        .status()
+    };
+
+    assert_eq!(users("0123456789%2301", ""), StatusCode::NOT_FOUND);
+    assert_eq!(
+      users(
+        "0123456789%2301",
+        "?sort=pendingReward&order=asc&start=10&limit=100"
+      ),
+      StatusCode::NOT_FOUND
+    );
+    assert_eq!(users("01234567", ""), StatusCode::BAD_REQUEST);
+    assert_eq!(
+      users("0123456789%2301", "?sort=reward_debt"),
+      StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+      users("0123456789%2301", "?order=up"),
+      StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+      users("0123456789%2301", "?limit=101"),
+      StatusCode::BAD_REQUEST
+    );
+  }
+
+  #[test]
+  fn brc20s_pool_history_validates_queries() {
+    let server = TestServer::new_server(
+      test_bitcoincore_rpc::builder()
+        .network(bitcoin::Network::Regtest)
+        .build(),
+      None,
+      &[
+        "--chain",
+        "regtest",
+        "--enable-index-brc20",
+        "--enable-index-brc20s",
+      ],
+      &[],
+    );
+    server.mine_blocks(1);
+
+    let history = |pid: &str, query: &str| {
+      server
+        .get(format!("/api/v1/brc20s/pool/{pid}/history{query}"))
+        .status()
+    };
+
+    assert_eq!(history("0123456789%2301", ""), StatusCode::NOT_FOUND);
+    assert_eq!(
+      history("0123456789%2301", "?from=10&to=10"),
+      StatusCode::NOT_FOUND
+    );
+    assert_eq!(history("01234567", ""), StatusCode::BAD_REQUEST);
+    assert_eq!(
+      history("0123456789%2301", "?from=11&to=10"),
+      StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+      history("0123456789%2301", "?from=-1"),
+      StatusCode::BAD_REQUEST
+    );
+  }
+
+  #[test]
+  fn brc20s_pool_endpoints() {
+    let server = TestServer::new_server(
+      test_bitcoincore_rpc::builder()
+        .network(bitcoin::Network::Regtest)
+        .build(),
+      None,
+      &[
+        "--chain",
+        "regtest",
+        "--enable-index-brc20",
+        "--enable-index-brc20s",
+      ],
+      &[],
+    );
+    server.mine_blocks(1);
+
+    let alice = Address::p2wsh(&ScriptBuf::from(vec![0x51]), Network::Regtest);
+    let bob = Address::p2wsh(&ScriptBuf::from(vec![0x52]), Network::Regtest);
+
+    // BRC20-S takes the sender from the input of the commit, so the wallet funds its own commit.
+    let height = std::cell::Cell::new(1);
+    let inscribe = |wallet: &Address, body: String| {
+      server.mine_blocks(1);
+      let mut input = (height.get() + 1, 0, 0);
+      for _ in 0..2 {
+        server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+          inputs: &[(input.0, input.1, input.2, Default::default())],
+          recipient: Some(wallet.clone()),
+          ..Default::default()
+        });
+        server.mine_blocks(1);
+        input = (input.0 + 1, 1, 0);
+      }
+      server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(
+          input.0,
+          input.1,
+          input.2,
+          brc20_inscription(&body, None).to_witness(),
+        )],
+        recipient: Some(wallet.clone()),
+        ..Default::default()
+      });
+      server.mine_blocks(1);
+      height.set(input.0 + 1);
+      height.get()
+    };
+
+    let script_key = ScriptKey::from_address(alice.clone());
+    let pid = format!(
+      "{}#01",
+      caculate_tick_id("earn", 21_000_000, 18, &script_key, &script_key).hex()
+    );
+    let pool = pid.replace('#', "%23");
+
+    inscribe(
+      &alice,
+      r#"{"p":"brc-20","op":"deploy","tick":"ordi","max":"21000","lim":"1000"}"#.into(),
+    );
+    for wallet in [&alice, &bob] {
+      inscribe(
+        wallet,
+        r#"{"p":"brc-20","op":"mint","tick":"ordi","amt":"1000"}"#.into(),
+      );
+    }
+    inscribe(
+      &alice,
+      serde_json::json!({
+        "p": "brc20-s",
+        "op": "deploy",
+        "t": "pool",
+        "pid": pid,
+        "stake": "ordi",
+        "earn": "earn",
+        "erate": "10",
+        "dmax": "1000000",
+        "total": "21000000",
+        "dec": "18",
+      })
+      .to_string(),
+    );
+    let bob_deposit = inscribe(
+      &bob,
+      serde_json::json!({"p": "brc20-s", "op": "deposit", "pid": pid, "amt": "100"}).to_string(),
+    );
+    let alice_deposit = inscribe(
+      &alice,
+      serde_json::json!({"p": "brc20-s", "op": "deposit", "pid": pid, "amt": "300"}).to_string(),
+    );
+    let bob_mint = inscribe(
+      &bob,
+      serde_json::json!({"p": "brc20-s", "op": "mint", "tick": "earn", "pid": pid, "amt": "1"})
+        .to_string(),
+    );
+
+    assert_eq!((bob_deposit, alice_deposit, bob_mint), (21, 25, 29));
+    let coin = |amount: u128| (amount * 10u128.pow(18)).to_string();
+    let tenth = |amount: u128| (amount * 10u128.pow(17)).to_string();
+
+    // alice holds 300 of the 400 staked, so earns 7.5 of the 10 per block.
+    let projection = server.get_json::<serde_json::Value>(&format!(
+      "/api/v1/brc20s/pool/{pool}/address/{alice}/projection?blocks=2"
+    ))["data"]
+      .clone();
+    assert_eq!(
+      projection,
+      serde_json::json!({
+        "pid": pid,
+        "pool": "pool",
+        "blockNum": 30,
+        "deposit": "0",
+        "staked": coin(300),
+        "poolStaked": coin(400),
+        "rewards": [
+          {"blockNum": 31, "reward": tenth(75)},
+          {"blockNum": 32, "reward": tenth(75)},
+        ],
+        "totalReward": coin(15),
+        "blocksUntilDmax": 99991,
+        "effectiveRate": (25 * 10u128.pow(15)).to_string(),
+      })
+    );
+
+    // another 100 brings bob to 200 of 500.
+    let projection = server.get_json::<serde_json::Value>(&format!(
+      "/api/v1/brc20s/pool/{pool}/address/{bob}/projection?amount={}&blocks=1",
+      coin(100)
+    ))["data"]
+      .clone();
+    assert_eq!(projection["staked"], coin(200));
+    assert_eq!(projection["poolStaked"], coin(500));
+    assert_eq!(projection["totalReward"], coin(4));
+
+    let users = |query: &str| {
+      server.get_json::<serde_json::Value>(&format!("/api/v1/brc20s/pool/{pool}/users{query}"))
+        ["data"]
+        .clone()
+    };
+    let alice_user = serde_json::json!({
+      "address": {"address": alice.to_string()},
+      "staked": coin(300),
+      "pendingReward": coin(30),
+      "minted": "0",
+    });
+    // bob earned 40 alone and 10 of the next 40, and minted 1 of it.
+    let bob_user = serde_json::json!({
+      "address": {"address": bob.to_string()},
+      "staked": coin(100),
+      "pendingReward": coin(49),
+      "minted": coin(1),
+    });
+    assert_eq!(
+      users(""),
+      serde_json::json!({
+        "pid": pid,
+        "blockNum": 29,
+        "users": [alice_user, bob_user],
+        "total": 2,
+      })
+    );
+    assert_eq!(
+      users("?sort=staked&order=asc")["users"],
+      serde_json::json!([bob_user, alice_user])
+    );
+    assert_eq!(
+      users("?sort=pendingReward")["users"],
+      serde_json::json!([bob_user, alice_user])
+    );
+    assert_eq!(
+      users("?sort=minted&order=asc")["users"],
+      serde_json::json!([alice_user, bob_user])
+    );
+    let page = users("?start=1&limit=1");
+    assert_eq!(page["users"], serde_json::json!([bob_user]));
+    assert_eq!(page["total"], 2);
+    assert_eq!(users("?start=2")["users"], serde_json::json!([]));
+
+    let history = |query: &str| {
+      server.get_json::<serde_json::Value>(&format!("/api/v1/brc20s/pool/{pool}/history{query}"))
+        ["data"]
+        .clone()
+    };
+    assert_eq!(
+      history(""),
+      serde_json::json!({
+        "pid": pid,
+        "snapshots": [
+          {
+            "blockNum": 21,
+            "staked": coin(100),
+            "minted": "0",
+            "accRewardPerShare": "0",
+            "latestUpdateBlock": 21,
+          },
+          {
+            "blockNum": 25,
+            "staked": coin(400),
+            "minted": coin(40),
+            "accRewardPerShare": tenth(4),
+            "latestUpdateBlock": 25,
+          },
+          {
+            "blockNum": 29,
+            "staked": coin(400),
+            "minted": coin(80),
+            "accRewardPerShare": tenth(5),
+            "latestUpdateBlock": 29,
+          },
+        ],
+        "next": null,
+      })
+    );
+    let snapshots = history("?from=22&to=29")["snapshots"].clone();
+    assert_eq!(
+      snapshots
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|snapshot| snapshot["blockNum"].clone())
+        .collect::<Vec<_>>(),
+      [25, 29]
+    );
+    assert_eq!(history("?from=30")["snapshots"], serde_json::json!([]));
+
+    let status = |path: String| server.get(format!("/api/v1/brc20s/pool/{path}")).status();
+    let unknown = "0123456789%2301";
+    assert_eq!(
+      status(format!("{unknown}/address/{alice}/projection")),
+      StatusCode::NOT_FOUND
+    );
+    assert_eq!(status(format!("{unknown}/users")), StatusCode::NOT_FOUND);
+    assert_eq!(status(format!("{unknown}/history")), StatusCode::NOT_FOUND);
+    assert_eq!(status("01234567/users".into()), StatusCode::BAD_REQUEST);
+    assert_eq!(
+      status(format!("{pool}/address/{alice}/projection?amount=1.5")),
+      StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+      status(format!("{pool}/address/{alice}/projection?blocks=10001")),
+      StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+      status(format!(
+        "{pool}/address/{alice}/projection?amount={}",
+        u128::MAX
+      )),
+      StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+      status(format!("{pool}/users?sort=reward_debt")),
+      StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+      status(format!("{pool}/users?order=up")),
+      StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+      status(format!("{pool}/users?limit=101")),
+      StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+      status(format!("{pool}/history?from=11&to=10")),
+      StatusCode::BAD_REQUEST
+    );
+  }
+
+  #[test]
+  fn ord_names_endpoints() {
+    let server = TestServer::new_server(
+      test_bitcoincore_rpc::builder()
+        .network(bitcoin::Network::Regtest)
+        .build(),
+      None,
+      &[
+        "--chain",
+        "regtest",
+        "--index-names",
+        "sats:any:64,btc:hostname:63",
+      ],
+      &[],
+    );
+    server.mine_blocks(3);
+
+    let alice = Address::p2wsh(&ScriptBuf::from(vec![0x51]), Network::Regtest);
+    let bob = Address::p2wsh(&ScriptBuf::from(vec![0x52]), Network::Regtest);
+
+    let first = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        inscription("text/plain", "Satoshi.sats").to_witness(),
+      )],
+      recipient: Some(alice.clone()),
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+    server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        2,
+        0,
+        0,
+        inscription("text/plain", "satoshi.sats").to_witness(),
+      )],
+      recipient: Some(bob.clone()),
+      ..Default::default()
+    });
+    server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(3, 0, 0, inscription("text/plain", "node.btc").to_witness())],
+      recipient: Some(bob.clone()),
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    let name = server.get_json::<serde_json::Value>("/api/v1/ord/collection/sats/SATOSHI");
+    assert_eq!(name["data"]["name"], "satoshi.sats");
+    assert_eq!(name["data"]["namespace"], "sats");
+    assert_eq!(
+      name["data"]["inscriptionId"],
+      InscriptionId {
+        txid: first,
+        index: 0
+      }
+      .to_string()
+    );
+    assert_eq!(name["data"]["inscriptionNumber"], 0);
+
+    let names = server.get_json::<serde_json::Value>(&format!("/api/v1/ord/address/{alice}/names"));
+    assert_eq!(names["data"]["names"].as_array().unwrap().len(), 1);
+    assert_eq!(names["data"]["names"][0]["name"], "satoshi.sats");
+
+    // the duplicate `satoshi.sats` is not a name.
+    let names = server.get_json::<serde_json::Value>(&format!("/api/v1/ord/address/{bob}/names"));
+    assert_eq!(names["data"]["names"].as_array().unwrap().len(), 1);
+    assert_eq!(names["data"]["names"][0]["name"], "node.btc");
+
+    assert_eq!(
+      server.get("/api/v1/ord/collection/sats/nobody").status(),
+      StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+      server.get("/api/v1/ord/collection/unisat/satoshi").status(),
+      StatusCode::BAD_REQUEST
+    );
+  }
+
+  #[test]
+  fn ord_address_inscriptions_endpoint() {
+    let server = TestServer::new_with_regtest();
+    server.mine_blocks(2);
+
+    let alice = Address::p2wsh(&ScriptBuf::from(vec![0x51]), Network::Regtest);
+    let bob = Address::p2wsh(&ScriptBuf::from(vec![0x52]), Network::Regtest);
+
+    server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      recipient: Some(alice.clone()),
+      ..Default::default()
+    });
+    server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, inscription("image/png", "world").to_witness())],
+      recipient: Some(alice.clone()),
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    let inscriptions =
+      server.get_json::<serde_json::Value>(&format!("/api/v1/ord/address/{alice}/inscriptions"));
+    assert_eq!(inscriptions["data"]["total"], 2);
+    assert_eq!(inscriptions["data"]["inscriptions"][0]["number"], 0);
+    assert_eq!(inscriptions["data"]["inscriptions"][1]["number"], 1);
+
+    let inscriptions = server.get_json::<serde_json::Value>(&format!(
+      "/api/v1/ord/address/{alice}/inscriptions?start=1&limit=1"
+    ));
+    assert_eq!(inscriptions["data"]["total"], 2);
+    assert_eq!(inscriptions["data"]["inscriptions"][0]["number"], 1);
+
+    let inscriptions = server.get_json::<serde_json::Value>(&format!(
+      "/api/v1/ord/address/{alice}/inscriptions?content_type=image/png"
+    ));
+    assert_eq!(inscriptions["data"]["total"], 1);
+    assert_eq!(inscriptions["data"]["inscriptions"][0]["number"], 1);
+
+    server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(3, 1, 0, Default::default())],
+      recipient: Some(bob.clone()),
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    let inscriptions =
+      server.get_json::<serde_json::Value>(&format!("/api/v1/ord/address/{alice}/inscriptions"));
+    assert_eq!(inscriptions["data"]["total"], 1);
+    assert_eq!(inscriptions["data"]["inscriptions"][0]["number"], 1);
+
+    let inscriptions =
+      server.get_json::<serde_json::Value>(&format!("/api/v1/ord/address/{bob}/inscriptions"));
+    assert_eq!(inscriptions["data"]["total"], 1);
+    assert_eq!(inscriptions["data"]["inscriptions"][0]["number"], 0);
+
+    assert_eq!(
+      server
+        .get("/api/v1/ord/address/invalid/inscriptions")
+        .status(),
+      StatusCode::BAD_REQUEST
+    );
+  }
+
+  #[test]
+  fn ord_inscription_history_endpoint() {
+    let server = TestServer::new_with_regtest();
+    server.mine_blocks(1);
+
+    let alice = Address::p2wsh(&ScriptBuf::from(vec![0x51]), Network::Regtest);
+    let bob = Address::p2wsh(&ScriptBuf::from(vec![0x52]), Network::Regtest);
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      recipient: Some(alice.clone()),
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    let transfer_txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 0, Default::default())],
+      recipient: Some(bob.clone()),
+      ..Default::default()
+    });
+    server.mine_blocks(1);
+
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    let history =
+      server.get_json::<serde_json::Value>(&format!("/api/v1/ord/id/{inscription_id}/history"));
+    let history = &history["data"]["history"];
+    assert_eq!(history.as_array().unwrap().len(), 2);
+    assert_eq!(history[0]["height"], 2);
+    assert_eq!(history[0]["action"], "inscribe");
+    assert_eq!(history[0]["txid"], txid.to_string());
+    assert_eq!(history[0]["owner"]["address"], alice.to_string());
+    assert_eq!(history[1]["height"], 3);
+    assert_eq!(history[1]["action"], "transfer");
+    assert_eq!(history[1]["location"], format!("{transfer_txid}:0:0"));
+    assert_eq!(history[1]["owner"]["address"], bob.to_string());
+
+    let page = server.get_json::<serde_json::Value>(&format!(
+      "/api/v1/ord/id/{inscription_id}/history?start=1&limit=1"
+    ));
+    assert_eq!(page["data"]["history"], serde_json::json!([history[1]]));
+    assert_eq!(
+      server
+        .get(format!("/api/v1/ord/id/{inscription_id}/history?limit=101"))
+        .status(),
+      StatusCode::BAD_REQUEST
+    );
+
+    server.assert_response_regex(
+      format!("/inscription/{inscription_id}"),
+      StatusCode::OK,
+      format!(
+        ".*<dt>history</dt>.*transferred in\\s*<a class=monospace href=/tx/{transfer_txid}>.*"
+      ),
+    );
+  }
+
   #[test]
   fn brc20_endpoint() {
     let test_server = TestServer::new();
@@ -3219,6 +4603,7 @@ mod tests {
               body: Some("hello".into()),
               parent: Some(parent_inscription_id.parent_value()),
               unrecognized_even_field: false,
+              metadata: None,
             }
             .to_witness(),
           ),
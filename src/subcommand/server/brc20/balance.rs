@@ -24,7 +24,7 @@ pub struct Balance {
     get,
     path = "/api/v1/brc20/tick/{ticker}/address/{address}/balance",
     params(
-        ("ticker" = String, Path, description = "Token ticker", min_length = 4, max_length = 4),
+        ("ticker" = String, Path, description = "Token ticker", min_length = 4, max_length = 5),
         ("address" = String, Path, description = "Address")
   ),
     responses(
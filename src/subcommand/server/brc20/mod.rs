@@ -1,6 +1,7 @@
 use super::{types::ScriptPubkey, *};
 mod balance;
 mod receipt;
+mod search;
 mod ticker;
 mod transaction;
 mod transferable;
@@ -19,6 +20,8 @@ pub(super) enum BRC20Error {
   EventsNotFound,
   #[error("block not found")]
   BlockNotFound,
+  #[error("search keyword must not be empty")]
+  EmptySearchKeyword,
 }
 
-pub(super) use {balance::*, receipt::*, ticker::*, transaction::*, transferable::*};
+pub(super) use {balance::*, receipt::*, search::*, ticker::*, transaction::*, transferable::*};
@@ -17,6 +17,9 @@ pub enum TxEvent {
   #[schema(value_type = brc20::TransferEvent)]
   /// Event generated by transfer.
   Transfer(TransferEvent),
+  /// Event generated by transfer to an OP_RETURN output.
+  #[schema(value_type = brc20::BurnEvent)]
+  Burn(BurnEvent),
   /// Event generated by the execution has failed.
   #[schema(value_type = brc20::ErrorEvent)]
   Error(ErrorEvent),
@@ -167,6 +170,35 @@ pub struct TransferEvent {
   pub msg: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(as = brc20::BurnEvent)]
+#[serde(rename_all = "camelCase")]
+pub struct BurnEvent {
+  /// Event type.
+  #[serde(rename = "type")]
+  pub event: String,
+  /// The ticker of burn.
+  pub tick: String,
+  /// The inscription id.
+  pub inscription_id: String,
+  /// The inscription number.
+  pub inscription_number: i64,
+  /// The inscription satpoint of the transaction input.
+  pub old_satpoint: String,
+  /// The inscription satpoint of the transaction output.
+  pub new_satpoint: String,
+  /// The amount of burn.
+  pub amount: String,
+  /// The message sender which is an address or script pubkey hash.
+  pub from: ScriptPubkey,
+  /// The message receiver which is the script pubkey hash of the OP_RETURN output.
+  pub to: ScriptPubkey,
+  /// Executed state.
+  pub valid: bool,
+  /// Message generated during execution.
+  pub msg: String,
+}
+
 impl From<&brc20_store::Receipt> for TxEvent {
   fn from(event: &brc20_store::Receipt) -> Self {
     match &event.result {
@@ -226,6 +258,19 @@ impl From<&brc20_store::Receipt> for TxEvent {
         msg: trans2.msg.clone().unwrap_or("ok".to_string()),
         event: "transfer".to_string(),
       }),
+      Ok(brc20_store::Event::Burn(burn_event)) => Self::Burn(BurnEvent {
+        tick: burn_event.tick.to_string(),
+        inscription_id: event.inscription_id.to_string(),
+        inscription_number: event.inscription_number,
+        old_satpoint: event.old_satpoint.to_string(),
+        new_satpoint: event.new_satpoint.to_string(),
+        amount: burn_event.amount.to_string(),
+        from: event.from.clone().into(),
+        to: event.to.clone().into(),
+        valid: true,
+        msg: "ok".to_string(),
+        event: "burn".to_string(),
+      }),
       Err(err) => Self::Error(ErrorEvent {
         inscription_id: event.inscription_id.to_string(),
         inscription_number: event.inscription_number,
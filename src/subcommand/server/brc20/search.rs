@@ -0,0 +1,146 @@
+use {
+  super::*, crate::okx::datastore::brc20::Tick, axum::Json, utoipa::IntoParams, utoipa::ToSchema,
+};
+
+const DEFAULT_SEARCH_LIMIT: usize = 20;
+const MAX_SEARCH_LIMIT: usize = 100;
+
+#[derive(Deserialize, IntoParams)]
+pub struct TickSearchQuery {
+  /// Keyword of the ticker name, case insensitive.
+  pub q: String,
+  /// Limit of the result, default 20, maximum 100.
+  pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(as = brc20::TickSearchResult)]
+#[serde(rename_all = "camelCase")]
+/// A ticker matching the search keyword.
+pub struct TickSearchResult {
+  /// Protocol of the ticker, `BRC20` or `BRC20-S`.
+  pub protocol: String,
+  /// Name of the ticker.
+  pub tick: String,
+  /// Identifier of the ticker.<br>
+  /// The BRC20 ticker name, or the BRC20S ticker ID.
+  pub tick_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(as = brc20::TickSearchResults)]
+#[serde(rename_all = "camelCase")]
+/// Tickers whose name starts with the search keyword.
+pub struct TickSearchResults {
+  #[schema(value_type = Vec<brc20::TickSearchResult>)]
+  pub ticks: Vec<TickSearchResult>,
+}
+
+/// Search tickers by name.
+///
+/// Retrieve the BRC20 and BRC20S tickers whose name starts with the keyword.
+#[utoipa::path(
+    get,
+    path = "/api/v1/brc20/search",
+    params(
+      TickSearchQuery
+  ),
+    responses(
+      (status = 200, description = "Obtain matching BRC20 and BRC20S tickers by keyword.", body = BRC20TickSearch),
+      (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request(BRC20Error::EmptySearchKeyword))),
+      (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+    )
+  )]
+pub(crate) async fn brc20_tick_search(
+  Extension(index): Extension<Arc<Index>>,
+  Query(query): Query<TickSearchQuery>,
+) -> ApiResult<TickSearchResults> {
+  log::debug!("rpc: get brc20_tick_search: {}", query.q);
+
+  let keyword = query.q.trim();
+  if keyword.is_empty() {
+    return Err(ApiError::bad_request(BRC20Error::EmptySearchKeyword));
+  }
+
+  let limit = query
+    .limit
+    .unwrap_or(DEFAULT_SEARCH_LIMIT)
+    .min(MAX_SEARCH_LIMIT);
+
+  let ticks = search_ticks(&index, keyword, limit)?;
+
+  log::debug!("rpc: get brc20_tick_search: {} {:?}", keyword, ticks);
+
+  Ok(Json(ApiResponse::ok(TickSearchResults { ticks })))
+}
+
+fn search_ticks(index: &Index, keyword: &str, limit: usize) -> Result<Vec<TickSearchResult>> {
+  let mut ticks = index
+    .brc20_search_ticks(keyword, limit)?
+    .into_iter()
+    .map(|tick| TickSearchResult {
+      protocol: "BRC20".to_string(),
+      tick: tick.to_string(),
+      tick_id: tick.to_string(),
+    })
+    .collect::<Vec<_>>();
+
+  ticks.extend(
+    index
+      .brc20s_search_ticks(keyword, limit - ticks.len())?
+      .into_iter()
+      .map(|(name, tick_id)| TickSearchResult {
+        protocol: "BRC20-S".to_string(),
+        tick: name.as_str().to_string(),
+        tick_id: tick_id.hex(),
+      }),
+  );
+
+  Ok(ticks)
+}
+
+/// Returns the page of the BRC20 ticker named `name`, or else of the first BRC20S ticker with that name.
+pub(crate) fn tick_page(index: &Index, name: &str) -> Result<Option<String>> {
+  if let Ok(tick) = Tick::from_str(name) {
+    if index.brc20_get_tick_info(&tick)?.is_some() {
+      return Ok(Some(format!(
+        "/api/v1/brc20/tick/{}",
+        Server::percent_encode(tick.as_str())
+      )));
+    }
+  }
+
+  Ok(
+    index
+      .brc20s_tick_ids_by_name(name)?
+      .first()
+      .map(|tick_id| format!("/api/v1/brc20s/tick/{}", tick_id.hex())),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_serialize_tick_search_results() {
+    let results = TickSearchResults {
+      ticks: vec![
+        TickSearchResult {
+          protocol: "BRC20".to_string(),
+          tick: "ordi".to_string(),
+          tick_id: "ordi".to_string(),
+        },
+        TickSearchResult {
+          protocol: "BRC20-S".to_string(),
+          tick: "ords".to_string(),
+          tick_id: "f7c515d6b7".to_string(),
+        },
+      ],
+    };
+    assert_eq!(
+      serde_json::to_string(&results).unwrap(),
+      r#"{"ticks":[{"protocol":"BRC20","tick":"ordi","tickId":"ordi"},{"protocol":"BRC20-S","tick":"ords","tickId":"f7c515d6b7"}]}"#
+    );
+  }
+}
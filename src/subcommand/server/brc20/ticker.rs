@@ -29,6 +29,10 @@ pub struct TickInfo {
   /// The amount of the ticker that has been minted.
   #[schema(format = "uint64")]
   pub minted: String,
+  /// The amount of the ticker burned by transfers to OP_RETURN outputs, minted less burned is the
+  /// circulating supply.
+  #[schema(format = "uint64")]
+  pub burned: String,
   /// The decimal of the ticker.<br>
   /// Number of decimals cannot exceed 18 (default).
   #[schema(
@@ -50,6 +54,8 @@ pub struct TickInfo {
   /// The timestamp of the block that the ticker deployed.
   #[schema(format = "uint32")]
   pub deploy_blocktime: u32,
+  /// Whether only the children of the deploy inscription can mint the ticker.
+  pub self_mint: bool,
 }
 
 impl From<TokenInfo> for TickInfo {
@@ -61,11 +67,13 @@ impl From<TokenInfo> for TickInfo {
       supply: tick_info.supply.to_string(),
       limit_per_mint: tick_info.limit_per_mint.to_string(),
       minted: tick_info.minted.to_string(),
+      burned: tick_info.burned.to_string(),
       decimal: tick_info.decimal,
       deploy_by: tick_info.deploy_by.clone().into(),
       txid: tick_info.inscription_id.txid.to_string(),
       deploy_height: tick_info.deployed_number,
       deploy_blocktime: tick_info.deployed_timestamp,
+      self_mint: tick_info.self_mint,
     }
   }
 }
@@ -77,7 +85,7 @@ impl From<TokenInfo> for TickInfo {
     get,
     path = "/api/v1/brc20/tick/{ticker}",
     params(
-      ("ticker" = String, Path, description = "Token ticker", min_length = 4, max_length = 4)
+      ("ticker" = String, Path, description = "Token ticker", min_length = 4, max_length = 5)
   ),
     responses(
       (status = 200, description = "Obtain matching BRC20 ticker by query.", body = BRC20Tick),
@@ -2,7 +2,7 @@ use {
   super::*,
   crate::okx::{
     datastore::{brc20::redb as brc20_db, ScriptKey},
-    protocol::brc20 as brc20_proto,
+    protocol::{brc20 as brc20_proto, ord as ord_proto},
   },
   axum::Json,
 };
@@ -78,6 +78,8 @@ pub struct Deploy {
   pub lim: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub dec: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub self_mint: Option<String>,
 }
 
 impl From<brc20_proto::Deploy> for Deploy {
@@ -87,6 +89,7 @@ impl From<brc20_proto::Deploy> for Deploy {
       max: deploy.max_supply,
       lim: deploy.mint_limit,
       dec: deploy.decimals,
+      self_mint: deploy.self_mint,
     }
   }
 }
@@ -161,8 +164,13 @@ fn get_operations_by_txid(
 
   let rtx = index.begin_read()?.0;
   let brc20_store = brc20_db::DataStoreReader::new(&rtx);
-  for operation in operations {
-    match brc20_proto::Message::resolve(&brc20_store, &new_inscriptions, &operation)? {
+  for operation in &operations {
+    match brc20_proto::Message::resolve(
+      &brc20_store,
+      &new_inscriptions,
+      operation,
+      ord_proto::verified_parent(operation, &operations),
+    )? {
       None => continue,
       Some(msg) => brc20_operation_infos.push(InscriptionInfo {
         action: match msg.op {
@@ -214,6 +222,7 @@ mod tests {
       max: "1000".to_string(),
       lim: Some("1000".to_string()),
       dec: Some("18".to_string()),
+      self_mint: None,
     };
     assert_eq!(
       serde_json::to_string(&deploy).unwrap(),
@@ -224,6 +233,7 @@ mod tests {
       max: "1000".to_string(),
       lim: None,
       dec: None,
+      self_mint: None,
     };
     assert_eq!(
       serde_json::to_string(&deploy).unwrap(),
@@ -262,6 +272,7 @@ mod tests {
       max: "1000".to_string(),
       lim: Some("1000".to_string()),
       dec: Some("18".to_string()),
+      self_mint: None,
     });
     assert_eq!(
       serde_json::to_string(&deploy).unwrap(),
@@ -456,6 +467,7 @@ mod tests {
           max: "1000".to_string(),
           lim: Some("1000".to_string()),
           dec: Some("18".to_string()),
+          self_mint: None,
         },
       ))),
     };
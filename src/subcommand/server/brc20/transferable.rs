@@ -36,7 +36,7 @@ impl From<&brc20_store::TransferableLog> for TransferableInscription {
   get,
   path = "/api/v1/brc20/tick/{ticker}/address/{address}/transferable",
   params(
-      ("ticker" = String, Path, description = "Token ticker", min_length = 4, max_length = 4),
+      ("ticker" = String, Path, description = "Token ticker", min_length = 4, max_length = 5),
       ("address" = String, Path, description = "Address")
 ),
   responses(
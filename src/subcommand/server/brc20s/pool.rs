@@ -1,7 +1,9 @@
 use {
   super::*,
+  crate::okx::datastore::ScriptKey,
   axum::Json,
   brc20s::{Pid, PoolInfo, TickId},
+  utoipa::IntoParams,
 };
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 #[schema(as = brc20s::Pool)]
@@ -284,3 +286,247 @@ pub(crate) async fn brc20s_all_pools_by_tid(
     total: all_pool_info.len(),
   })))
 }
+
+const DEFAULT_POOL_USERS_LIMIT: usize = 20;
+const MAX_POOL_USERS_LIMIT: usize = 100;
+
+#[derive(Deserialize, IntoParams)]
+pub struct PoolUsersQuery {
+  /// Field to sort by, `staked`, `pendingReward` or `minted`, default `staked`.
+  pub sort: Option<String>,
+  /// Sort order, `asc` or `desc`, default `desc`.
+  pub order: Option<String>,
+  /// Start index of the result.
+  pub start: Option<usize>,
+  /// Limit of the result, default 20, maximum 100.
+  pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = brc20s::PoolUser)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PoolUser {
+  /// The user.
+  pub address: ScriptPubkey,
+  /// The amount of tokens staked by the user.
+  #[schema(format = "uint64")]
+  pub staked: String,
+  /// The amount of reward tokens the user can mint at the queried block.
+  #[schema(format = "uint64")]
+  pub pending_reward: String,
+  /// The amount of reward tokens minted by the user so far.
+  #[schema(format = "uint64")]
+  pub minted: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(as = brc20s::PoolUsers)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PoolUsers {
+  /// The pool id.
+  pub pid: String,
+  /// The height of the block being queried.
+  #[schema(format = "uint64")]
+  pub block_num: u64,
+  #[schema(value_type = Vec<brc20s::PoolUser>)]
+  pub users: Vec<PoolUser>,
+  /// The number of users of the pool.
+  pub total: usize,
+}
+
+// brc20s/pool/:pid/users
+
+/// Get the users of the pool.
+///
+/// Retrieve the stakers of the pool with their staked amount, pending reward and minted reward.
+#[utoipa::path(
+  get,
+  path = "/api/v1/brc20s/pool/{pid}/users",
+  params(
+      ("pid" = String, Path, description = "Pool ID", min_length = 13, max_length = 13, example= "a01234567f#0f"),
+      PoolUsersQuery
+),
+  responses(
+    (status = 200, description = "Obtain the users of the pool", body = BRC20SPoolUsers),
+    (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request("bad request"))),
+    (status = 404, description = "Not found.", body = ApiError, example = json!(&ApiError::not_found("not found"))),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn brc20s_pool_users(
+  Extension(index): Extension<Arc<Index>>,
+  Path(pid): Path<String>,
+  Query(query): Query<PoolUsersQuery>,
+) -> ApiResult<PoolUsers> {
+  log::debug!("rpc: get brc20s_pool_users: {}", pid);
+
+  let pid =
+    Pid::from_str(&pid).map_err(|_| ApiError::bad_request(BRC20SError::IncorrectPidFormat))?;
+  let sort_key: fn(&(ScriptKey, brc20s::UserInfo, u128)) -> u128 =
+    match query.sort.as_deref().unwrap_or("staked") {
+      "staked" => |(_, user_info, _)| user_info.staked,
+      "pendingReward" => |(_, _, pending_reward)| *pending_reward,
+      "minted" => |(_, user_info, _)| user_info.minted,
+      sort => return Err(ApiError::bad_request(format!("unknown sort field: {sort}"))),
+    };
+  let descending = match query.order.as_deref().unwrap_or("desc") {
+    "asc" => false,
+    "desc" => true,
+    order => return Err(ApiError::bad_request(format!("unknown order: {order}"))),
+  };
+  let limit = query.limit.unwrap_or(DEFAULT_POOL_USERS_LIMIT);
+  if limit > MAX_POOL_USERS_LIMIT {
+    return Err(ApiError::bad_request(format!(
+      "limit must not exceed {MAX_POOL_USERS_LIMIT}"
+    )));
+  }
+
+  let (block_num, mut users) = index
+    .brc20s_pool_users(&pid)?
+    .ok_or_api_not_found(BRC20SError::PoolInfoNotFound)?;
+
+  log::debug!(
+    "rpc: get brc20s_pool_users: {:?}, {} users",
+    pid.as_str(),
+    users.len()
+  );
+
+  // the script breaks ties so that pages are stable between requests.
+  users.sort_by(|a, b| {
+    let ordering = sort_key(a).cmp(&sort_key(b));
+    if descending {
+      ordering.reverse()
+    } else {
+      ordering
+    }
+    .then_with(|| a.0.to_string().cmp(&b.0.to_string()))
+  });
+
+  Ok(Json(ApiResponse::ok(PoolUsers {
+    pid: pid.as_str().to_string(),
+    block_num,
+    total: users.len(),
+    users: users
+      .into_iter()
+      .skip(query.start.unwrap_or(0))
+      .take(limit)
+      .map(|(script_key, user_info, pending_reward)| PoolUser {
+        address: script_key.into(),
+        staked: user_info.staked.to_string(),
+        pending_reward: pending_reward.to_string(),
+        minted: user_info.minted.to_string(),
+      })
+      .collect(),
+  })))
+}
+
+const MAX_POOL_HISTORY_SNAPSHOTS: usize = 1000;
+
+#[derive(Deserialize, IntoParams)]
+pub struct PoolHistoryQuery {
+  /// The first block of the history, inclusive, default 0.
+  pub from: Option<u64>,
+  /// The last block of the history, inclusive, default the latest block.
+  pub to: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = brc20s::PoolSnapshot)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PoolSnapshot {
+  /// The height of the block in which the pool changed.
+  #[schema(format = "uint64")]
+  pub block_num: u64,
+  /// The amount of the ticker staked at the end of the block.
+  pub staked: String,
+  /// The amount of the ticker minted at the end of the block.
+  pub minted: String,
+  /// The accumulated reward per share at the end of the block.
+  pub acc_reward_per_share: String,
+  /// The latest update block number.
+  #[schema(format = "uint64")]
+  pub latest_update_block: u64,
+}
+
+impl From<&brc20s::PoolSnapshot> for PoolSnapshot {
+  fn from(snapshot: &brc20s::PoolSnapshot) -> Self {
+    Self {
+      block_num: snapshot.block_num,
+      staked: snapshot.staked.to_string(),
+      minted: snapshot.minted.to_string(),
+      acc_reward_per_share: snapshot.acc_reward_per_share.clone(),
+      latest_update_block: snapshot.last_update_block,
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = brc20s::PoolHistory)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PoolHistory {
+  /// The pool id.
+  pub pid: String,
+  /// The snapshots of the pool in ascending block order, at most 1000.
+  #[schema(value_type = Vec<brc20s::PoolSnapshot>)]
+  pub snapshots: Vec<PoolSnapshot>,
+  /// The `from` of the next page, null if the history is complete.
+  #[schema(format = "uint64")]
+  pub next: Option<u64>,
+}
+
+// brc20s/pool/:pid/history
+
+/// Get the history of the pool.
+///
+/// Retrieve the state of the pool at the end of each block in which it was deposited, withdrawn or minted.
+#[utoipa::path(
+  get,
+  path = "/api/v1/brc20s/pool/{pid}/history",
+  params(
+      ("pid" = String, Path, description = "Pool ID", min_length = 13, max_length = 13, example= "a01234567f#0f"),
+      PoolHistoryQuery
+),
+  responses(
+    (status = 200, description = "Obtain the history of the pool", body = BRC20SPoolHistory),
+    (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request("bad request"))),
+    (status = 404, description = "Not found.", body = ApiError, example = json!(&ApiError::not_found("not found"))),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn brc20s_pool_history(
+  Extension(index): Extension<Arc<Index>>,
+  Path(pid): Path<String>,
+  Query(query): Query<PoolHistoryQuery>,
+) -> ApiResult<PoolHistory> {
+  log::debug!("rpc: get brc20s_pool_history: {}", pid);
+
+  let pid =
+    Pid::from_str(&pid).map_err(|_| ApiError::bad_request(BRC20SError::IncorrectPidFormat))?;
+  let from = query.from.unwrap_or(0);
+  let to = query.to.unwrap_or(u64::MAX);
+  if from > to {
+    return Err(ApiError::bad_request("from must not exceed to"));
+  }
+
+  // the extra snapshot tells whether the history continues after this page.
+  let mut snapshots = index
+    .brc20s_pool_history(&pid, from, to, MAX_POOL_HISTORY_SNAPSHOTS + 1)?
+    .ok_or_api_not_found(BRC20SError::PoolInfoNotFound)?;
+  let next = if snapshots.len() > MAX_POOL_HISTORY_SNAPSHOTS {
+    snapshots.pop().map(|snapshot| snapshot.block_num)
+  } else {
+    None
+  };
+
+  log::debug!(
+    "rpc: get brc20s_pool_history: {:?}, {} snapshots",
+    pid.as_str(),
+    snapshots.len()
+  );
+
+  Ok(Json(ApiResponse::ok(PoolHistory {
+    pid: pid.as_str().to_string(),
+    snapshots: snapshots.iter().map(PoolSnapshot::from).collect(),
+    next,
+  })))
+}
@@ -8,6 +8,7 @@ use {
     subcommand::server::brc20::BRC20Error,
   },
   axum::Json,
+  utoipa::IntoParams,
 };
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
@@ -139,6 +140,141 @@ pub(crate) async fn brc20s_user_pending_reward(
   })))
 }
 
+const DEFAULT_PROJECTION_BLOCKS: u64 = 144;
+const MAX_PROJECTION_BLOCKS: u64 = 10_000;
+
+#[derive(Deserialize, IntoParams)]
+pub struct RewardProjectionQuery {
+  /// The hypothetical deposit in the smallest unit of the staked ticker, default 0.
+  pub amount: Option<String>,
+  /// The number of blocks to project after the deposit, default 144, maximum 10000.
+  pub blocks: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = brc20s::BlockReward)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockReward {
+  /// The block height.
+  #[schema(format = "uint64")]
+  pub block_num: u64,
+  /// The amount of reward tokens earned in the block.
+  pub reward: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = brc20s::RewardProjection)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardProjection {
+  /// The pool id.
+  pub pid: String,
+  /// Pool type. Such as "pool", "fixed".
+  pub pool: String,
+  /// The height of the next block, where the deposit is projected.
+  #[schema(format = "uint64")]
+  pub block_num: u64,
+  /// The amount of the hypothetical deposit.
+  pub deposit: String,
+  /// The amount of tokens staked by the user after the deposit.
+  pub staked: String,
+  /// The amount of tokens staked in the pool after the deposit.
+  pub pool_staked: String,
+  /// The projected reward of the user in each block after the deposit.
+  #[schema(value_type = Vec<brc20s::BlockReward>)]
+  pub rewards: Vec<BlockReward>,
+  /// The sum of the projected rewards.
+  pub total_reward: String,
+  /// The number of blocks after the deposit until the pool has minted dmax, null if it never does with the current stakes.
+  #[schema(format = "uint64")]
+  pub blocks_until_dmax: Option<u64>,
+  /// The average reward per block for each whole staked token, the erate of fixed pools before dmax is reached.
+  pub effective_rate: String,
+}
+
+// brc20s/pool/:pid/address/:address/projection
+
+/// Project the reward of a deposit.
+///
+/// Simulate a deposit in the next block and the rewards of the user over the following blocks, with the other stakes of the pool unchanged.
+#[utoipa::path(
+  get,
+  path = "/api/v1/brc20s/pool/{pid}/address/{address}/projection",
+  params(
+      ("pid" = String, Path, description = "Pool ID", min_length = 13, max_length = 13, example= "a01234567f#0f"),
+      ("address" = String, Path, description = "Address"),
+      RewardProjectionQuery
+),
+  responses(
+    (status = 200, description = "Obtain the projected rewards of the deposit", body = BRC20SRewardProjection),
+    (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request("bad request"))),
+    (status = 404, description = "Not found.", body = ApiError, example = json!(&ApiError::not_found("not found"))),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn brc20s_reward_projection(
+  Extension(index): Extension<Arc<Index>>,
+  Path((pid, address)): Path<(String, String)>,
+  Query(query): Query<RewardProjectionQuery>,
+) -> ApiResult<RewardProjection> {
+  log::debug!("rpc: get brc20s_reward_projection: {}, {}", pid, address);
+
+  let pid =
+    Pid::from_str(&pid).map_err(|_| ApiError::bad_request(BRC20SError::IncorrectPidFormat))?;
+  let address: bitcoin::Address = Address::from_str(&address)
+    .and_then(|address| address.require_network(index.get_chain_network()))
+    .map_err(ApiError::bad_request)?;
+  let deposit = query
+    .amount
+    .map_or(Ok(0), |amount| amount.parse::<u128>())
+    .map_err(ApiError::bad_request)?;
+  let blocks = query.blocks.unwrap_or(DEFAULT_PROJECTION_BLOCKS);
+  if blocks > MAX_PROJECTION_BLOCKS {
+    return Err(ApiError::bad_request(format!(
+      "blocks must not exceed {MAX_PROJECTION_BLOCKS}"
+    )));
+  }
+
+  let (block_num, pool_info, user_info, projection) = index
+    .brc20s_reward_projection(&pid, &address, deposit, blocks)?
+    .ok_or_api_not_found(BRC20SError::PoolInfoNotFound)?;
+
+  // deposits and projections too large for the reward computation are rejected.
+  let overflow = || ApiError::bad_request("amount or blocks overflow the projected reward");
+  let projection = projection.map_err(|_| overflow())?;
+  let staked = user_info.staked.checked_add(deposit).ok_or_else(overflow)?;
+  let pool_staked = pool_info.staked.checked_add(deposit).ok_or_else(overflow)?;
+  let total_reward = projection
+    .rewards
+    .iter()
+    .try_fold(0u128, |total, reward| total.checked_add(*reward))
+    .ok_or_else(overflow)?;
+
+  log::debug!(
+    "rpc: get brc20s_reward_projection: {:?}, {:?}",
+    pid.as_str(),
+    projection,
+  );
+
+  Ok(Json(ApiResponse::ok(RewardProjection {
+    pid: pid.as_str().to_string(),
+    pool: pool_info.ptype.to_string(),
+    block_num,
+    deposit: deposit.to_string(),
+    staked: staked.to_string(),
+    pool_staked: pool_staked.to_string(),
+    rewards: (block_num + 1..)
+      .zip(&projection.rewards)
+      .map(|(block_num, reward)| BlockReward {
+        block_num,
+        reward: reward.to_string(),
+      })
+      .collect(),
+    total_reward: total_reward.to_string(),
+    blocks_until_dmax: projection.blocks_until_dmax,
+    effective_rate: projection.effective_rate.to_string(),
+  })))
+}
+
 // brc20s/pool/:pid/address/:address/userinfo
 
 /// Get the user info of the pool.
@@ -0,0 +1,122 @@
+use {
+  super::{error::ApiError, *},
+  axum::Json,
+  utoipa::{IntoParams, ToSchema},
+};
+
+#[derive(Deserialize, IntoParams)]
+pub struct ContentTypeFilter {
+  /// Only return inscriptions of the content type, case insensitive.<br>
+  /// Parameters are ignored, so `text/plain` matches `text/plain;charset=utf-8`.
+  pub content_type: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = ord::AddressInscriptions)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressInscriptions {
+  /// The inscriptions owned by the address, ordered by inscription number.
+  #[schema(value_type = Vec<ord::InscriptionDigest>)]
+  pub inscriptions: Vec<InscriptionDigest>,
+  /// The number of inscriptions matching the query.
+  pub total: usize,
+}
+
+fn content_type_matches(content_type: Option<&str>, filter: &str) -> bool {
+  content_type
+    .and_then(|content_type| content_type.split(';').next())
+    .map(|media_type| media_type.trim().eq_ignore_ascii_case(filter.trim()))
+    .unwrap_or(false)
+}
+
+// /ord/address/:address/inscriptions
+/// Retrieve the inscriptions owned by the address.
+///
+/// Inscriptions are ordered by inscription number.
+#[utoipa::path(
+  get,
+  path = "/api/v1/ord/address/{address}/inscriptions",
+  params(
+      ("address" = String, Path, description = "Address"),
+      Pagination,
+      ContentTypeFilter,
+),
+  responses(
+    (status = 200, description = "Obtain the inscriptions owned by the address.", body = OrdAddressInscriptions),
+    (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request("bad request"))),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn ord_address_inscriptions(
+  Extension(index): Extension<Arc<Index>>,
+  Path(address): Path<String>,
+  Query(page): Query<Pagination>,
+  Query(filter): Query<ContentTypeFilter>,
+) -> ApiResult<AddressInscriptions> {
+  log::debug!("rpc: get ord_address_inscriptions: {address}");
+
+  let address: bitcoin::Address = Address::from_str(&address)
+    .and_then(|address| address.require_network(index.get_chain_network()))
+    .map_err(ApiError::bad_request)?;
+
+  let mut inscriptions = index.ord_get_inscriptions_by_address(&address)?;
+
+  if let Some(content_type) = &filter.content_type {
+    let content_types =
+      index.ord_get_inscription_content_types(inscriptions.iter().map(|(number, _)| *number))?;
+    inscriptions = inscriptions
+      .into_iter()
+      .zip(content_types)
+      .filter(|(_, inscription_content_type)| {
+        content_type_matches(inscription_content_type.as_deref(), content_type)
+      })
+      .map(|(inscription, _)| inscription)
+      .collect();
+  }
+
+  let total = inscriptions.len();
+
+  let mut inscription_digests = Vec::new();
+  for (number, id) in inscriptions
+    .into_iter()
+    .skip(page.start.unwrap_or(0))
+    .take(page.limit.unwrap_or(usize::MAX))
+  {
+    inscription_digests.push(InscriptionDigest {
+      id: id.to_string(),
+      number,
+      location: index
+        .get_inscription_satpoint_by_id(id)?
+        .ok_or(anyhow!(
+          "Failed to get the inscription location, there may be an error in the database."
+        ))?
+        .to_string(),
+    });
+  }
+
+  log::debug!(
+    "rpc: get ord_address_inscriptions: {address} {:?}",
+    inscription_digests
+  );
+
+  Ok(Json(ApiResponse::ok(AddressInscriptions {
+    inscriptions: inscription_digests,
+    total,
+  })))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn content_type_filter() {
+    assert!(content_type_matches(Some("text/plain"), "text/plain"));
+    assert!(content_type_matches(
+      Some("text/plain;charset=utf-8"),
+      "TEXT/PLAIN"
+    ));
+    assert!(!content_type_matches(Some("text/html"), "text/plain"));
+    assert!(!content_type_matches(None, "text/plain"));
+  }
+}
@@ -0,0 +1,206 @@
+use {
+  super::{error::ApiError, types::ScriptPubkey, *},
+  crate::okx::datastore::{
+    ord::bitmap::{District, Parcel},
+    ScriptKey,
+  },
+  axum::Json,
+  utoipa::ToSchema,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = ord::BitmapDistrict)]
+#[serde(rename_all = "camelCase")]
+pub struct BitmapDistrict {
+  /// The district number, which is the height of its block.
+  pub number: u64,
+  /// The id of the inscription that registered the district.
+  pub inscription_id: String,
+  /// The number of the inscription that registered the district.
+  pub inscription_number: i64,
+  /// The inscription location.
+  pub location: String,
+  /// The district holder.<br>
+  /// `None` if the inscription is unbound or lost in the coinbase.
+  pub owner: Option<ScriptPubkey>,
+  /// The hash of the district's block.
+  pub block_hash: String,
+  /// The timestamp of the district's block.
+  pub block_time: u32,
+  /// The number of transactions of the district's block, which is the number of its parcels.
+  pub transactions: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = ord::BitmapParcel)]
+#[serde(rename_all = "camelCase")]
+pub struct BitmapParcel {
+  /// The parcel, like `0.840000.bitmap`.
+  pub parcel: String,
+  /// The index of the parcel's transaction in the district's block.
+  pub index: u64,
+  /// The district number of the parcel.
+  pub district: u64,
+  /// The id of the inscription that registered the parcel.
+  pub inscription_id: String,
+  /// The number of the inscription that registered the parcel.
+  pub inscription_number: i64,
+  /// The inscription location.
+  pub location: String,
+  /// The parcel holder.<br>
+  /// `None` if the inscription is unbound or lost in the coinbase.
+  pub owner: Option<ScriptPubkey>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = ord::AddressBitmap)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBitmap {
+  /// The districts owned by the address, ordered by inscription number.
+  #[schema(value_type = Vec<ord::BitmapDistrict>)]
+  pub districts: Vec<BitmapDistrict>,
+  /// The parcels owned by the address, ordered by inscription number.
+  #[schema(value_type = Vec<ord::BitmapParcel>)]
+  pub parcels: Vec<BitmapParcel>,
+}
+
+fn inscription_location(
+  index: &Index,
+  id: InscriptionId,
+) -> Result<(SatPoint, Option<ScriptPubkey>)> {
+  let satpoint = index.get_inscription_satpoint_by_id(id)?.ok_or(anyhow!(
+    "Failed to get the inscription location, there may be an error in the database."
+  ))?;
+
+  let owner = index
+    .get_outpoint_entry(satpoint.outpoint)?
+    .map(|tx_out| ScriptKey::from_script(&tx_out.script_pubkey, index.get_chain_network()).into());
+
+  Ok((satpoint, owner))
+}
+
+fn bitmap_district(
+  index: &Index,
+  district: District,
+  number: i64,
+  id: InscriptionId,
+) -> Result<BitmapDistrict> {
+  let (satpoint, owner) = inscription_location(index, id)?;
+
+  let block_hash = index.block_hash(Some(district.number))?.ok_or(anyhow!(
+    "Failed to get the block of district {}.",
+    district.number
+  ))?;
+  let header = index
+    .block_header_info(block_hash)?
+    .ok_or(anyhow!("Failed to get the block header {block_hash}."))?;
+
+  Ok(BitmapDistrict {
+    number: district.number,
+    inscription_id: id.to_string(),
+    inscription_number: number,
+    location: satpoint.to_string(),
+    owner,
+    block_hash: block_hash.to_string(),
+    block_time: u32::try_from(header.time).unwrap(),
+    transactions: u64::try_from(header.n_tx).unwrap(),
+  })
+}
+
+fn bitmap_parcel(
+  index: &Index,
+  parcel: Parcel,
+  number: i64,
+  id: InscriptionId,
+) -> Result<BitmapParcel> {
+  let (satpoint, owner) = inscription_location(index, id)?;
+
+  Ok(BitmapParcel {
+    parcel: parcel.to_string(),
+    index: parcel.index,
+    district: parcel.district,
+    inscription_id: id.to_string(),
+    inscription_number: number,
+    location: satpoint.to_string(),
+    owner,
+  })
+}
+
+// /ord/bitmap/district/:number
+/// Retrieve a bitmap district and the data of its block.
+#[utoipa::path(
+  get,
+  path = "/api/v1/ord/bitmap/district/{number}",
+  params(
+      ("number" = u64, Path, description = "District number"),
+),
+  responses(
+    (status = 200, description = "Obtain the district.", body = OrdBitmapDistrict),
+    (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request("bad request"))),
+    (status = 404, description = "District not found.", body = ApiError, example = json!(&ApiError::not_found("district not found"))),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn ord_bitmap_district(
+  Extension(index): Extension<Arc<Index>>,
+  Path(number): Path<u64>,
+) -> ApiResult<BitmapDistrict> {
+  log::debug!("rpc: get ord_bitmap_district: {number}");
+
+  let id = index
+    .ord_get_district_inscription_id(number)?
+    .ok_or_api_not_found(format!("district {number} not found"))?;
+
+  let inscription_number = index
+    .get_inscription_entry(id)?
+    .ok_or_api_err(|| ApiError::internal(format!("Failed to get inscription {id}.")))?
+    .number;
+
+  let district = bitmap_district(&index, District { number }, inscription_number, id)?;
+
+  log::debug!("rpc: get ord_bitmap_district: {:?}", district);
+
+  Ok(Json(ApiResponse::ok(district)))
+}
+
+// /ord/address/:address/bitmap
+/// Retrieve the bitmap districts and parcels owned by the address.
+#[utoipa::path(
+  get,
+  path = "/api/v1/ord/address/{address}/bitmap",
+  params(
+      ("address" = String, Path, description = "Address"),
+),
+  responses(
+    (status = 200, description = "Obtain the districts and parcels owned by the address.", body = OrdAddressBitmap),
+    (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request("bad request"))),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn ord_address_bitmap(
+  Extension(index): Extension<Arc<Index>>,
+  Path(address): Path<String>,
+) -> ApiResult<AddressBitmap> {
+  log::debug!("rpc: get ord_address_bitmap: {address}");
+
+  let address: bitcoin::Address = Address::from_str(&address)
+    .and_then(|address| address.require_network(index.get_chain_network()))
+    .map_err(ApiError::bad_request)?;
+
+  let (districts, parcels) = index.ord_get_bitmap_by_address(&address)?;
+
+  let bitmap = AddressBitmap {
+    districts: districts
+      .into_iter()
+      .map(|(district, number, id)| bitmap_district(&index, district, number, id))
+      .collect::<Result<_>>()?,
+    parcels: parcels
+      .into_iter()
+      .map(|(parcel, number, id)| bitmap_parcel(&index, parcel, number, id))
+      .collect::<Result<_>>()?,
+  };
+
+  log::debug!("rpc: get ord_address_bitmap: {address} {:?}", bitmap);
+
+  Ok(Json(ApiResponse::ok(bitmap)))
+}
@@ -0,0 +1,143 @@
+use {
+  super::{error::ApiError, types::ScriptPubkey, *},
+  crate::okx::datastore::ord::InscriptionTransfer,
+  axum::Json,
+  utoipa::ToSchema,
+};
+
+const DEFAULT_HISTORY_LIMIT: usize = 20;
+const MAX_HISTORY_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = ord::InscriptionTransferEvent)]
+#[serde(rename_all = "camelCase")]
+pub struct InscriptionTransferEvent {
+  /// The block height of the transaction.
+  #[schema(format = "uint64")]
+  pub height: u64,
+  /// The transaction id.
+  pub txid: String,
+  /// The action, `inscribe` for the genesis or `transfer`.
+  pub action: String,
+  /// The inscription location after the transaction.
+  pub location: String,
+  /// The inscription owner after the transaction.<br>
+  /// `None` if the inscription is unbound or lost in the coinbase.
+  pub owner: Option<ScriptPubkey>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = ord::InscriptionHistory)]
+#[serde(rename_all = "camelCase")]
+pub struct InscriptionHistory {
+  /// The inscription id.
+  pub id: String,
+  /// The page of the genesis and the transfers of the inscription, in chronological order.
+  #[schema(value_type = Vec<ord::InscriptionTransferEvent>)]
+  pub history: Vec<InscriptionTransferEvent>,
+}
+
+impl From<InscriptionTransfer> for InscriptionTransferEvent {
+  fn from(transfer: InscriptionTransfer) -> Self {
+    Self {
+      height: transfer.height,
+      txid: transfer.txid.to_string(),
+      action: if transfer.genesis {
+        "inscribe".to_string()
+      } else {
+        "transfer".to_string()
+      },
+      location: transfer.satpoint.to_string(),
+      owner: transfer.owner.map(ScriptPubkey::from),
+    }
+  }
+}
+
+// /ord/id/:id/history
+/// Retrieve the transfer history of the inscription.
+///
+/// The locations and owners of the inscription from its genesis through every transfer, at most 100 per page.
+#[utoipa::path(
+  get,
+  path = "/api/v1/ord/id/{id}/history",
+  params(
+      ("id" = String, Path, description = "inscription ID"),
+      Pagination,
+),
+  responses(
+    (status = 200, description = "Obtain the transfer history of the inscription.", body = OrdInscriptionHistory),
+    (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request("bad request"))),
+    (status = 404, description = "Not found.", body = ApiError, example = json!(&ApiError::not_found("not found"))),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn ord_inscription_history(
+  Extension(index): Extension<Arc<Index>>,
+  Path(id): Path<String>,
+  Query(page): Query<Pagination>,
+) -> ApiResult<InscriptionHistory> {
+  log::debug!("rpc: get ord_inscription_history: {id}");
+
+  let id = InscriptionId::from_str(&id).map_err(|e| ApiError::bad_request(e.to_string()))?;
+  let limit = page.limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+  if limit > MAX_HISTORY_LIMIT {
+    return Err(ApiError::bad_request(format!(
+      "limit must not exceed {MAX_HISTORY_LIMIT}"
+    )));
+  }
+
+  index
+    .get_inscription_entry(id)?
+    .ok_or_api_not_found(format!("inscriptionId not found {id}"))?;
+
+  let history = index.ord_get_inscription_history(id, page.start.unwrap_or(0), limit)?;
+
+  log::debug!("rpc: get ord_inscription_history: {id} {:?}", history);
+
+  Ok(Json(ApiResponse::ok(InscriptionHistory {
+    id: id.to_string(),
+    history: history.into_iter().map(Into::into).collect(),
+  })))
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, crate::okx::datastore::ScriptKey};
+
+  #[test]
+  fn test_serialize_inscription_history() {
+    let txid =
+      Txid::from_str("b61b0172d95e266c18aea0c624db987e971a5d6d4ebc2aaed85da4642d635735").unwrap();
+    let history = InscriptionHistory {
+      id: InscriptionId { txid, index: 0 }.to_string(),
+      history: vec![
+        InscriptionTransfer {
+          height: 1,
+          txid,
+          genesis: true,
+          satpoint: SatPoint {
+            outpoint: OutPoint { txid, vout: 0 },
+            offset: 0,
+          },
+          owner: Some(ScriptKey::from_str("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").unwrap()),
+        }
+        .into(),
+        InscriptionTransfer {
+          height: 2,
+          txid,
+          genesis: false,
+          satpoint: SatPoint {
+            outpoint: unbound_outpoint(),
+            offset: 0,
+          },
+          owner: None,
+        }
+        .into(),
+      ],
+    };
+    assert_eq!(
+      serde_json::to_string(&history).unwrap(),
+      r#"{"id":"b61b0172d95e266c18aea0c624db987e971a5d6d4ebc2aaed85da4642d635735i0","history":[{"height":1,"txid":"b61b0172d95e266c18aea0c624db987e971a5d6d4ebc2aaed85da4642d635735","action":"inscribe","location":"b61b0172d95e266c18aea0c624db987e971a5d6d4ebc2aaed85da4642d635735:0:0","owner":{"address":"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"}},{"height":2,"txid":"b61b0172d95e266c18aea0c624db987e971a5d6d4ebc2aaed85da4642d635735","action":"transfer","location":"0000000000000000000000000000000000000000000000000000000000000000:0:0","owner":null}]}"#
+    );
+  }
+}
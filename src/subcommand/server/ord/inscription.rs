@@ -8,6 +8,9 @@ use {
   utoipa::ToSchema,
 };
 
+const DEFAULT_CHILDREN_LIMIT: usize = 20;
+const MAX_CHILDREN_LIMIT: usize = 100;
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[schema(as = ord::OrdInscription)]
 #[serde(rename_all = "camelCase")]
@@ -31,6 +34,21 @@ pub struct OrdInscription {
   pub collections: Vec<String>,
   /// The inscription sat index.  
   pub sat: Option<u64>,
+  /// The id of the verified parent inscription.
+  pub parent: Option<String>,
+  /// The number of verified children of the inscription.
+  pub children_count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = ord::InscriptionChildren)]
+#[serde(rename_all = "camelCase")]
+pub struct InscriptionChildren {
+  /// The verified children of the inscription, in inscription order.
+  #[schema(value_type = Vec<ord::InscriptionDigest>)]
+  pub children: Vec<InscriptionDigest>,
+  /// The number of verified children of the inscription.
+  pub total: u64,
 }
 
 // /ord/id/:id/inscription
@@ -138,6 +156,11 @@ fn ord_get_inscription_by_id(index: Arc<Index>, id: InscriptionId) -> ApiResult<
       .map(|c| c.to_string())
       .collect(),
     sat: inscription_data.entry.sat.map(|s| s.0),
+    parent: inscription_data
+      .entry
+      .parent
+      .map(|parent| parent.to_string()),
+    children_count: index.ord_get_children_count(id)?,
   })))
 }
 
@@ -185,6 +208,73 @@ fn get_inscription_all_data_by_id(
   }))
 }
 
+// /ord/id/:id/children
+/// Retrieve the verified children of the inscription.
+///
+/// A child is verified if its parent inscription is spent by the transaction that inscribes it.
+#[utoipa::path(
+  get,
+  path = "/api/v1/ord/id/{id}/children",
+  params(
+      ("id" = String, Path, description = "inscription ID"),
+      Pagination,
+),
+  responses(
+    (status = 200, description = "Obtain the children of the inscription.", body = OrdInscriptionChildren),
+    (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request("bad request"))),
+    (status = 404, description = "Not found.", body = ApiError, example = json!(&ApiError::not_found("not found"))),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn ord_inscription_children(
+  Extension(index): Extension<Arc<Index>>,
+  Path(id): Path<String>,
+  Query(page): Query<Pagination>,
+) -> ApiResult<InscriptionChildren> {
+  log::debug!("rpc: get ord_inscription_children: {id}");
+  let id = InscriptionId::from_str(&id).map_err(|e| ApiError::bad_request(e.to_string()))?;
+  let limit = page.limit.unwrap_or(DEFAULT_CHILDREN_LIMIT);
+  if limit > MAX_CHILDREN_LIMIT {
+    return Err(ApiError::bad_request(format!(
+      "limit must not exceed {MAX_CHILDREN_LIMIT}"
+    )));
+  }
+
+  index
+    .get_inscription_entry(id)?
+    .ok_or_api_not_found(format!("inscriptionId not found {id}"))?;
+
+  let (children, total) = index.ord_get_children(
+    id,
+    page.start.unwrap_or(0).try_into().unwrap(),
+    limit.try_into().unwrap(),
+  )?;
+
+  let mut digests = Vec::new();
+  for child in children {
+    digests.push(InscriptionDigest {
+      id: child.to_string(),
+      number: index
+        .get_inscription_entry(child)?
+        .ok_or_api_err(|| ApiError::internal(format!("Failed to get inscription {child}.")))?
+        .number,
+      location: index
+        .get_inscription_satpoint_by_id(child)?
+        .ok_or(anyhow!(
+          "Failed to get the inscription location, there may be an error in the database."
+        ))?
+        .to_string(),
+    });
+  }
+
+  log::debug!("rpc: get ord_inscription_children: {id} {:?}", digests);
+
+  Ok(Json(ApiResponse::ok(InscriptionChildren {
+    children: digests,
+    total,
+  })))
+}
+
 // ord/debug/bitmap/district/:number
 pub(crate) async fn ord_debug_bitmap_district(
   Extension(index): Extension<Arc<Index>>,
@@ -237,6 +327,8 @@ mod tests {
       .to_string(),
       collections: Vec::new(),
       sat: None,
+      parent: None,
+      children_count: 0,
     };
     assert_eq!(
       serde_json::to_string_pretty(&ord_inscription).unwrap(),
@@ -251,7 +343,9 @@ mod tests {
   "genesisHeight": 1,
   "location": "5660d06bd69326c18ec63127b37fb3b32ea763c3846b3334c51beb6a800c57d3:1:3000",
   "collections": [],
-  "sat": null
+  "sat": null,
+  "parent": null,
+  "childrenCount": 0
 }"#,
     );
     ord_inscription.owner = None;
@@ -266,7 +360,9 @@ mod tests {
   "genesisHeight": 1,
   "location": "5660d06bd69326c18ec63127b37fb3b32ea763c3846b3334c51beb6a800c57d3:1:3000",
   "collections": [],
-  "sat": null
+  "sat": null,
+  "parent": null,
+  "childrenCount": 0
 }"#,
     );
   }
@@ -3,11 +3,19 @@ use {
   crate::okx::datastore::ord::{Action, InscriptionOp},
 };
 
+mod address;
+mod bitmap;
+mod history;
 mod inscription;
+mod names;
 mod outpoint;
+mod stats;
 mod transaction;
 
-pub(super) use {inscription::*, outpoint::*, transaction::*};
+pub(super) use {
+  address::*, bitmap::*, history::*, inscription::*, names::*, outpoint::*, stats::*,
+  transaction::*,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum OrdError {
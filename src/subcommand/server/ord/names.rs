@@ -0,0 +1,133 @@
+use {
+  super::{error::ApiError, types::ScriptPubkey, *},
+  crate::okx::datastore::{ord::names::Name, ScriptKey},
+  axum::Json,
+  utoipa::ToSchema,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = ord::OrdName)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdName {
+  /// The case folded name, including the namespace.
+  pub name: String,
+  /// The namespace of the name.
+  pub namespace: String,
+  /// The id of the inscription that registered the name.
+  pub inscription_id: String,
+  /// The number of the inscription that registered the name.
+  pub inscription_number: i64,
+  /// The inscription location.
+  pub location: String,
+  /// The inscription owner.<br>
+  /// `None` if the inscription is unbound or lost in the coinbase.
+  pub owner: Option<ScriptPubkey>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = ord::AddressNames)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressNames {
+  /// The names registered by the inscriptions owned by the address, ordered by inscription number.
+  #[schema(value_type = Vec<ord::OrdName>)]
+  pub names: Vec<OrdName>,
+}
+
+fn ord_name(index: &Index, name: Name, number: i64, id: InscriptionId) -> Result<OrdName> {
+  let satpoint = index.get_inscription_satpoint_by_id(id)?.ok_or(anyhow!(
+    "Failed to get the inscription location, there may be an error in the database."
+  ))?;
+
+  let owner = index
+    .get_outpoint_entry(satpoint.outpoint)?
+    .map(|tx_out| ScriptKey::from_script(&tx_out.script_pubkey, index.get_chain_network()).into());
+
+  Ok(OrdName {
+    name: name.to_string(),
+    namespace: name.namespace,
+    inscription_id: id.to_string(),
+    inscription_number: number,
+    location: satpoint.to_string(),
+    owner,
+  })
+}
+
+// /ord/collection/:namespace/:name
+/// Resolve a name to the inscription that registered it.
+///
+/// The first inscription of a name registers it. Names are case insensitive.
+#[utoipa::path(
+  get,
+  path = "/api/v1/ord/collection/{namespace}/{name}",
+  params(
+      ("namespace" = String, Path, description = "Namespace, like `sats`"),
+      ("name" = String, Path, description = "Name without the namespace"),
+),
+  responses(
+    (status = 200, description = "Obtain the inscription of the name.", body = OrdOrdName),
+    (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request("bad request"))),
+    (status = 404, description = "Name not found.", body = ApiError, example = json!(&ApiError::not_found("name not found"))),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn ord_collection_name(
+  Extension(index): Extension<Arc<Index>>,
+  Path((namespace, name)): Path<(String, String)>,
+) -> ApiResult<OrdName> {
+  log::debug!("rpc: get ord_collection_name: {namespace} {name}");
+
+  let name = Name::parse(
+    format!("{name}.{namespace}").as_bytes(),
+    index.get_name_namespaces(),
+  )
+  .map_err(ApiError::bad_request)?;
+
+  let id = index
+    .ord_get_name_inscription_id(&name)?
+    .ok_or_api_not_found(format!("name {name} not found"))?;
+
+  let number = index
+    .get_inscription_entry(id)?
+    .ok_or_api_err(|| ApiError::internal(format!("Failed to get inscription {id}.")))?
+    .number;
+
+  let ord_name = ord_name(&index, name, number, id)?;
+
+  log::debug!("rpc: get ord_collection_name: {:?}", ord_name);
+
+  Ok(Json(ApiResponse::ok(ord_name)))
+}
+
+// /ord/address/:address/names
+/// Retrieve the names registered by the inscriptions owned by the address.
+#[utoipa::path(
+  get,
+  path = "/api/v1/ord/address/{address}/names",
+  params(
+      ("address" = String, Path, description = "Address"),
+),
+  responses(
+    (status = 200, description = "Obtain the names owned by the address.", body = OrdAddressNames),
+    (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request("bad request"))),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn ord_address_names(
+  Extension(index): Extension<Arc<Index>>,
+  Path(address): Path<String>,
+) -> ApiResult<AddressNames> {
+  log::debug!("rpc: get ord_address_names: {address}");
+
+  let address: bitcoin::Address = Address::from_str(&address)
+    .and_then(|address| address.require_network(index.get_chain_network()))
+    .map_err(ApiError::bad_request)?;
+
+  let mut names = Vec::new();
+  for (name, number, id) in index.ord_get_names_by_address(&address)? {
+    names.push(ord_name(&index, name, number, id)?);
+  }
+
+  log::debug!("rpc: get ord_address_names: {address} {:?}", names);
+
+  Ok(Json(ApiResponse::ok(AddressNames { names })))
+}
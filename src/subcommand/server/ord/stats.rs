@@ -0,0 +1,207 @@
+use {
+  super::{error::ApiError, *},
+  crate::index::InscriptionStats,
+  axum::Json,
+  utoipa::ToSchema,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(as = ord::ContentStats)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentStats {
+  /// The content type, or the media kind, of the inscriptions.<br>
+  /// Inscriptions without a content type are counted under the empty string.
+  pub name: String,
+  /// The number of inscriptions.
+  #[schema(format = "uint64")]
+  pub count: u64,
+  /// The total body size of the inscriptions in bytes.
+  #[schema(format = "uint64")]
+  pub bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(as = ord::OrdStats)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdStats {
+  /// The number of inscriptions.
+  #[schema(format = "uint64")]
+  pub count: u64,
+  /// The total body size of the inscriptions in bytes.
+  #[schema(format = "uint64")]
+  pub bytes: u64,
+  /// The number of cursed inscriptions.
+  #[schema(format = "uint64")]
+  pub cursed: u64,
+  /// The number of unbound inscriptions.
+  #[schema(format = "uint64")]
+  pub unbound: u64,
+  /// Statistics grouped by content type.
+  #[schema(value_type = Vec<ord::ContentStats>)]
+  pub content_types: Vec<ContentStats>,
+  /// Statistics grouped by media kind.
+  #[schema(value_type = Vec<ord::ContentStats>)]
+  pub media: Vec<ContentStats>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(as = ord::BlockStats)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockStats {
+  /// The block height.
+  #[schema(format = "uint64")]
+  pub height: u64,
+  /// Statistics of the inscriptions created in the block.
+  #[serde(flatten)]
+  #[schema(value_type = ord::OrdStats)]
+  pub stats: OrdStats,
+}
+
+impl From<InscriptionStats> for OrdStats {
+  fn from(stats: InscriptionStats) -> Self {
+    let total = stats.total();
+    let into_content_stats =
+      |(name, stats): (String, crate::index::ContentTypeStats)| ContentStats {
+        name,
+        count: stats.count,
+        bytes: stats.bytes,
+      };
+
+    Self {
+      count: total.count,
+      bytes: total.bytes,
+      cursed: stats.cursed,
+      unbound: stats.unbound,
+      media: stats.media().into_iter().map(into_content_stats).collect(),
+      content_types: stats
+        .content_types
+        .into_iter()
+        .map(into_content_stats)
+        .collect(),
+    }
+  }
+}
+
+// /ord/stats
+/// Retrieve the cumulative inscription statistics.
+///
+/// Count and size of all indexed inscriptions grouped by content type and media kind.
+#[utoipa::path(
+  get,
+  path = "/api/v1/ord/stats",
+  responses(
+    (status = 200, description = "Obtain the cumulative inscription statistics.", body = OrdOrdStats),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn ord_stats(Extension(index): Extension<Arc<Index>>) -> ApiResult<OrdStats> {
+  log::debug!("rpc: get ord_stats");
+
+  let stats = index.get_inscription_stats()?;
+
+  log::debug!("rpc: get ord_stats: {:?}", stats);
+
+  Ok(Json(ApiResponse::ok(stats.into())))
+}
+
+// /ord/block/:height/stats
+/// Retrieve the inscription statistics of the block.
+///
+/// Count and size of the inscriptions created in the block grouped by content type and media kind.
+#[utoipa::path(
+  get,
+  path = "/api/v1/ord/block/{height}/stats",
+  params(
+      ("height" = u64, Path, description = "block height")
+),
+  responses(
+    (status = 200, description = "Obtain the inscription statistics of the block.", body = OrdBlockStats),
+    (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request("bad request"))),
+    (status = 404, description = "Block not found.", body = ApiError, example = json!(&ApiError::not_found(OrdError::BlockNotFound))),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn ord_block_stats(
+  Extension(index): Extension<Arc<Index>>,
+  Path(height): Path<String>,
+) -> ApiResult<BlockStats> {
+  log::debug!("rpc: get ord_block_stats: {height}");
+
+  let height = height
+    .parse::<u64>()
+    .map_err(|e| ApiError::bad_request(e.to_string()))?;
+
+  let stats = index
+    .get_block_inscription_stats(height)?
+    .ok_or_api_not_found(OrdError::BlockNotFound)?;
+
+  log::debug!("rpc: get ord_block_stats: {height} {:?}", stats);
+
+  Ok(Json(ApiResponse::ok(BlockStats {
+    height,
+    stats: stats.into(),
+  })))
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, crate::index::ContentTypeStats};
+
+  #[test]
+  fn test_serialize_block_stats() {
+    let block_stats = BlockStats {
+      height: 100,
+      stats: InscriptionStats {
+        content_types: [
+          (
+            "image/png".to_string(),
+            ContentTypeStats { count: 1, bytes: 3 },
+          ),
+          (
+            "text/plain".to_string(),
+            ContentTypeStats { count: 2, bytes: 8 },
+          ),
+        ]
+        .into_iter()
+        .collect(),
+        cursed: 1,
+        unbound: 0,
+      }
+      .into(),
+    };
+    assert_eq!(
+      serde_json::to_string_pretty(&block_stats).unwrap(),
+      r#"{
+  "height": 100,
+  "count": 3,
+  "bytes": 11,
+  "cursed": 1,
+  "unbound": 0,
+  "contentTypes": [
+    {
+      "name": "image/png",
+      "count": 1,
+      "bytes": 3
+    },
+    {
+      "name": "text/plain",
+      "count": 2,
+      "bytes": 8
+    }
+  ],
+  "media": [
+    {
+      "name": "image",
+      "count": 1,
+      "bytes": 3
+    },
+    {
+      "name": "text",
+      "count": 2,
+      "bytes": 8
+    }
+  ]
+}"#
+    );
+  }
+}
@@ -0,0 +1,180 @@
+use {
+  super::{
+    brc20::{Balance, TickInfo},
+    error::{OptionExt, ServerError, ServerResult},
+    *,
+  },
+  crate::okx::datastore::brc20::Tick,
+  axum::{
+    extract::{Extension, Json, Path},
+    http::{header, HeaderMap, HeaderValue},
+    response::{IntoResponse, Response},
+  },
+};
+
+/// The number of inscription ids in each page of `/r/sat` and `/r/children`.
+const PAGE_SIZE: usize = 100;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct InscriptionIdsJson {
+  pub(crate) ids: Vec<InscriptionId>,
+  pub(crate) more: bool,
+  pub(crate) page: usize,
+}
+
+/// Inscription content may only load content and recursive endpoints from this server.
+pub(super) fn content_security_policy(headers: &mut HeaderMap) {
+  headers.insert(
+    header::CONTENT_SECURITY_POLICY,
+    HeaderValue::from_static("default-src 'self' 'unsafe-eval' 'unsafe-inline' data: blob:"),
+  );
+  headers.append(
+    header::CONTENT_SECURITY_POLICY,
+    HeaderValue::from_static("default-src *:*/content/ *:*/blockheight *:*/blockhash *:*/blockhash/ *:*/blocktime *:*/r/ 'unsafe-eval' 'unsafe-inline' data: blob:"),
+  );
+}
+
+fn recursive_response<T: Serialize>(value: T) -> Response {
+  let mut headers = HeaderMap::new();
+  content_security_policy(&mut headers);
+  (headers, Json(value)).into_response()
+}
+
+fn inscription_ids_page(ids: Vec<InscriptionId>, more: bool, page: usize) -> Response {
+  recursive_response(InscriptionIdsJson { ids, more, page })
+}
+
+pub(super) async fn blockheight(Extension(index): Extension<Arc<Index>>) -> ServerResult<Response> {
+  Ok(recursive_response(
+    index.block_height()?.ok_or_not_found(|| "blockheight")?.n(),
+  ))
+}
+
+pub(super) async fn blockhash(Extension(index): Extension<Arc<Index>>) -> ServerResult<Response> {
+  Ok(recursive_response(
+    index.block_hash(None)?.ok_or_not_found(|| "blockhash")?,
+  ))
+}
+
+pub(super) async fn blockhash_at_height(
+  Extension(index): Extension<Arc<Index>>,
+  Path(height): Path<u64>,
+) -> ServerResult<Response> {
+  Ok(recursive_response(
+    index
+      .block_hash(Some(height))?
+      .ok_or_not_found(|| "blockhash")?,
+  ))
+}
+
+pub(super) async fn blocktime(Extension(index): Extension<Arc<Index>>) -> ServerResult<Response> {
+  Ok(recursive_response(
+    index
+      .block_time(index.block_height()?.ok_or_not_found(|| "blocktime")?)?
+      .unix_timestamp(),
+  ))
+}
+
+pub(super) async fn sat(
+  Extension(index): Extension<Arc<Index>>,
+  Path(sat): Path<u64>,
+) -> ServerResult<Response> {
+  sat_page(Extension(index), Path((sat, 0))).await
+}
+
+pub(super) async fn sat_page(
+  Extension(index): Extension<Arc<Index>>,
+  Path((sat, page)): Path<(u64, usize)>,
+) -> ServerResult<Response> {
+  if !index.has_sat_index()? {
+    return Err(ServerError::NotFound(
+      "this server has no sat index".to_string(),
+    ));
+  }
+
+  let ids = index.get_inscription_ids_by_sat(Sat(sat))?;
+  let more = ids.len() > (page + 1).saturating_mul(PAGE_SIZE);
+  let ids = ids
+    .into_iter()
+    .skip(page.saturating_mul(PAGE_SIZE))
+    .take(PAGE_SIZE)
+    .collect();
+
+  Ok(inscription_ids_page(ids, more, page))
+}
+
+pub(super) async fn metadata(
+  Extension(index): Extension<Arc<Index>>,
+  Path(inscription_id): Path<InscriptionId>,
+) -> ServerResult<Response> {
+  let inscription = index
+    .get_inscription_by_id(inscription_id)?
+    .ok_or_not_found(|| format!("inscription {inscription_id}"))?;
+
+  let metadata = inscription
+    .metadata()
+    .ok_or_not_found(|| format!("inscription {inscription_id} metadata"))?;
+
+  Ok(recursive_response(hex::encode(metadata)))
+}
+
+pub(super) async fn children(
+  Extension(index): Extension<Arc<Index>>,
+  Path(inscription_id): Path<InscriptionId>,
+) -> ServerResult<Response> {
+  children_page(Extension(index), Path((inscription_id, 0))).await
+}
+
+pub(super) async fn children_page(
+  Extension(index): Extension<Arc<Index>>,
+  Path((inscription_id, page)): Path<(InscriptionId, usize)>,
+) -> ServerResult<Response> {
+  index
+    .get_inscription_entry(inscription_id)?
+    .ok_or_not_found(|| format!("inscription {inscription_id}"))?;
+
+  let start = page.saturating_mul(PAGE_SIZE);
+  let (ids, total) = index.ord_get_children(
+    inscription_id,
+    start.try_into().unwrap(),
+    PAGE_SIZE.try_into().unwrap(),
+  )?;
+  let more = total > u64::try_from(start.saturating_add(PAGE_SIZE)).unwrap();
+
+  Ok(inscription_ids_page(ids, more, page))
+}
+
+pub(super) async fn brc20_tick(
+  Extension(index): Extension<Arc<Index>>,
+  Path(tick): Path<String>,
+) -> ServerResult<Response> {
+  let tick = Tick::from_str(&tick).map_err(|err| ServerError::BadRequest(err.to_string()))?;
+
+  let token_info = index
+    .brc20_get_tick_info(&tick)?
+    .ok_or_not_found(|| format!("tick {tick}"))?;
+
+  Ok(recursive_response(TickInfo::from(token_info)))
+}
+
+pub(super) async fn brc20_balance(
+  Extension(index): Extension<Arc<Index>>,
+  Path((tick, address)): Path<(String, String)>,
+) -> ServerResult<Response> {
+  let tick = Tick::from_str(&tick).map_err(|err| ServerError::BadRequest(err.to_string()))?;
+
+  let address = Address::from_str(&address)
+    .and_then(|address| address.require_network(index.get_chain_network()))
+    .map_err(|err| ServerError::BadRequest(err.to_string()))?;
+
+  let balance = index
+    .brc20_get_balance_by_address(&tick, &address)?
+    .ok_or_not_found(|| format!("balance of {tick} for {address}"))?;
+
+  Ok(recursive_response(Balance {
+    tick: balance.tick.to_string(),
+    available_balance: (balance.overall_balance - balance.transferable_balance).to_string(),
+    transferable_balance: balance.transferable_balance.to_string(),
+    overall_balance: balance.overall_balance.to_string(),
+  }))
+}
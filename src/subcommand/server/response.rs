@@ -11,6 +11,7 @@ use {
   BRC20TxEvents = ApiResponse<brc20::TxEvents>,
   BRC20BlockEvents = ApiResponse<brc20::BlockEvents>,
   BRC20Transferable = ApiResponse<brc20::TransferableInscriptions>,
+  BRC20TickSearch = ApiResponse<brc20::TickSearchResults>,
 
   BRC20STick = ApiResponse<brc20s::TickInfo>,
   BRC20SAllTick = ApiResponse<brc20s::AllTickInfo>,
@@ -18,10 +19,13 @@ use {
   BRC20SAllBalance = ApiResponse<brc20s::AllBalance>,
   BRC20SPool = ApiResponse<brc20s::Pool>,
   BRC20SAllPool = ApiResponse<brc20s::AllPoolInfo>,
+  BRC20SPoolUsers = ApiResponse<brc20s::PoolUsers>,
+  BRC20SPoolHistory = ApiResponse<brc20s::PoolHistory>,
   BRC20STxReceipts = ApiResponse<brc20s::TxReceipts>,
   BRC20SBlockReceipts = ApiResponse<brc20s::BlockReceipts>,
   BRC20STransferable = ApiResponse<brc20s::Transferable>,
   BRC20SUserInfo = ApiResponse<brc20s::UserInfo>,
+  BRC20SRewardProjection = ApiResponse<brc20s::RewardProjection>,
   BRC20SStakedInfo = ApiResponse<brc20s::StakedInfo>,
 
   OrdOrdInscription = ApiResponse<ord::OrdInscription>,
@@ -29,6 +33,17 @@ use {
   OrdOutPointResult = ApiResponse<ord::OutPointResult>,
   OrdTxInscriptions = ApiResponse<ord::TxInscriptions>,
   OrdBlockInscriptions = ApiResponse<ord::BlockInscriptions>,
+  OrdOrdStats = ApiResponse<ord::OrdStats>,
+  OrdBlockStats = ApiResponse<ord::BlockStats>,
+  OrdAddressInscriptions = ApiResponse<ord::AddressInscriptions>,
+  OrdInscriptionHistory = ApiResponse<ord::InscriptionHistory>,
+  OrdOrdName = ApiResponse<ord::OrdName>,
+  OrdAddressNames = ApiResponse<ord::AddressNames>,
+  OrdBitmapDistrict = ApiResponse<ord::BitmapDistrict>,
+  OrdAddressBitmap = ApiResponse<ord::AddressBitmap>,
+  OrdInscriptionChildren = ApiResponse<ord::InscriptionChildren>,
+
+  SatAddressRareSats = ApiResponse<sat::AddressRareSats>,
 
   Node = ApiResponse<NodeInfo>
 )]
@@ -0,0 +1,105 @@
+use {
+  super::{error::ApiError, *},
+  axum::Json,
+  utoipa::{IntoParams, ToSchema},
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = sat::RareSat)]
+#[serde(rename_all = "camelCase")]
+pub struct RareSat {
+  /// The sat number.
+  #[schema(format = "uint64")]
+  pub sat: u64,
+  /// The rarity of the sat, `uncommon` or rarer.
+  pub rarity: String,
+  /// The location of the sat.
+  pub satpoint: String,
+  /// Whether an inscription sits on the sat.
+  pub inscribed: bool,
+  /// The satributes of the sat, like `alpha` or `vintage`.
+  #[schema(value_type = Vec<String>)]
+  pub satributes: Vec<Satribute>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(as = sat::AddressRareSats)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressRareSats {
+  /// The rare sats held by the address, ordered by sat number.
+  #[schema(value_type = Vec<sat::RareSat>)]
+  pub sats: Vec<RareSat>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
+pub struct RareSatQuery {
+  /// Optional to only list the rare sats with the satribute, like `vintage`. Rare sats are the first
+  /// sat of their block, so `omega` and `black` are rejected.
+  satribute: Option<String>,
+}
+
+// /sat/address/:address/rare
+/// Retrieve the sats that are uncommon or rarer held by the address.
+///
+/// Requires the server to index sats with `--index-sats`. The rare sats can be filtered by
+/// satribute, common sats are not listed whatever their satributes.
+#[utoipa::path(
+  get,
+  path = "/api/v1/sat/address/{address}/rare",
+  params(
+      ("address" = String, Path, description = "Address"),
+      RareSatQuery,
+),
+  responses(
+    (status = 200, description = "Obtain the rare sats held by the address.", body = SatAddressRareSats),
+    (status = 400, description = "Bad query.", body = ApiError, example = json!(&ApiError::bad_request("bad request"))),
+    (status = 404, description = "Sats are not indexed.", body = ApiError, example = json!(&ApiError::not_found("sat index not found"))),
+    (status = 500, description = "Internal server error.", body = ApiError, example = json!(&ApiError::internal("internal error"))),
+  )
+)]
+pub(crate) async fn sat_address_rare(
+  Extension(index): Extension<Arc<Index>>,
+  Path(address): Path<String>,
+  Query(query): Query<RareSatQuery>,
+) -> ApiResult<AddressRareSats> {
+  log::debug!("rpc: get sat_address_rare: {address} {:?}", query.satribute);
+
+  let address: bitcoin::Address = Address::from_str(&address)
+    .and_then(|address| address.require_network(index.get_chain_network()))
+    .map_err(ApiError::bad_request)?;
+
+  let satribute = query
+    .satribute
+    .map(|satribute| satribute.parse::<Satribute>())
+    .transpose()
+    .map_err(ApiError::bad_request)?;
+
+  if let Some(satribute @ (Satribute::Omega | Satribute::Black)) = satribute {
+    return Err(ApiError::bad_request(format!(
+      "rare sats are never {satribute}"
+    )));
+  }
+
+  let mut sats = Vec::new();
+  for (sat, satpoint) in index
+    .get_rare_sats_by_address(&address)?
+    .ok_or_api_not_found("sat index not found")?
+  {
+    let satributes = index.satributes(sat)?;
+    if satribute.is_some_and(|satribute| !satributes.contains(&satribute)) {
+      continue;
+    }
+
+    sats.push(RareSat {
+      sat: sat.n(),
+      rarity: sat.rarity().to_string(),
+      satpoint: satpoint.to_string(),
+      inscribed: !index.get_inscription_ids_by_sat(sat)?.is_empty(),
+      satributes,
+    });
+  }
+
+  log::debug!("rpc: get sat_address_rare: {address} {:?}", sats);
+
+  Ok(Json(ApiResponse::ok(AddressRareSats { sats })))
+}
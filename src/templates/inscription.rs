@@ -1,4 +1,4 @@
-use super::*;
+use {super::*, crate::okx::datastore::ord::InscriptionTransfer};
 
 #[derive(Boilerplate)]
 pub(crate) struct InscriptionHtml {
@@ -6,6 +6,7 @@ pub(crate) struct InscriptionHtml {
   pub(crate) children: Vec<InscriptionId>,
   pub(crate) genesis_fee: u64,
   pub(crate) genesis_height: u64,
+  pub(crate) history: Vec<InscriptionTransfer>,
   pub(crate) inscription: Inscription,
   pub(crate) inscription_id: InscriptionId,
   pub(crate) next: Option<InscriptionId>,
@@ -100,6 +101,7 @@ mod tests {
         chain: Chain::Mainnet,
         genesis_fee: 1,
         genesis_height: 0,
+        history: Vec::new(),
         inscription: inscription("text/plain;charset=utf-8", "HELLOWORLD"),
         inscription_id: inscription_id(1),
         next: None,
@@ -157,6 +159,7 @@ mod tests {
         chain: Chain::Mainnet,
         genesis_fee: 1,
         genesis_height: 0,
+        history: Vec::new(),
         inscription: inscription("text/plain;charset=utf-8", "HELLOWORLD"),
         inscription_id: inscription_id(1),
         next: None,
@@ -196,6 +199,7 @@ mod tests {
         chain: Chain::Mainnet,
         genesis_fee: 1,
         genesis_height: 0,
+        history: Vec::new(),
         inscription: inscription("text/plain;charset=utf-8", "HELLOWORLD"),
         inscription_id: inscription_id(1),
         next: None,
@@ -230,6 +234,7 @@ mod tests {
         chain: Chain::Mainnet,
         genesis_fee: 1,
         genesis_height: 0,
+        history: Vec::new(),
         inscription: inscription("text/plain;charset=utf-8", "HELLOWORLD"),
         inscription_id: inscription_id(2),
         next: Some(inscription_id(3)),
@@ -262,6 +267,7 @@ mod tests {
         chain: Chain::Mainnet,
         genesis_fee: 1,
         genesis_height: 0,
+        history: Vec::new(),
         inscription: inscription("text/plain;charset=utf-8", "HELLOWORLD"),
         inscription_id: inscription_id(2),
         next: None,
@@ -300,6 +306,7 @@ mod tests {
         chain: Chain::Mainnet,
         genesis_fee: 1,
         genesis_height: 0,
+        history: Vec::new(),
         inscription: inscription("text/plain;charset=utf-8", "HELLOWORLD"),
         inscription_id: inscription_id(1),
         next: None,
@@ -359,6 +366,7 @@ mod tests {
         chain: Chain::Mainnet,
         genesis_fee: 1,
         genesis_height: 0,
+        history: Vec::new(),
         inscription: inscription("text/plain;charset=utf-8", "HELLOWORLD"),
         inscription_id: inscription_id(1),
         next: None,
@@ -413,4 +421,68 @@ mod tests {
       .unindent()
     );
   }
+
+  #[test]
+  fn with_history() {
+    assert_regex_match!(
+      InscriptionHtml {
+        children: Vec::new(),
+        parent: None,
+        chain: Chain::Mainnet,
+        genesis_fee: 1,
+        genesis_height: 0,
+        history: vec![
+          InscriptionTransfer {
+            height: 0,
+            txid: txid(1),
+            genesis: true,
+            satpoint: satpoint(1, 0),
+            owner: Some("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".parse().unwrap()),
+          },
+          InscriptionTransfer {
+            height: 5,
+            txid: txid(2),
+            genesis: false,
+            satpoint: SatPoint {
+              outpoint: OutPoint::null(),
+              offset: 0,
+            },
+            owner: None,
+          },
+        ],
+        inscription: inscription("text/plain;charset=utf-8", "HELLOWORLD"),
+        inscription_id: inscription_id(1),
+        next: None,
+        number: 1,
+        output: None,
+        previous: None,
+        sat: None,
+        satpoint: satpoint(1, 0),
+        timestamp: timestamp(0),
+      },
+      "
+        .*
+          <dt>offset</dt>
+          <dd>0</dd>
+          <dt>history</dt>
+          <dd>
+            <ol>
+              <li>
+                <a href=/block/0>0</a>
+                inscribed in
+                <a class=monospace href=/tx/1{64}>1{64}</a>
+                to <span class=monospace>1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa</span>
+              </li>
+              <li>
+                <a href=/block/5>5</a>
+                transferred in
+                <a class=monospace href=/tx/2{64}>2{64}</a>
+              </li>
+            </ol>
+          </dd>
+        </dl>
+      "
+      .unindent()
+    );
+  }
 }
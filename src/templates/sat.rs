@@ -6,6 +6,7 @@ pub(crate) struct SatHtml {
   pub(crate) satpoint: Option<SatPoint>,
   pub(crate) blocktime: Blocktime,
   pub(crate) inscriptions: Vec<InscriptionId>,
+  pub(crate) satributes: Vec<Satribute>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -20,6 +21,7 @@ pub struct SatJson {
   pub period: u64,
   pub offset: u64,
   pub rarity: Rarity,
+  pub satributes: Vec<Satribute>,
   pub percentile: String,
   pub satpoint: Option<SatPoint>,
   pub timestamp: i64,
@@ -44,6 +46,7 @@ mod tests {
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
         inscriptions: Vec::new(),
+        satributes: Vec::new(),
       },
       "
         <h1>Sat 0</h1>
@@ -77,6 +80,7 @@ mod tests {
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
         inscriptions: Vec::new(),
+        satributes: Vec::new(),
       },
       "
         <h1>Sat 2099999997689999</h1>
@@ -110,6 +114,7 @@ mod tests {
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
         inscriptions: Vec::new(),
+        satributes: Vec::new(),
       },
       r"<h1>Sat 1</h1>.*<a class=prev href=/sat/0>prev</a>\n<a class=next href=/sat/2>next</a>.*",
     );
@@ -123,6 +128,7 @@ mod tests {
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
         inscriptions: vec![inscription_id(1)],
+        satributes: Vec::new(),
       },
       "
         <h1>Sat 0</h1>
@@ -144,6 +150,7 @@ mod tests {
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
         inscriptions: vec![inscription_id(1), inscription_id(2)],
+        satributes: Vec::new(),
       },
       "
         <h1>Sat 0</h1>
@@ -166,6 +173,7 @@ mod tests {
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
         inscriptions: Vec::new(),
+        satributes: Vec::new(),
       },
       r"<h1>Sat 2099999997689999</h1>.*<a class=prev href=/sat/2099999997689998>prev</a>\nnext.*",
     );
@@ -179,8 +187,35 @@ mod tests {
         satpoint: Some(satpoint(1, 0)),
         blocktime: Blocktime::confirmed(0),
         inscriptions: Vec::new(),
+        satributes: Vec::new(),
       },
       "<h1>Sat 0</h1>.*<dt>location</dt><dd class=monospace>1{64}:1:0</dd>.*",
     );
   }
+
+  #[test]
+  fn sat_with_satributes() {
+    assert_regex_match!(
+      SatHtml {
+        sat: Sat(0),
+        satpoint: None,
+        blocktime: Blocktime::confirmed(0),
+        inscriptions: Vec::new(),
+        satributes: Sat(0).satributes(),
+      },
+      "
+        <h1>Sat 0</h1>
+        .*
+          <dt>rarity</dt><dd><span class=mythic>mythic</span></dd>
+          <dt>satributes</dt>
+          <dd>
+            <span>alpha</span>
+            <span>palindrome</span>
+            <span>vintage</span>
+          </dd>
+          <dt>timestamp</dt>.*
+        .*"
+        .unindent(),
+    );
+  }
 }
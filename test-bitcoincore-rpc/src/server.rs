@@ -123,7 +123,12 @@ impl Api for Server {
           height,
           median_time: None,
           merkle_root: TxMerkleNode::all_zeros(),
-          n_tx: 0,
+          n_tx: self
+            .state()
+            .blocks
+            .get(&block_hash)
+            .map(|block| block.txdata.len())
+            .unwrap_or_default(),
           next_block_hash: None,
           nonce: 0,
           previous_block_hash: None,
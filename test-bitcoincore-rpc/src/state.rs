@@ -159,7 +159,14 @@ impl State {
             .get(i)
             .cloned()
             .unwrap_or(value_per_output),
-          script_pubkey: script::Builder::new().into_script(),
+          script_pubkey: match (i, &template.op_return) {
+            (0, Some(op_return)) => op_return.clone(),
+            _ => template
+              .recipient
+              .as_ref()
+              .map(|recipient| recipient.script_pubkey())
+              .unwrap_or_else(|| script::Builder::new().into_script()),
+          },
         })
         .collect(),
     };
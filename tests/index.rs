@@ -64,3 +64,32 @@ fn index_runs_with_rpc_user_and_pass_as_env_vars() {
 
   assert_eq!(ord.wait_with_output().unwrap().status.code(), Some(0));
 }
+
+#[test]
+fn export_brc20_writes_header_with_indexed_height() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  rpc_server.mine_blocks(2);
+
+  assert_eq!(
+    CommandBuilder::new("index export-brc20 --what tokens --output tokens.csv")
+      .rpc_server(&rpc_server)
+      .stdout_regex(".*")
+      .run_and_extract_file("tokens.csv"),
+    "# export at block height 3\n\
+     tick,inscription_id,inscription_number,supply,minted,limit_per_mint,decimal,deploy_by,deployed_number,deployed_timestamp,latest_mint_number\n"
+  );
+}
+
+#[test]
+fn export_brc20s_jsonl_writes_header_with_indexed_height() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  rpc_server.mine_blocks(1);
+
+  assert_eq!(
+    CommandBuilder::new("index export-brc20s --what pools --format jsonl")
+      .rpc_server(&rpc_server)
+      .stdout_regex(".*")
+      .run_and_extract_file("brc20s_pools.jsonl"),
+    "{\"export_height\":2}\n"
+  );
+}
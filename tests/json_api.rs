@@ -27,6 +27,7 @@ fn get_sat_without_sat_index() {
       period: 3437,
       offset: 0,
       rarity: Rarity::Uncommon,
+      satributes: vec![Satribute::Black],
       percentile: "100%".into(),
       satpoint: None,
       timestamp: 0,
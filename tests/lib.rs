@@ -3,7 +3,9 @@
 use {
   self::{command_builder::CommandBuilder, expected::Expected, test_server::TestServer},
   executable_path::executable_path,
-  ord::{inscription_id::InscriptionId, rarity::Rarity, templates::sat::SatJson},
+  ord::{
+    inscription_id::InscriptionId, rarity::Rarity, satribute::Satribute, templates::sat::SatJson,
+  },
   pretty_assertions::assert_eq as pretty_assert_eq,
   regex::Regex,
   reqwest::{StatusCode, Url},